@@ -0,0 +1,50 @@
+//! Extract the documentation of each rule from the doc comment of its lint,
+//! e.g. `src/lints/any_is_na/any_is_na.rs`, so that it can be printed by
+//! `jarl explain` without having to read the website.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+fn main() {
+    let lints_dir = Path::new("src/lints");
+    println!("cargo:rerun-if-changed={}", lints_dir.display());
+
+    let mut names = fs::read_dir(lints_dir)
+        .expect("Failed to read the lints directory")
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect::<Vec<_>>();
+    names.sort();
+
+    let mut out = String::from(
+        "/// Documentation of the rule, taken from the doc comment of its lint.\n\
+         fn rule_documentation(name: &str) -> Option<&'static str> {\n    match name {\n",
+    );
+    for name in names {
+        let path = lints_dir.join(&name).join(format!("{name}.rs"));
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+        if let Some(doc) = extract_documentation(&contents) {
+            writeln!(out, "        {name:?} => Some({doc:?}),").unwrap();
+        }
+    }
+    out.push_str("        _ => None,\n    }\n}\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("rule_docs.rs");
+    fs::write(out_path, out).expect("Failed to write the documentation of rules");
+}
+
+/// The doc comment starting at `## What it does`, without the `///`.
+fn extract_documentation(contents: &str) -> Option<String> {
+    let lines = contents
+        .lines()
+        .skip_while(|line| line.trim() != "/// ## What it does")
+        .map_while(|line| line.trim().strip_prefix("///"))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>();
+
+    (!lines.is_empty()).then(|| lines.join("\n").trim_end().to_string())
+}
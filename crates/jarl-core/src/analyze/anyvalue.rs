@@ -3,7 +3,10 @@ use crate::rule_set::Rule;
 use air_r_syntax::AnyRValue;
 use biome_rowan::AstNode;
 
+use crate::lints::absolute_path::absolute_path::absolute_path;
+use crate::lints::implicit_integer::implicit_integer::implicit_integer;
 use crate::lints::numeric_leading_zero::numeric_leading_zero::numeric_leading_zero;
+use crate::lints::quotes::quotes::quotes;
 
 pub fn anyvalue(r_expr: &AnyRValue, checker: &mut Checker) -> anyhow::Result<()> {
     let node = r_expr.syntax();
@@ -11,10 +14,23 @@ pub fn anyvalue(r_expr: &AnyRValue, checker: &mut Checker) -> anyhow::Result<()>
     // Check suppressions once for this node
     let suppressed_rules = checker.get_suppressed_rules(node);
 
+    if checker.is_rule_enabled(Rule::AbsolutePath)
+        && !suppressed_rules.contains(&Rule::AbsolutePath)
+    {
+        checker.report_diagnostic(absolute_path(r_expr)?);
+    }
+    if checker.is_rule_enabled(Rule::ImplicitInteger)
+        && !suppressed_rules.contains(&Rule::ImplicitInteger)
+    {
+        checker.report_diagnostic(implicit_integer(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::NumericLeadingZero)
         && !suppressed_rules.contains(&Rule::NumericLeadingZero)
     {
         checker.report_diagnostic(numeric_leading_zero(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::Quotes) && !suppressed_rules.contains(&Rule::Quotes) {
+        checker.report_diagnostic(quotes(r_expr, checker.config.preferred_quote)?);
+    }
     Ok(())
 }
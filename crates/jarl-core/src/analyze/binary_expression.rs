@@ -11,8 +11,12 @@ use crate::lints::equals_na::equals_na::equals_na;
 use crate::lints::equals_nan::equals_nan::equals_nan;
 use crate::lints::equals_null::equals_null::equals_null;
 use crate::lints::implicit_assignment::implicit_assignment::implicit_assignment;
+use crate::lints::infix_spaces::infix_spaces::infix_spaces;
 use crate::lints::is_numeric::is_numeric::is_numeric;
+use crate::lints::nzchar::nzchar::nzchar;
+use crate::lints::object_name::object_name::object_name;
 use crate::lints::redundant_equals::redundant_equals::redundant_equals;
+use crate::lints::scalar_in::scalar_in::scalar_in;
 use crate::lints::seq::seq::seq;
 use crate::lints::string_boundary::string_boundary::string_boundary;
 use crate::lints::vector_logic::vector_logic::vector_logic;
@@ -56,14 +60,27 @@ pub fn binary_expression(r_expr: &RBinaryExpression, checker: &mut Checker) -> a
     {
         checker.report_diagnostic(implicit_assignment(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::InfixSpaces) && !suppressed_rules.contains(&Rule::InfixSpaces)
+    {
+        checker.report_diagnostic(infix_spaces(r_expr, checker.config.infix_spaces_exponent)?);
+    }
     if checker.is_rule_enabled(Rule::IsNumeric) && !suppressed_rules.contains(&Rule::IsNumeric) {
         checker.report_diagnostic(is_numeric(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::Nzchar) && !suppressed_rules.contains(&Rule::Nzchar) {
+        checker.report_diagnostic(nzchar(r_expr)?);
+    }
+    if checker.is_rule_enabled(Rule::ObjectName) && !suppressed_rules.contains(&Rule::ObjectName) {
+        checker.report_diagnostic(object_name(r_expr, &checker.config.object_name_styles)?);
+    }
     if checker.is_rule_enabled(Rule::RedundantEquals)
         && !suppressed_rules.contains(&Rule::RedundantEquals)
     {
         checker.report_diagnostic(redundant_equals(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::ScalarIn) && !suppressed_rules.contains(&Rule::ScalarIn) {
+        checker.report_diagnostic(scalar_in(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::Seq) && !suppressed_rules.contains(&Rule::Seq) {
         checker.report_diagnostic(seq(r_expr)?);
     }
@@ -8,8 +8,11 @@ use crate::lints::any_duplicated::any_duplicated::any_duplicated;
 use crate::lints::any_is_na::any_is_na::any_is_na;
 use crate::lints::browser::browser::browser;
 use crate::lints::class_equals::class_equals::class_identical;
+use crate::lints::condition_message::condition_message::condition_message;
+use crate::lints::consecutive_assertion::consecutive_assertion::consecutive_assertion;
 use crate::lints::download_file::download_file::download_file;
 use crate::lints::duplicated_arguments::duplicated_arguments::duplicated_arguments;
+use crate::lints::expect_comparison::expect_comparison::expect_comparison;
 use crate::lints::expect_length::expect_length::expect_length;
 use crate::lints::expect_named::expect_named::expect_named;
 use crate::lints::expect_not::expect_not::expect_not;
@@ -19,17 +22,27 @@ use crate::lints::expect_true_false::expect_true_false::expect_true_false;
 use crate::lints::expect_type::expect_type::expect_type;
 use crate::lints::fixed_regex::fixed_regex::fixed_regex;
 use crate::lints::grepv::grepv::grepv;
+use crate::lints::ifelse_censor::ifelse_censor::ifelse_censor;
+use crate::lints::inner_combine::inner_combine::inner_combine;
 use crate::lints::length_levels::length_levels::length_levels;
 use crate::lints::length_test::length_test::length_test;
 use crate::lints::lengths::lengths::lengths;
 use crate::lints::list2df::list2df::list2df;
+use crate::lints::literal_coercion::literal_coercion::literal_coercion;
 use crate::lints::matrix_apply::matrix_apply::matrix_apply;
+use crate::lints::nested_ifelse::nested_ifelse::nested_ifelse;
 use crate::lints::outer_negation::outer_negation::outer_negation;
+use crate::lints::paste_sep::paste_sep::paste_sep;
 use crate::lints::redundant_ifelse::redundant_ifelse::redundant_ifelse;
+use crate::lints::rev_sort::rev_sort::rev_sort;
 use crate::lints::sample_int::sample_int::sample_int;
+use crate::lints::sapply_type::sapply_type::sapply_type;
 use crate::lints::seq2::seq2::seq2;
 use crate::lints::sprintf::sprintf::sprintf;
+use crate::lints::string_boundary::string_boundary::string_boundary_grepl;
 use crate::lints::system_file::system_file::system_file;
+use crate::lints::undesirable_function::undesirable_function::undesirable_function;
+use crate::lints::unnecessary_concatenation::unnecessary_concatenation::unnecessary_concatenation;
 use crate::lints::which_grepl::which_grepl::which_grepl;
 
 pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
@@ -56,6 +69,16 @@ pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
     {
         checker.report_diagnostic(class_identical(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::ConditionMessage)
+        && !suppressed_rules.contains(&Rule::ConditionMessage)
+    {
+        checker.report_diagnostic(condition_message(r_expr)?);
+    }
+    if checker.is_rule_enabled(Rule::ConsecutiveAssertion)
+        && !suppressed_rules.contains(&Rule::ConsecutiveAssertion)
+    {
+        checker.report_diagnostic(consecutive_assertion(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::DownloadFile)
         && !suppressed_rules.contains(&Rule::DownloadFile)
     {
@@ -64,7 +87,15 @@ pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::DuplicatedArguments)
         && !suppressed_rules.contains(&Rule::DuplicatedArguments)
     {
-        checker.report_diagnostic(duplicated_arguments(r_expr)?);
+        checker.report_diagnostic(duplicated_arguments(
+            r_expr,
+            checker.config.duplicated_arguments_partial_matching,
+        )?);
+    }
+    if checker.is_rule_enabled(Rule::ExpectComparison)
+        && !suppressed_rules.contains(&Rule::ExpectComparison)
+    {
+        checker.report_diagnostic(expect_comparison(r_expr)?);
     }
     if checker.is_rule_enabled(Rule::ExpectLength)
         && !suppressed_rules.contains(&Rule::ExpectLength)
@@ -100,6 +131,16 @@ pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::Grepv) && !suppressed_rules.contains(&Rule::Grepv) {
         checker.report_diagnostic(grepv(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::IfelseCensor)
+        && !suppressed_rules.contains(&Rule::IfelseCensor)
+    {
+        checker.report_diagnostic(ifelse_censor(r_expr)?);
+    }
+    if checker.is_rule_enabled(Rule::InnerCombine)
+        && !suppressed_rules.contains(&Rule::InnerCombine)
+    {
+        checker.report_diagnostic(inner_combine(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::LengthLevels)
         && !suppressed_rules.contains(&Rule::LengthLevels)
     {
@@ -114,32 +155,72 @@ pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::List2df) && !suppressed_rules.contains(&Rule::List2df) {
         checker.report_diagnostic(list2df(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::LiteralCoercion)
+        && !suppressed_rules.contains(&Rule::LiteralCoercion)
+    {
+        checker.report_diagnostic(literal_coercion(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::MatrixApply) && !suppressed_rules.contains(&Rule::MatrixApply)
     {
         checker.report_diagnostic(matrix_apply(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::NestedIfelse)
+        && !suppressed_rules.contains(&Rule::NestedIfelse)
+    {
+        checker.report_diagnostic(nested_ifelse(
+            r_expr,
+            checker.config.nested_ifelse_max_depth,
+        )?);
+    }
     if checker.is_rule_enabled(Rule::OuterNegation)
         && !suppressed_rules.contains(&Rule::OuterNegation)
     {
         checker.report_diagnostic(outer_negation(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::PasteSep) && !suppressed_rules.contains(&Rule::PasteSep) {
+        checker.report_diagnostic(paste_sep(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::RedundantIfelse)
         && !suppressed_rules.contains(&Rule::RedundantIfelse)
     {
         checker.report_diagnostic(redundant_ifelse(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::RevSort) && !suppressed_rules.contains(&Rule::RevSort) {
+        checker.report_diagnostic(rev_sort(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::SampleInt) && !suppressed_rules.contains(&Rule::SampleInt) {
         checker.report_diagnostic(sample_int(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::SapplyType) && !suppressed_rules.contains(&Rule::SapplyType) {
+        checker.report_diagnostic(sapply_type(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::Seq2) && !suppressed_rules.contains(&Rule::Seq2) {
         checker.report_diagnostic(seq2(r_expr)?);
     }
     if checker.is_rule_enabled(Rule::Sprintf) && !suppressed_rules.contains(&Rule::Sprintf) {
         checker.report_diagnostic(sprintf(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::StringBoundary)
+        && !suppressed_rules.contains(&Rule::StringBoundary)
+    {
+        checker.report_diagnostic(string_boundary_grepl(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::SystemFile) && !suppressed_rules.contains(&Rule::SystemFile) {
         checker.report_diagnostic(system_file(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::UndesirableFunction)
+        && !suppressed_rules.contains(&Rule::UndesirableFunction)
+    {
+        checker.report_diagnostic(undesirable_function(
+            r_expr,
+            &checker.config.undesirable_functions,
+        )?);
+    }
+    if checker.is_rule_enabled(Rule::UnnecessaryConcatenation)
+        && !suppressed_rules.contains(&Rule::UnnecessaryConcatenation)
+    {
+        checker.report_diagnostic(unnecessary_concatenation(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::WhichGrepl) && !suppressed_rules.contains(&Rule::WhichGrepl) {
         checker.report_diagnostic(which_grepl(r_expr)?);
     }
@@ -0,0 +1,122 @@
+use crate::check::Checker;
+use crate::rule_set::Rule;
+use air_r_syntax::RSyntaxNode;
+
+use crate::lints::brace_style::brace_style::brace_style;
+use crate::lints::commented_code::commented_code::commented_code;
+use crate::lints::duplicate_block::duplicate_block::duplicate_block;
+use crate::lints::library_call::library_call::library_call;
+use crate::lints::line_length::line_length::line_length;
+use crate::lints::pipe_consistency::pipe_consistency::pipe_consistency;
+use crate::lints::semicolon::semicolon::semicolon;
+use crate::lints::trailing_blank_lines::trailing_blank_lines::trailing_blank_lines;
+use crate::lints::trailing_whitespace::trailing_whitespace::trailing_whitespace;
+use crate::lints::undesirable_operator::undesirable_operator::undesirable_operator;
+
+/// Rules that need to look at the whole file at once instead of a single
+/// expression.
+pub fn document(root: &RSyntaxNode, checker: &mut Checker) -> anyhow::Result<()> {
+    if checker.is_rule_enabled(Rule::BraceStyle) {
+        for (node, diagnostic) in brace_style(root)? {
+            if !checker
+                .get_suppressed_rules(&node)
+                .contains(&Rule::BraceStyle)
+            {
+                checker.report_diagnostic(Some(diagnostic));
+            }
+        }
+    }
+    if checker.is_rule_enabled(Rule::CommentedCode) {
+        for (node, diagnostic) in commented_code(root)? {
+            if !checker
+                .get_suppressed_rules(&node)
+                .contains(&Rule::CommentedCode)
+            {
+                checker.report_diagnostic(Some(diagnostic));
+            }
+        }
+    }
+    if checker.is_rule_enabled(Rule::DuplicateBlock) {
+        for (node, diagnostic) in duplicate_block(root, checker.config.duplicate_block_min_size)? {
+            if !checker
+                .get_suppressed_rules(&node)
+                .contains(&Rule::DuplicateBlock)
+            {
+                checker.report_diagnostic(Some(diagnostic));
+            }
+        }
+    }
+    if checker.is_rule_enabled(Rule::LibraryCall) {
+        for (node, diagnostic) in library_call(root)? {
+            if !checker
+                .get_suppressed_rules(&node)
+                .contains(&Rule::LibraryCall)
+            {
+                checker.report_diagnostic(Some(diagnostic));
+            }
+        }
+    }
+    if checker.is_rule_enabled(Rule::LineLength) {
+        for (node, diagnostic) in line_length(root, checker.config.line_length_max)? {
+            if !checker
+                .get_suppressed_rules(&node)
+                .contains(&Rule::LineLength)
+            {
+                checker.report_diagnostic(Some(diagnostic));
+            }
+        }
+    }
+    if checker.is_rule_enabled(Rule::PipeConsistency) {
+        for (node, diagnostic) in pipe_consistency(root, checker.config.pipe_consistency_style)? {
+            if !checker
+                .get_suppressed_rules(&node)
+                .contains(&Rule::PipeConsistency)
+            {
+                checker.report_diagnostic(Some(diagnostic));
+            }
+        }
+    }
+    if checker.is_rule_enabled(Rule::Semicolon) {
+        for (node, diagnostic) in semicolon(root)? {
+            if !checker
+                .get_suppressed_rules(&node)
+                .contains(&Rule::Semicolon)
+            {
+                checker.report_diagnostic(Some(diagnostic));
+            }
+        }
+    }
+    if checker.is_rule_enabled(Rule::TrailingWhitespace) {
+        for (node, diagnostic) in trailing_whitespace(root)? {
+            if !checker
+                .get_suppressed_rules(&node)
+                .contains(&Rule::TrailingWhitespace)
+            {
+                checker.report_diagnostic(Some(diagnostic));
+            }
+        }
+    }
+    if checker.is_rule_enabled(Rule::TrailingBlankLines) {
+        for (node, diagnostic) in trailing_blank_lines(root)? {
+            if !checker
+                .get_suppressed_rules(&node)
+                .contains(&Rule::TrailingBlankLines)
+            {
+                checker.report_diagnostic(Some(diagnostic));
+            }
+        }
+    }
+    if checker.is_rule_enabled(Rule::UndesirableOperator) {
+        for (node, diagnostic) in undesirable_operator(root, &checker.config.undesirable_operators)?
+        {
+            if !checker
+                .get_suppressed_rules(&node)
+                .contains(&Rule::UndesirableOperator)
+            {
+                checker.report_diagnostic(Some(diagnostic));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -3,6 +3,8 @@ use crate::rule_set::Rule;
 use air_r_syntax::RFunctionDefinition;
 use biome_rowan::AstNode;
 
+use crate::lints::implicit_return::implicit_return::implicit_return;
+use crate::lints::unnecessary_lambda::unnecessary_lambda::unnecessary_lambda;
 use crate::lints::unreachable_code::unreachable_code::unreachable_code;
 
 pub fn function_definition(
@@ -14,6 +16,16 @@ pub fn function_definition(
     // Check suppressions once for this node
     let suppressed_rules = checker.get_suppressed_rules(node);
 
+    if checker.is_rule_enabled(Rule::ImplicitReturn)
+        && !suppressed_rules.contains(&Rule::ImplicitReturn)
+    {
+        checker.report_diagnostic(implicit_return(func)?);
+    }
+    if checker.is_rule_enabled(Rule::UnnecessaryLambda)
+        && !suppressed_rules.contains(&Rule::UnnecessaryLambda)
+    {
+        checker.report_diagnostic(unnecessary_lambda(func)?);
+    }
     if checker.is_rule_enabled(Rule::UnreachableCode)
         && !suppressed_rules.contains(&Rule::UnreachableCode)
     {
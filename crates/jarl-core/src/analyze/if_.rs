@@ -4,6 +4,7 @@ use air_r_syntax::RIfStatement;
 use biome_rowan::AstNode;
 
 use crate::lints::coalesce::coalesce::coalesce;
+use crate::lints::null_check_without_else::null_check_without_else::null_check_without_else;
 use crate::lints::unnecessary_nesting::unnecessary_nesting::unnecessary_nesting;
 
 pub fn if_(r_expr: &RIfStatement, checker: &mut Checker) -> anyhow::Result<()> {
@@ -15,6 +16,11 @@ pub fn if_(r_expr: &RIfStatement, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::Coalesce) && !suppressed_rules.contains(&Rule::Coalesce) {
         checker.report_diagnostic(coalesce(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::NullCheckWithoutElse)
+        && !suppressed_rules.contains(&Rule::NullCheckWithoutElse)
+    {
+        checker.report_diagnostic(null_check_without_else(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::UnnecessaryNesting)
         && !suppressed_rules.contains(&Rule::UnnecessaryNesting)
     {
@@ -3,6 +3,7 @@ use crate::rule_set::Rule;
 use air_r_syntax::RSubset;
 use biome_rowan::AstNode;
 
+use crate::lints::order_mismatch::order_mismatch::order_mismatch;
 use crate::lints::sort::sort::sort;
 
 pub fn subset(r_expr: &RSubset, checker: &mut Checker) -> anyhow::Result<()> {
@@ -11,6 +12,11 @@ pub fn subset(r_expr: &RSubset, checker: &mut Checker) -> anyhow::Result<()> {
     // Check suppressions once for this node
     let suppressed_rules = checker.get_suppressed_rules(node);
 
+    if checker.is_rule_enabled(Rule::OrderMismatch)
+        && !suppressed_rules.contains(&Rule::OrderMismatch)
+    {
+        checker.report_diagnostic(order_mismatch(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::Sort) && !suppressed_rules.contains(&Rule::Sort) {
         checker.report_diagnostic(sort(r_expr)?);
     }
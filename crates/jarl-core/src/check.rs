@@ -1,3 +1,4 @@
+use crate::directive::find_assignment_directive;
 use crate::error::ParseError;
 use crate::rule_set::Rule;
 use crate::suppression::SuppressionManager;
@@ -5,8 +6,8 @@ use crate::vcs::check_version_control;
 use air_fs::relativize_path;
 use air_r_parser::RParserOptions;
 use air_r_syntax::{
-    AnyRExpression, RBinaryExpressionFields, RForStatementFields, RIfStatementFields, RSyntaxKind,
-    RWhileStatementFields,
+    AnyRExpression, RBinaryExpressionFields, RExpressionList, RForStatementFields,
+    RIfStatementFields, RSyntaxKind, RSyntaxNode, RWhileStatementFields,
 };
 use anyhow::{Context, Result};
 use rayon::prelude::*;
@@ -16,28 +17,27 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::analyze;
+use crate::chunks::find_r_chunks;
 use crate::config::Config;
 use crate::diagnostic::*;
 use crate::fix::*;
+use crate::fs::has_markdown_extension;
+use crate::roxygen::find_roxygen_examples;
 use crate::rule_set::RuleSet;
 use crate::utils::*;
 
 pub fn check(config: Config) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Error>)> {
-    // Ensure that all paths are covered by VCS. This is conservative because
-    // technically we could apply fixes on those that are covered by VCS and
-    // error for the others, but I'd rather be on the safe side and force the
-    // user to deal with that before applying any fixes.
-    if (config.apply_fixes || config.apply_unsafe_fixes) && !config.paths.is_empty() {
-        let path_strings: Vec<String> = config.paths.iter().map(relativize_path).collect();
-        if let Err(e) = check_version_control(&path_strings, &config) {
-            let first_path = path_strings.first().unwrap().clone();
-            return vec![(first_path, Err(e))];
-        }
+    if let Err(error) = ensure_version_control(&config) {
+        return vec![error];
     }
 
     // Wrap config in Arc to avoid expensive clones in parallel execution
     let config = Arc::new(config);
 
+    if config.atomic && (config.apply_fixes || config.apply_unsafe_fixes) {
+        return check_atomic(vec![config]);
+    }
+
     config
         .paths
         .par_iter()
@@ -48,6 +48,112 @@ pub fn check(config: Config) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Err
         .collect()
 }
 
+/// Like `check()`, but for files that are checked with different
+/// configurations, e.g. because they are covered by different `jarl.toml`.
+/// With `atomic`, the fixes are applied on the files of all configurations or
+/// on none of them.
+pub fn check_all(configs: Vec<Config>) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Error>)> {
+    let is_atomic = configs
+        .iter()
+        .any(|config| config.atomic && (config.apply_fixes || config.apply_unsafe_fixes));
+
+    if !is_atomic {
+        return configs.into_iter().flat_map(check).collect();
+    }
+
+    for config in &configs {
+        if let Err(error) = ensure_version_control(config) {
+            return vec![error];
+        }
+    }
+
+    check_atomic(configs.into_iter().map(Arc::new).collect())
+}
+
+/// Ensure that all paths are covered by VCS before applying fixes. This is
+/// conservative because technically we could apply fixes on those that are
+/// covered by VCS and error for the others, but I'd rather be on the safe side
+/// and force the user to deal with that before applying any fixes.
+fn ensure_version_control(config: &Config) -> Result<(), (String, anyhow::Error)> {
+    if (config.apply_fixes || config.apply_unsafe_fixes) && !config.paths.is_empty() {
+        let path_strings: Vec<String> = config.paths.iter().map(relativize_path).collect();
+        if let Err(e) = check_version_control(&path_strings, config) {
+            let first_path = path_strings.first().unwrap().clone();
+            return Err((first_path, e));
+        }
+    }
+    Ok(())
+}
+
+/// Like `check()` with fixes, but the fixed content of all files is computed
+/// in memory before writing any of them. If a write fails, the files that were
+/// already written are restored, so that the fixes are applied on all files or
+/// on none of them.
+fn check_atomic(
+    configs: Vec<Arc<Config>>,
+) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Error>)> {
+    let files = configs
+        .iter()
+        .flat_map(|config| config.paths.iter().map(move |file| (file, config)))
+        .collect::<Vec<_>>();
+
+    let results = files
+        .into_par_iter()
+        .map(|(file, config)| {
+            let path = relativize_path(file);
+            let res = fs::read_to_string(Path::new(&path))
+                .with_context(|| format!("Failed to read file: {path}"))
+                .and_then(|contents| {
+                    let (checks, fixed) = check_contents(&contents, Path::new(&path), config)?;
+                    Ok((checks, contents, fixed))
+                });
+            (path, res)
+        })
+        .collect::<Vec<_>>();
+
+    let files_to_write = results
+        .iter()
+        .filter_map(|(path, res)| match res {
+            Ok((_, original, fixed)) if original != fixed => {
+                Some(FileFix { path: Path::new(path), original, fixed })
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if let Err(e) =
+        write_fixes_atomically(&files_to_write, |path, contents| fs::write(path, contents))
+    {
+        let first_path = files_to_write[0].path.display().to_string();
+        return vec![(first_path, Err(e))];
+    }
+
+    results
+        .into_iter()
+        .map(|(path, res)| (path, res.map(|(checks, _, _)| checks)))
+        .collect()
+}
+
+/// Like `check()` with fixes, but the files are not modified. This returns the
+/// original and the fixed content of each file, e.g. to show the changes that
+/// `--fix` would make. `config` must have `apply_fixes` or `apply_unsafe_fixes`.
+pub fn check_diff(config: Config) -> Vec<(String, Result<(String, String), anyhow::Error>)> {
+    config
+        .paths
+        .par_iter()
+        .map(|file| {
+            let path = relativize_path(file);
+            let res = fs::read_to_string(Path::new(&path))
+                .with_context(|| format!("Failed to read file: {path}"))
+                .and_then(|contents| {
+                    let (_, fixed) = check_contents(&contents, Path::new(&path), &config)?;
+                    Ok((contents, fixed))
+                });
+            (path, res)
+        })
+        .collect()
+}
+
 pub fn check_path(path: &PathBuf, config: Arc<Config>) -> Result<Vec<Diagnostic>, anyhow::Error> {
     if config.apply_fixes || config.apply_unsafe_fixes {
         lint_fix(path, config)
@@ -69,34 +175,59 @@ pub fn lint_only(path: &PathBuf, config: Arc<Config>) -> Result<Vec<Diagnostic>,
 
 pub fn lint_fix(path: &PathBuf, config: Arc<Config>) -> Result<Vec<Diagnostic>, anyhow::Error> {
     let path = relativize_path(path);
+    let contents = fs::read_to_string(Path::new(&path))
+        .with_context(|| format!("Failed to read file: {path}",))?;
 
-    let mut has_skipped_fixes = true;
-    let mut checks: Vec<Diagnostic>;
+    let (checks, fixed) = check_contents(&contents, Path::new(&path), &config)?;
 
-    loop {
-        let contents = fs::read_to_string(Path::new(&path))
-            .with_context(|| format!("Failed to read file: {path}",))?;
+    if fixed != contents {
+        fs::write(&path, fixed).with_context(|| format!("Failed to write file: {path}",))?;
+    }
 
-        checks = get_checks(&contents, &PathBuf::from(&path), &config)
-            .with_context(|| format!("Failed to get checks for file: {path}",))?;
+    Ok(checks)
+}
 
-        if !has_skipped_fixes {
-            break;
-        }
+/// Lint some R code that doesn't come from a file on disk, e.g. code read from
+/// stdin. `path` is only used as the filename of the diagnostics.
+///
+/// If the user asked for fixes, they are applied on `contents` and the fixed
+/// code is returned alongside the remaining diagnostics. Otherwise, `contents`
+/// is returned unchanged.
+pub fn check_contents(
+    contents: &str,
+    path: &Path,
+    config: &Config,
+) -> Result<(Vec<Diagnostic>, String), anyhow::Error> {
+    let lint = |contents: &str| {
+        get_checks(contents, path, config)
+            .with_context(|| format!("Failed to get checks for file: {}", path.display()))
+    };
+
+    if !(config.apply_fixes || config.apply_unsafe_fixes) {
+        return Ok((lint(contents)?, contents.to_string()));
+    }
 
-        let (new_has_skipped_fixes, fixed_text) = apply_fixes(&checks, &contents);
-        has_skipped_fixes = new_has_skipped_fixes;
+    fix_until_stable(contents, config.fix_iterations, lint)
+}
 
-        fs::write(&path, fixed_text).with_context(|| format!("Failed to write file: {path}",))?;
-    }
+/// Rules that are run on some R code. These are the rules of the
+/// configuration, unless the whole file is skipped, e.g. because it was
+/// generated by another tool.
+pub fn active_rules(contents: &str, config: &Config) -> RuleSet {
+    let parsed = air_r_parser::parse(contents, RParserOptions::default());
+    let syntax = &parsed.syntax();
 
-    Ok(checks)
+    if SuppressionManager::from_node(syntax, contents).should_skip_file(syntax) {
+        RuleSet::empty()
+    } else {
+        config.rules_to_apply.clone()
+    }
 }
 
 #[derive(Debug)]
 // The object that will collect diagnostics in check_expressions(). One per
 // analyzed file.
-pub struct Checker {
+pub struct Checker<'a> {
     // The diagnostics to report (possibly empty).
     pub diagnostics: Vec<Diagnostic>,
     // A set of rules to apply. Each rule contains metadata about whether it
@@ -109,16 +240,20 @@ pub struct Checker {
     pub suppression: SuppressionManager,
     // Which assignment operator is preferred?
     pub assignment: RSyntaxKind,
+    // The resolved configuration of the file, including the options of rules
+    // such as `line_length_max` or `undesirable_functions`.
+    pub config: &'a Config,
 }
 
-impl Checker {
-    fn new(suppression: SuppressionManager, assignment: RSyntaxKind) -> Self {
+impl<'a> Checker<'a> {
+    fn new(suppression: SuppressionManager, assignment: RSyntaxKind, config: &'a Config) -> Self {
         Self {
             diagnostics: vec![],
             rule_set: RuleSet::empty(),
             minimum_r_version: None,
             suppression,
             assignment,
+            config,
         }
     }
 
@@ -183,12 +318,24 @@ impl Checker {
     }
 }
 
+/// Parse the R code and return its syntax tree, i.e. the nodes traversed by
+/// the rules. This is only meant to debug rules with `jarl dump-ast`, so the
+/// tree is returned even if the code contains syntax errors.
+pub fn dump_ast(contents: &str) -> String {
+    let parsed = air_r_parser::parse(contents, RParserOptions::default());
+    format!("{:#?}", parsed.syntax())
+}
+
 // Takes the R code as a string, parses it, and obtains a (possibly empty)
 // vector of `Diagnostic`s.
 //
 // If there are diagnostics to report, this is also where their range in the
 // string is converted to their location (row, column).
 pub fn get_checks(contents: &str, file: &Path, config: &Config) -> Result<Vec<Diagnostic>> {
+    if has_markdown_extension(file) {
+        return get_checks_in_chunks(contents, file, config);
+    }
+
     let parser_options = RParserOptions::default();
     let parsed = air_r_parser::parse(contents, parser_options);
 
@@ -206,12 +353,56 @@ pub fn get_checks(contents: &str, file: &Path, config: &Config) -> Result<Vec<Di
         return Ok(vec![]);
     }
 
-    let mut checker = Checker::new(suppression, config.assignment);
+    let mut diagnostics =
+        get_checks_in_syntax(syntax, expressions, suppression, contents, file, config)?;
+
+    if config.roxygen_examples {
+        diagnostics.extend(get_checks_in_roxygen_examples(contents, file, config)?);
+    }
+
+    let loc_new_lines = find_new_lines(syntax)?;
+    let diagnostics = compute_lints_location(diagnostics, &loc_new_lines);
+    let diagnostics = if config.show_context {
+        compute_enclosing_functions(diagnostics, syntax)
+    } else {
+        diagnostics
+    };
+
+    Ok(diagnostics)
+}
+
+// Run the rules on the parsed R code and filter out the fixes that must not be
+// applied. The diagnostics don't have a location yet.
+fn get_checks_in_syntax(
+    syntax: &RSyntaxNode,
+    expressions: &RExpressionList,
+    suppression: SuppressionManager,
+    contents: &str,
+    file: &Path,
+    config: &Config,
+) -> Result<Vec<Diagnostic>> {
+    // A file can override the preferred assignment operator with a directive
+    // at the top, e.g. `# jarl: assignment = "="`.
+    let assignment = match find_assignment_directive(contents) {
+        Some("=") => RSyntaxKind::EQUAL,
+        Some("<-") => RSyntaxKind::ASSIGN,
+        _ => config.assignment,
+    };
+
+    let mut checker = Checker::new(suppression, assignment, config);
     checker.rule_set = config.rules_to_apply.clone();
     checker.minimum_r_version = config.minimum_r_version;
     for expr in expressions {
         check_expression(&expr, &mut checker)?;
     }
+    analyze::document::document(syntax, &mut checker)?;
+
+    // Unknown rules in `# nolint` directives would otherwise silently
+    // suppress nothing.
+    if checker.is_rule_enabled(Rule::UnknownNolintRule) {
+        let unknown_rules = checker.suppression.unknown_rules_diagnostics(syntax);
+        checker.diagnostics.extend(unknown_rules);
+    }
 
     // Some rules have a fix available in their implementation but do not have
     // fix in the config, for instance because they are part of the "unfixable"
@@ -229,8 +420,10 @@ pub fn get_checks(contents: &str, file: &Path, config: &Config) -> Result<Vec<Di
     let diagnostics: Vec<Diagnostic> = checker
         .diagnostics
         .into_iter()
+        .filter(|x| x.confidence >= config.min_confidence)
         .map(|mut x| {
             x.filename = file.to_path_buf();
+            x.severity = config.severity.get(&x.message.name).copied().or(x.severity);
             // Check if fix should be skipped based on fixable/unfixable settings
             if rules_without_fix.contains(&x.message.name) {
                 x.fix = Fix::empty();
@@ -250,16 +443,123 @@ pub fn get_checks(contents: &str, file: &Path, config: &Config) -> Result<Vec<Di
             if x.fix.to_skip {
                 x.fix = Fix::empty();
             }
+            // When fixing, only keep the fixes whose applicability matches
+            // what the user asked for: `--fix` only applies fixes that are
+            // always correct, `--unsafe-fixes` also applies the others.
+            if (config.apply_fixes || config.apply_unsafe_fixes)
+                && !x.applicability.is_applied(config.apply_unsafe_fixes)
+            {
+                x.fix = Fix::empty();
+            }
             x
         })
         .collect();
 
-    let loc_new_lines = find_new_lines(syntax)?;
-    let diagnostics = compute_lints_location(diagnostics, &loc_new_lines);
+    Ok(diagnostics)
+}
+
+// Check the code in the `@examples` and `@examplesIf` sections of roxygen2
+// comments. The ranges of the diagnostics (and of their fixes) are converted
+// to ranges in the original file. Examples that can't be parsed are skipped
+// since they are not necessarily valid R code.
+fn get_checks_in_roxygen_examples(
+    contents: &str,
+    file: &Path,
+    config: &Config,
+) -> Result<Vec<Diagnostic>> {
+    let mut diagnostics = vec![];
+
+    for examples in find_roxygen_examples(contents) {
+        let parsed = air_r_parser::parse(&examples.code, RParserOptions::default());
+        if parsed.has_error() {
+            continue;
+        }
+
+        let syntax = &parsed.syntax();
+        let expressions = &parsed.tree().expressions();
+        let suppression = SuppressionManager::from_node(syntax, &examples.code);
+
+        let checks = get_checks_in_syntax(
+            syntax,
+            expressions,
+            suppression,
+            &examples.code,
+            file,
+            config,
+        )?;
+
+        let checks = checks
+            .into_iter()
+            .filter(|x| !Rule::from_name(&x.message.name).is_some_and(Rule::checks_lines));
+
+        diagnostics.extend(checks.map(|mut x| {
+            x.range = examples.file_range(x.range);
+            // Fixes spanning several lines would lose the `#'` at the start
+            // of each line.
+            if !examples.is_single_line(x.fix.start, x.fix.end) || x.fix.content.contains('\n') {
+                x.fix = Fix::empty();
+            } else if !x.fix.to_skip {
+                x.fix.start = examples.file_offset(x.fix.start);
+                x.fix.end = examples.file_offset(x.fix.end);
+            }
+            x
+        }));
+    }
 
     Ok(diagnostics)
 }
 
+// Check the R chunks of an R Markdown or Quarto document. Each chunk is
+// checked separately, and the ranges of the diagnostics (and of their fixes)
+// are converted to ranges in the original file. As for roxygen2 examples,
+// chunks that can't be parsed are skipped, e.g. chunks with `eval=FALSE` that
+// show invalid code on purpose.
+fn get_checks_in_chunks(contents: &str, file: &Path, config: &Config) -> Result<Vec<Diagnostic>> {
+    let mut diagnostics = vec![];
+
+    for chunk in find_r_chunks(contents) {
+        let parsed = air_r_parser::parse(&chunk.code, RParserOptions::default());
+        if parsed.has_error() {
+            continue;
+        }
+
+        let syntax = &parsed.syntax();
+        let expressions = &parsed.tree().expressions();
+        let suppression = SuppressionManager::from_node(syntax, &chunk.code);
+        if suppression.should_skip_file(syntax) {
+            continue;
+        }
+
+        let checks =
+            get_checks_in_syntax(syntax, expressions, suppression, &chunk.code, file, config)?;
+        let checks = if config.show_context {
+            compute_enclosing_functions(checks, syntax)
+        } else {
+            checks
+        };
+
+        // The end of a chunk is not the end of the file.
+        let checks = checks
+            .into_iter()
+            .filter(|x| x.message.name != Rule::TrailingBlankLines.name());
+
+        diagnostics.extend(checks.map(|mut x| {
+            x.range = chunk.file_range(x.range);
+            if !x.fix.to_skip {
+                x.fix.start = chunk.file_offset(x.fix.start);
+                x.fix.end = chunk.file_offset(x.fix.end);
+            }
+            x
+        }));
+    }
+
+    let loc_new_lines = contents
+        .match_indices('\n')
+        .map(|x| x.0)
+        .collect::<Vec<_>>();
+    Ok(compute_lints_location(diagnostics, &loc_new_lines))
+}
+
 // This function does two things:
 // - dispatch an expression to its appropriate set of rules, e.g. binary
 //   expressions are sent to the rules stored in
@@ -0,0 +1,183 @@
+//! Extraction of the R code chunks of R Markdown and Quarto documents, so that
+//! they can be checked like R files.
+//!
+//! Only fenced chunks (```` ```{r} ````) are extracted. Inline code
+//! (`` `r expr` ``) is not checked.
+
+use biome_rowan::{TextRange, TextSize};
+
+/// The code of an R chunk. The code of a chunk is a contiguous part of the
+/// file, so converting an offset in the chunk to an offset in the file only
+/// requires the offset of the start of the chunk.
+#[derive(Debug, PartialEq)]
+pub struct RChunk {
+    /// The code between the opening and closing fences of the chunk.
+    pub code: String,
+    /// Offset of the start of `code` in the original file.
+    start: usize,
+}
+
+impl RChunk {
+    /// Convert an offset in `code` to an offset in the original file.
+    pub fn file_offset(&self, offset: usize) -> usize {
+        self.start + offset
+    }
+
+    /// Convert a range in `code` to a range in the original file.
+    pub fn file_range(&self, range: TextRange) -> TextRange {
+        let start = TextSize::from(self.start as u32);
+        TextRange::new(range.start() + start, range.end() + start)
+    }
+}
+
+/// Find all R chunks of an R Markdown or Quarto document, e.g.
+///
+/// ````md
+/// ```{r setup, echo=FALSE}
+/// library(dplyr)
+/// ```
+/// ````
+///
+/// A chunk ends at the next fence made of at least as many backticks as its
+/// opening fence, or at the end of the file. Chunks of other languages (e.g.
+/// `{python}`) and code blocks that are not run (e.g. ```` ```r ````) are
+/// skipped.
+pub fn find_r_chunks(contents: &str) -> Vec<RChunk> {
+    let mut chunks = vec![];
+    // Number of backticks of the opening fence of the current block, and
+    // offset of the start of its code if it is an R chunk.
+    let mut current: Option<(usize, Option<usize>)> = None;
+    let mut line_start = 0;
+
+    for line in contents.split_inclusive('\n') {
+        let offset = line_start;
+        line_start += line.len();
+
+        let trimmed = line.trim();
+        let n_backticks = trimmed.len() - trimmed.trim_start_matches('`').len();
+        if n_backticks < 3 {
+            continue;
+        }
+
+        match current {
+            Some((opening_backticks, code_start)) => {
+                if n_backticks >= opening_backticks && trimmed.len() == n_backticks {
+                    if let Some(start) = code_start {
+                        chunks.push(RChunk { code: contents[start..offset].to_string(), start });
+                    }
+                    current = None;
+                }
+            }
+            None => {
+                let info = &trimmed[n_backticks..];
+                let code_start = is_r_chunk_header(info).then_some(line_start);
+                current = Some((n_backticks, code_start));
+            }
+        }
+    }
+
+    // A chunk that isn't closed goes until the end of the file.
+    if let Some((_, Some(start))) = current {
+        chunks.push(RChunk { code: contents[start..].to_string(), start });
+    }
+
+    chunks
+}
+
+/// Whether the info string after the opening fence is the one of an R chunk,
+/// e.g. `{r}`, `{r label}`, or `{R, echo=FALSE}`.
+fn is_r_chunk_header(info: &str) -> bool {
+    let Some(header) = info.trim_start().strip_prefix('{') else {
+        return false;
+    };
+    let Some(rest) = header
+        .trim_start()
+        .strip_prefix('r')
+        .or_else(|| header.trim_start().strip_prefix('R'))
+    else {
+        return false;
+    };
+    rest.starts_with(['}', ' ', ','])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_r_chunks() {
+        let contents = "\
+---
+title: Test
+---
+
+Some text with `any(is.na(x))`.
+
+```{r setup, include=FALSE}
+library(dplyr)
+```
+
+More text.
+
+```{R}
+x <- 1
+any(is.na(x))
+```
+";
+        let chunks = find_r_chunks(contents);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].code, "library(dplyr)\n");
+        assert_eq!(chunks[1].code, "x <- 1\nany(is.na(x))\n");
+
+        let any = contents.rfind("any(is.na(x))").unwrap();
+        assert_eq!(chunks[1].file_offset(7), any);
+        assert_eq!(
+            chunks[1].file_range(TextRange::new(7.into(), 20.into())),
+            TextRange::new((any as u32).into(), (any as u32 + 13).into())
+        );
+    }
+
+    #[test]
+    fn test_find_r_chunks_skips_other_blocks() {
+        let contents = "\
+```r
+x = 1
+```
+
+```{python}
+x = 1
+```
+
+```{rcpp}
+int x = 1;
+```
+
+````md
+```{r}
+x = 1
+```
+````
+
+   ```{r}
+   y <- 2
+   ```
+";
+        let chunks = find_r_chunks(contents);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].code, "   y <- 2\n");
+    }
+
+    #[test]
+    fn test_find_r_chunks_unclosed() {
+        let contents = "```{r}\nx <- 1\n";
+        let chunks = find_r_chunks(contents);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].code, "x <- 1\n");
+    }
+
+    #[test]
+    fn test_find_r_chunks_empty() {
+        assert!(find_r_chunks("Just text.\n").is_empty());
+        assert_eq!(find_r_chunks("```{r}\n```\n")[0].code, "");
+    }
+}
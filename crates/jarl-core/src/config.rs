@@ -1,5 +1,8 @@
 use crate::{
     description::Description,
+    diagnostic::{Confidence, Severity},
+    discovery::get_user_config_dir,
+    fix::DEFAULT_FIX_ITERATIONS,
     lints::all_rules_enabled_by_default,
     rule_set::{Category, Rule, RuleSet},
     settings::Settings,
@@ -7,7 +10,118 @@ use crate::{
 use air_r_syntax::RSyntaxKind;
 use air_workspace::resolve::PathResolver;
 use anyhow::Result;
-use std::{collections::HashSet, fs, path::PathBuf};
+use serde::{Serialize, Serializer};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Default minimum number of statements in blocks reported by
+/// `duplicate_block`.
+pub const DEFAULT_DUPLICATE_BLOCK_MIN_SIZE: usize = 3;
+
+/// Default maximum number of nested `ifelse()` calls allowed by
+/// `nested_ifelse`.
+pub const DEFAULT_NESTED_IFELSE_MAX_DEPTH: usize = 2;
+
+/// Default maximum number of characters in a line allowed by `line_length`.
+pub const DEFAULT_LINE_LENGTH_MAX: usize = 80;
+
+/// Default functions reported by `undesirable_function`, with the message
+/// displayed when they are used.
+pub const DEFAULT_UNDESIRABLE_FUNCTIONS: [(&str, &str); 4] = [
+    (
+        "attach",
+        "Refer to the variables explicitly, `attach()` modifies the search path.",
+    ),
+    (
+        "sapply",
+        "Use `vapply()` or `lapply()`, whose output type is predictable.",
+    ),
+    (
+        "setwd",
+        "Use paths relative to the project instead of changing the working directory.",
+    ),
+    (
+        "source",
+        "Put the code in functions, for instance in a package, instead of sourcing it.",
+    ),
+];
+
+/// Build the map of undesirable functions from [DEFAULT_UNDESIRABLE_FUNCTIONS].
+pub fn default_undesirable_functions() -> HashMap<String, String> {
+    DEFAULT_UNDESIRABLE_FUNCTIONS
+        .iter()
+        .map(|(name, message)| (name.to_string(), message.to_string()))
+        .collect()
+}
+
+/// Default operators reported by `undesirable_operator`, with the message
+/// displayed when they are used.
+pub const DEFAULT_UNDESIRABLE_OPERATORS: [(&str, &str); 1] = [(
+    ":::",
+    "Internal functions of a package can change or disappear in any version, use exported functions instead.",
+)];
+
+/// Build the map of undesirable operators from [DEFAULT_UNDESIRABLE_OPERATORS].
+pub fn default_undesirable_operators() -> HashMap<String, String> {
+    DEFAULT_UNDESIRABLE_OPERATORS
+        .iter()
+        .map(|(operator, message)| (operator.to_string(), message.to_string()))
+        .collect()
+}
+
+/// Naming styles of the names checked by `object_name`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum NamingStyle {
+    /// `snake_case`
+    SnakeCase,
+    /// `camelCase`
+    CamelCase,
+    /// `CamelCase`, with a leading uppercase letter
+    UpperCamelCase,
+    /// `dotted.case`
+    DottedCase,
+    /// `UPPER_SNAKE`
+    UpperSnake,
+}
+
+impl NamingStyle {
+    pub const ALL: [NamingStyle; 5] = [
+        NamingStyle::SnakeCase,
+        NamingStyle::CamelCase,
+        NamingStyle::UpperCamelCase,
+        NamingStyle::DottedCase,
+        NamingStyle::UpperSnake,
+    ];
+
+    /// The name of the style, as written in `jarl.toml`.
+    pub fn name(self) -> &'static str {
+        match self {
+            NamingStyle::SnakeCase => "snake_case",
+            NamingStyle::CamelCase => "camelCase",
+            NamingStyle::UpperCamelCase => "CamelCase",
+            NamingStyle::DottedCase => "dotted.case",
+            NamingStyle::UpperSnake => "UPPER_SNAKE",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|style| style.name() == name)
+    }
+}
+
+/// Pipe operator enforced by `pipe_consistency`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum PipeStyle {
+    /// Use the pipe that is used the most in each file.
+    Auto,
+    /// `|>`
+    Native,
+    /// `%>%`
+    Magrittr,
+}
 
 /// Parsed rule selection from CLI or TOML configuration.
 /// Contains selected rules, extended rules, and ignored rules.
@@ -45,11 +159,29 @@ pub struct ArgsConfig {
     pub allow_no_vcs: bool,
     /// Which assignment operator to use? Can be `"<-"` or `"="`.
     pub assignment: Option<String>,
+    /// Did the user pass the --show-context flag?
+    pub show_context: bool,
+    /// Did the user pass the --atomic flag?
+    pub atomic: bool,
+    /// Name of the single rule to run, passed with `--only`. This bypasses
+    /// the rule selection and the minimum R version.
+    pub only: Option<String>,
+    /// Did the user pass the --roxygen-examples flag?
+    pub roxygen_examples: bool,
+    /// Minimum confidence of the violations to report, passed with
+    /// `--min-confidence`. Can be `"high"` or `"heuristic"`.
+    pub min_confidence: Option<String>,
+    /// Maximum number of passes made to apply fixes, passed with
+    /// `--fix-iterations`.
+    pub fix_iterations: Option<usize>,
 }
 
-#[derive(Clone)]
+/// The serialized configuration is used as the key of the cache, so maps and
+/// sets are serialized in a deterministic order.
+#[derive(Clone, Serialize)]
 pub struct Config {
     /// Paths to files to lint.
+    #[serde(skip)]
     pub paths: Vec<PathBuf>,
     /// List of rules and whether they have an associated safe fix, passed by
     /// the user and/or recovered from the config file. Those will
@@ -67,60 +199,221 @@ pub struct Config {
     /// that require functions that are not available in all R versions, e.g.
     /// grepv() introduced in R 4.5.0.
     pub minimum_r_version: Option<(u32, u32, u32)>,
+    /// Selected rules that were disabled because they require a specific R
+    /// version and the minimum R version of the project is unknown.
+    pub version_gated_rules: RuleSet,
     /// Apply fixes even if the Git branch still has uncommitted files?
     pub allow_dirty: bool,
     /// Apply fixes even if there is no version control system?
     pub allow_no_vcs: bool,
     /// Which assignment operator to use? Can be `RSyntaxKind::ASSIGN` or
     /// `RSyntaxKind::EQUAL`.
+    #[serde(serialize_with = "serialize_debug")]
     pub assignment: RSyntaxKind,
     /// Rules that should not have their fixes applied (from unfixable setting)
+    #[serde(serialize_with = "serialize_sorted_set")]
     pub unfixable: HashSet<String>,
     /// Rules that are allowed to have fixes applied (from fixable setting)
     /// None means all rules with fixes can be applied
+    #[serde(serialize_with = "serialize_sorted_optional_set")]
     pub fixable: Option<HashSet<String>>,
+    /// Severity of each rule (from severity setting). Rules that are not in
+    /// this map don't have a configured severity.
+    #[serde(serialize_with = "serialize_sorted_map")]
+    pub severity: HashMap<String, Severity>,
+    /// Should diagnostics store the name of the function they are in?
+    pub show_context: bool,
+    /// Should fixes be applied on all files or on none of them?
+    pub atomic: bool,
+    /// Minimum number of statements in blocks reported by `duplicate_block`.
+    pub duplicate_block_min_size: usize,
+    /// Functions reported by `undesirable_function`, with the message
+    /// displayed when they are used.
+    #[serde(serialize_with = "serialize_sorted_map")]
+    pub undesirable_functions: HashMap<String, String>,
+    /// Operators reported by `undesirable_operator`, with the message
+    /// displayed when they are used.
+    #[serde(serialize_with = "serialize_sorted_map")]
+    pub undesirable_operators: HashMap<String, String>,
+    /// Maximum number of nested `ifelse()` calls allowed by `nested_ifelse`.
+    pub nested_ifelse_max_depth: usize,
+    /// Should `duplicated_arguments` also report partially matching argument
+    /// names?
+    pub duplicated_arguments_partial_matching: bool,
+    /// Quote that `quotes` expects around strings, either `"` or `'`.
+    pub preferred_quote: char,
+    /// Maximum number of characters in a line allowed by `line_length`.
+    pub line_length_max: usize,
+    /// Should `infix_spaces` also report `^` and `**` without spaces?
+    pub infix_spaces_exponent: bool,
+    /// Naming styles accepted by `object_name`.
+    pub object_name_styles: Vec<NamingStyle>,
+    /// Pipe operator enforced by `pipe_consistency`.
+    pub pipe_consistency_style: PipeStyle,
+    /// Should the code in roxygen2 `@examples` sections be checked?
+    pub roxygen_examples: bool,
+    /// Violations with a lower confidence than this are not reported.
+    pub min_confidence: Confidence,
+    /// Maximum number of passes made to apply fixes. Fixing stops earlier if
+    /// the content doesn't change anymore.
+    pub fix_iterations: usize,
+}
+
+fn serialize_debug<T: std::fmt::Debug, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{value:?}"))
+}
+
+fn serialize_sorted_set<S: Serializer>(
+    set: &HashSet<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    set.iter().collect::<BTreeSet<_>>().serialize(serializer)
+}
+
+fn serialize_sorted_optional_set<S: Serializer>(
+    set: &Option<HashSet<String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    set.as_ref()
+        .map(|set| set.iter().collect::<BTreeSet<_>>())
+        .serialize(serializer)
+}
+
+fn serialize_sorted_map<V: Serialize, S: Serializer>(
+    map: &HashMap<String, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
 }
 
+/// Build the configuration used to check all `paths` with the same settings,
+/// e.g. because they come from `--config` or because there is a single
+/// `jarl.toml`. If several `jarl.toml` were found, the one that applies to
+/// the first path is used. Use [build_configs()] to check each file with the
+/// closest `jarl.toml`.
 pub fn build_config(
     check_config: &ArgsConfig,
     resolver: &PathResolver<Settings>,
     paths: Vec<PathBuf>,
 ) -> Result<Config> {
-    let root_path = resolver
-        .items()
-        .iter()
-        .map(|x| x.path())
-        .collect::<Vec<_>>();
+    let toml_settings = if resolver.items().len() > 1 {
+        paths
+            .first()
+            .and_then(|path| resolve_settings(resolver, path))
+            .map(|(_directory, settings)| settings)
+    } else {
+        resolver.items().first().map(|item| item.value())
+    };
+
+    build_config_with_settings(check_config, toml_settings, paths)
+}
 
-    if root_path.len() > 1 {
-        todo!("Don't know how to handle multiple TOML")
+/// Build one configuration per `jarl.toml` that applies to `paths`. Each file
+/// is checked with the settings of the closest `jarl.toml` in its directory or
+/// in its parent directories, so nested projects can select different rules.
+/// Files that aren't covered by any `jarl.toml` use the default settings.
+///
+/// The configurations are sorted by the directory of their `jarl.toml`, and
+/// the files keep their order within each configuration.
+pub fn build_configs(
+    check_config: &ArgsConfig,
+    resolver: &PathResolver<Settings>,
+    paths: Vec<PathBuf>,
+) -> Result<Vec<Config>> {
+    let mut groups: BTreeMap<Option<&Path>, (Option<&Settings>, Vec<PathBuf>)> = BTreeMap::new();
+
+    for path in paths {
+        let resolved = resolve_settings(resolver, &path);
+        groups
+            .entry(resolved.map(|(directory, _settings)| directory))
+            .or_insert_with(|| (resolved.map(|(_directory, settings)| settings), Vec::new()))
+            .1
+            .push(path);
     }
 
-    let toml_settings = if root_path.len() == 1 {
-        Some(resolver.items().first().unwrap().value())
-    } else {
-        None
-    };
+    groups
+        .into_values()
+        .map(|(toml_settings, paths)| {
+            build_config_with_settings(check_config, toml_settings, paths)
+        })
+        .collect()
+}
+
+/// Find the settings of the closest `jarl.toml` that applies to `path`, i.e.
+/// the one whose directory is the deepest ancestor of `path`. If there is
+/// none, the `jarl.toml` of the user config directory is used, if any.
+/// Returns the directory of this `jarl.toml` along with its settings.
+fn resolve_settings<'a>(
+    resolver: &'a PathResolver<Settings>,
+    path: &Path,
+) -> Option<(&'a Path, &'a Settings)> {
+    let path = crate::fs::normalize_path(path);
+    let user_config_dir = get_user_config_dir();
+
+    resolver
+        .items()
+        .iter()
+        .filter(|item| path.starts_with(item.path()))
+        .max_by_key(|item| item.path().components().count())
+        .or_else(|| {
+            resolver
+                .items()
+                .iter()
+                .find(|item| user_config_dir.as_deref() == Some(item.path()))
+        })
+        .map(|item| (item.path(), item.value()))
+}
 
+fn build_config_with_settings(
+    check_config: &ArgsConfig,
+    toml_settings: Option<&Settings>,
+    paths: Vec<PathBuf>,
+) -> Result<Config> {
     // Determining the minimum R version has to come first since if it is
     // unknown then only rules that don't have a version restriction are
     // selected.
     let minimum_r_version = determine_minimum_r_version(check_config, &paths)?;
 
-    let rules_cli = parse_rules_cli(
-        &check_config.select,
-        &check_config.extend_select,
-        &check_config.ignore,
-    )?;
-    let rules_toml = parse_rules_toml(toml_settings)?;
-    let rules = reconcile_rules(rules_cli, rules_toml)?;
+    let (rules, version_gated_rules) = match &check_config.only {
+        // `--only` is a debug aid that runs a single rule, whatever its
+        // minimum R version and the rules selected or ignored elsewhere.
+        Some(only) => (parse_only_rule(only)?, RuleSet::empty()),
+        None => {
+            let rules_cli = parse_rules_cli(
+                &check_config.select,
+                &check_config.extend_select,
+                &check_config.ignore,
+            )?;
+            let rules_toml = parse_rules_toml(toml_settings)?;
+            let rules = reconcile_rules(rules_cli, rules_toml)?;
 
-    let rules = filter_rules_by_version(&rules, minimum_r_version);
+            // Rules that are skipped only because we don't know the minimum R
+            // version, not because it is too low.
+            let version_gated_rules = if minimum_r_version.is_none() {
+                rules
+                    .iter()
+                    .filter(|rule| rule.minimum_r_version().is_some())
+                    .collect::<RuleSet>()
+            } else {
+                RuleSet::empty()
+            };
+
+            (
+                filter_rules_by_version(&rules, minimum_r_version),
+                version_gated_rules,
+            )
+        }
+    };
 
     // Parse fixable/unfixable rules from TOML.
     // These will be stored in Config and checked when applying fixes.
     let (fixable_toml, unfixable_toml) = parse_fixable_toml(toml_settings)?;
 
+    let severity = parse_severity_toml(toml_settings)?;
+
     // Resolve the interaction between --fix and --unsafe-fixes first. Using
     // --unsafe-fixes implies using --fix, but the opposite is not true.
     let rules_to_apply = match (check_config.fix, check_config.unsafe_fixes) {
@@ -151,6 +444,39 @@ pub fn build_config(
 
     let assignment = parse_assignment(check_config, toml_settings)?;
 
+    let duplicate_block_min_size = parse_duplicate_block_min_size(toml_settings)?;
+
+    let undesirable_functions = parse_undesirable_function_toml(toml_settings);
+
+    let undesirable_operators = parse_undesirable_operator_toml(toml_settings);
+
+    let nested_ifelse_max_depth = parse_nested_ifelse_max_depth(toml_settings)?;
+
+    let duplicated_arguments_partial_matching = toml_settings
+        .and_then(|s| s.linter.duplicated_arguments_partial_matching)
+        .unwrap_or(false);
+
+    let preferred_quote = parse_quotes_style(toml_settings)?;
+
+    let line_length_max = parse_line_length_max(toml_settings)?;
+
+    let infix_spaces_exponent = toml_settings
+        .and_then(|s| s.linter.infix_spaces_exponent)
+        .unwrap_or(false);
+
+    let object_name_styles = parse_object_name_styles(toml_settings)?;
+
+    let pipe_consistency_style = parse_pipe_consistency_style(toml_settings)?;
+
+    let min_confidence = match &check_config.min_confidence {
+        Some(min_confidence) => min_confidence.parse::<Confidence>().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid value for `--min-confidence`: {min_confidence} (must be \"high\" or \"heuristic\")."
+            )
+        })?,
+        None => Confidence::Heuristic,
+    };
+
     Ok(Config {
         paths,
         rules,
@@ -158,14 +484,41 @@ pub fn build_config(
         apply_fixes: check_config.fix,
         apply_unsafe_fixes: check_config.unsafe_fixes,
         minimum_r_version,
+        version_gated_rules,
         allow_dirty: check_config.allow_dirty,
         allow_no_vcs: check_config.allow_no_vcs,
         assignment,
         unfixable: unfixable_toml,
         fixable: fixable_toml,
+        severity,
+        show_context: check_config.show_context,
+        atomic: check_config.atomic,
+        duplicate_block_min_size,
+        undesirable_functions,
+        undesirable_operators,
+        nested_ifelse_max_depth,
+        duplicated_arguments_partial_matching,
+        preferred_quote,
+        line_length_max,
+        infix_spaces_exponent,
+        object_name_styles,
+        pipe_consistency_style,
+        roxygen_examples: check_config.roxygen_examples,
+        min_confidence,
+        fix_iterations: check_config
+            .fix_iterations
+            .unwrap_or(DEFAULT_FIX_ITERATIONS),
     })
 }
 
+/// Parse the rule passed to `--only`. Groups of rules are not accepted.
+fn parse_only_rule(only: &str) -> Result<RuleSet> {
+    match Rule::from_name(only) {
+        Some(rule) => Ok(RuleSet::from_rules(vec![rule])),
+        None => Err(anyhow::anyhow!("Unknown rule in `--only`: {only}")),
+    }
+}
+
 /// Parse CLI rule arguments and return (selected_rules, ignored_rules).
 ///
 /// Returns None for selected_rules if no --select was specified.
@@ -396,6 +749,57 @@ pub fn parse_fixable_toml(
     Ok((fixable_rules, unfixable_rules))
 }
 
+/// Parse the severity of rules from TOML configuration.
+///
+/// Returns a map from rule names to their severity. Groups of rules (e.g.
+/// "PERF") are expanded, but the severity of a rule given by name takes
+/// precedence over the one of its group.
+pub fn parse_severity_toml(toml_settings: Option<&Settings>) -> Result<HashMap<String, Severity>> {
+    let all_rules = Rule::all();
+    let mut out = HashMap::new();
+
+    let Some(severity) = toml_settings.and_then(|s| s.linter.severity.as_ref()) else {
+        return Ok(out);
+    };
+
+    let passed_by_user = severity.keys().map(|s| s.as_str()).collect();
+    let expanded_rules = replace_group_rules(&passed_by_user, all_rules);
+    if let Some(invalid_rules) = get_invalid_rules(all_rules, &expanded_rules) {
+        return Err(anyhow::anyhow!(
+            "Unknown rules in field `severity` in 'jarl.toml': {}",
+            invalid_rules.join(", ")
+        ));
+    }
+
+    let mut invalid_values = Vec::new();
+    let mut by_group = Vec::new();
+    let mut by_name = Vec::new();
+    for (key, value) in severity {
+        let Ok(value) = value.parse::<Severity>() else {
+            invalid_values.push(format!("{key} = \"{value}\""));
+            continue;
+        };
+        if key.trim().parse::<Category>().is_ok() || key.trim() == "ALL" {
+            let expanded = replace_group_rules(&vec![key.as_str()], all_rules);
+            by_group.extend(expanded.into_iter().map(|rule| (rule, value)));
+        } else {
+            by_name.push((key.trim().to_string(), value));
+        }
+    }
+
+    if !invalid_values.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Invalid values in field `severity` in 'jarl.toml' (must be \"error\", \"warning\", or \"info\"): {}",
+            invalid_values.join(", ")
+        ));
+    }
+
+    out.extend(by_group);
+    out.extend(by_name);
+
+    Ok(out)
+}
+
 // This takes rules that refer to groups (e.g. "PERF", "READ") and replaces them
 // with the rule names.
 // Returns a vector with the original rule names left unmodified and the expanded
@@ -606,6 +1010,115 @@ fn filter_rules_by_version(rules: &RuleSet, minimum_r_version: Option<(u32, u32,
     }
 }
 
+fn parse_duplicate_block_min_size(toml_settings: Option<&Settings>) -> Result<usize> {
+    let Some(min_size) = toml_settings.and_then(|s| s.linter.duplicate_block_min_size) else {
+        return Ok(DEFAULT_DUPLICATE_BLOCK_MIN_SIZE);
+    };
+    if min_size < 2 {
+        return Err(anyhow::anyhow!(
+            "Invalid value in field `duplicate-block-min-size` in 'jarl.toml': it must be at least 2."
+        ));
+    }
+    Ok(min_size)
+}
+
+fn parse_nested_ifelse_max_depth(toml_settings: Option<&Settings>) -> Result<usize> {
+    let Some(max_depth) = toml_settings.and_then(|s| s.linter.nested_ifelse_max_depth) else {
+        return Ok(DEFAULT_NESTED_IFELSE_MAX_DEPTH);
+    };
+    if max_depth < 1 {
+        return Err(anyhow::anyhow!(
+            "Invalid value in field `max_depth` of `[lint.nested_ifelse]` in 'jarl.toml': it must be at least 1."
+        ));
+    }
+    Ok(max_depth)
+}
+
+fn parse_line_length_max(toml_settings: Option<&Settings>) -> Result<usize> {
+    let Some(max) = toml_settings.and_then(|s| s.linter.line_length_max) else {
+        return Ok(DEFAULT_LINE_LENGTH_MAX);
+    };
+    if max < 1 {
+        return Err(anyhow::anyhow!(
+            "Invalid value in field `max` of `[lint.line_length]` in 'jarl.toml': it must be at least 1."
+        ));
+    }
+    Ok(max)
+}
+
+fn parse_object_name_styles(toml_settings: Option<&Settings>) -> Result<Vec<NamingStyle>> {
+    let Some(styles) = toml_settings.and_then(|s| s.linter.object_name_styles.as_ref()) else {
+        return Ok(vec![NamingStyle::SnakeCase]);
+    };
+    if styles.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Invalid value in field `style` of `[lint.object_name]` in 'jarl.toml': it must contain at least one style."
+        ));
+    }
+    styles
+        .iter()
+        .map(|style| {
+            NamingStyle::from_name(style).ok_or_else(|| {
+                let possible_values = NamingStyle::ALL
+                    .iter()
+                    .map(|style| format!("\"{}\"", style.name()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                anyhow::anyhow!(
+                    "Invalid value in field `style` of `[lint.object_name]` in 'jarl.toml': \"{style}\" is not a naming style. Possible values are {possible_values}."
+                )
+            })
+        })
+        .collect()
+}
+
+fn parse_quotes_style(toml_settings: Option<&Settings>) -> Result<char> {
+    match toml_settings.and_then(|s| s.linter.quotes_style.as_deref()) {
+        None | Some("double") => Ok('"'),
+        Some("single") => Ok('\''),
+        Some(_) => Err(anyhow::anyhow!(
+            "Invalid value in field `style` of `[lint.quotes]` in 'jarl.toml': it must be \"double\" or \"single\"."
+        )),
+    }
+}
+
+fn parse_pipe_consistency_style(toml_settings: Option<&Settings>) -> Result<PipeStyle> {
+    match toml_settings.and_then(|s| s.linter.pipe_consistency_style.as_deref()) {
+        None | Some("auto") => Ok(PipeStyle::Auto),
+        Some("native") => Ok(PipeStyle::Native),
+        Some("magrittr") => Ok(PipeStyle::Magrittr),
+        Some(_) => Err(anyhow::anyhow!(
+            "Invalid value in field `style` of `[lint.pipe_consistency]` in 'jarl.toml': it must be \"auto\", \"native\", or \"magrittr\"."
+        )),
+    }
+}
+
+/// Parse the functions reported by `undesirable_function` from TOML
+/// configuration. If `[lint.undesirable_function]` is present, it replaces the
+/// default list.
+fn parse_undesirable_function_toml(toml_settings: Option<&Settings>) -> HashMap<String, String> {
+    match toml_settings.and_then(|s| s.linter.undesirable_function.as_ref()) {
+        Some(functions) => functions
+            .iter()
+            .map(|(name, message)| (name.clone(), message.clone()))
+            .collect(),
+        None => default_undesirable_functions(),
+    }
+}
+
+/// Parse the operators reported by `undesirable_operator` from TOML
+/// configuration. If `[lint.undesirable_operator]` is present, it replaces the
+/// default list.
+fn parse_undesirable_operator_toml(toml_settings: Option<&Settings>) -> HashMap<String, String> {
+    match toml_settings.and_then(|s| s.linter.undesirable_operator.as_ref()) {
+        Some(operators) => operators
+            .iter()
+            .map(|(operator, message)| (operator.clone(), message.clone()))
+            .collect(),
+        None => default_undesirable_operators(),
+    }
+}
+
 fn parse_assignment(
     check_config: &ArgsConfig,
     toml_settings: Option<&Settings>,
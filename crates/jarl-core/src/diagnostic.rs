@@ -2,10 +2,106 @@ use biome_rowan::TextRange;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use crate::location::Location;
 use crate::rule_set::{FixStatus, Rule};
 
+/// How confident we are that applying a fix keeps the intent of the code.
+///
+/// This mirrors the applicability levels used by `rustc` and lets clients
+/// (the CLI, the LSP, or any consumer of the JSON output) decide which fixes
+/// can be applied automatically.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Applicability {
+    /// The fix is always correct and can be applied without review. Those are
+    /// applied with `--fix`.
+    Always,
+    /// The fix is correct in most contexts but may change the behavior of the
+    /// code in some cases. Those are only applied with `--unsafe-fixes`.
+    MaybeIncorrect,
+    /// The fix is only meant to be shown to the user and is never applied.
+    #[default]
+    DisplayOnly,
+}
+
+impl From<FixStatus> for Applicability {
+    fn from(status: FixStatus) -> Self {
+        match status {
+            FixStatus::Safe => Applicability::Always,
+            FixStatus::Unsafe => Applicability::MaybeIncorrect,
+            FixStatus::None => Applicability::DisplayOnly,
+        }
+    }
+}
+
+impl Applicability {
+    /// Whether a fix with this applicability should be applied, depending on
+    /// whether the user passed `--unsafe-fixes`.
+    pub fn is_applied(self, unsafe_fixes: bool) -> bool {
+        match self {
+            Applicability::Always => true,
+            Applicability::MaybeIncorrect => unsafe_fixes,
+            Applicability::DisplayOnly => false,
+        }
+    }
+}
+
+/// Severity of a violation, configured per rule with `[lint.severity]` in
+/// `jarl.toml`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Always makes `jarl check` fail.
+    Error,
+    /// Only makes `jarl check` fail with `--warnings-as-errors`.
+    Warning,
+    /// Never makes `jarl check` fail.
+    Info,
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Self::Error),
+            "warning" => Ok(Self::Warning),
+            "info" => Ok(Self::Info),
+            _ => Err(format!("Unknown severity: {}", s)),
+        }
+    }
+}
+
+/// How confident a rule is that a violation is an actual problem.
+///
+/// Most rules look for patterns that are always problematic, but some rules
+/// rely on heuristics (e.g. `absolute_path` guesses whether a string is a
+/// path), so consumers may want to filter out their violations with
+/// `--min-confidence`.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    /// The violation was found with a heuristic and may be a false positive.
+    Heuristic,
+    /// The violation is very likely an actual problem.
+    #[default]
+    High,
+}
+
+impl FromStr for Confidence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "high" => Ok(Self::High),
+            "heuristic" => Ok(Self::Heuristic),
+            _ => Err(format!("Unknown confidence: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
 // The fix to apply to the violation.
 pub struct Fix {
@@ -28,6 +124,21 @@ impl Fix {
     }
 }
 
+/// Namespace that can be prepended to rule names in structured output formats,
+/// e.g. `jarl:any_is_na`.
+pub const RULE_NAMESPACE: &str = "jarl";
+
+/// Id of a rule in structured output formats. If `namespaced` is true, the
+/// name of the rule is prefixed with [`RULE_NAMESPACE`] so that it can't be
+/// mistaken for a rule of another linter.
+pub fn rule_id(name: &str, namespaced: bool) -> String {
+    if namespaced {
+        format!("{RULE_NAMESPACE}:{name}")
+    } else {
+        name.to_string()
+    }
+}
+
 /// Details on the violated rule.
 pub trait Violation {
     /// Name of the rule.
@@ -56,8 +167,27 @@ pub struct Diagnostic {
     pub filename: PathBuf,
     pub range: TextRange,
     pub location: Option<Location>,
+    // Location of the end of the violated rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_location: Option<Location>,
     // Fix to apply if the user passed `--fix`.
     pub fix: Fix,
+    // Whether the fix can be applied automatically.
+    #[serde(default)]
+    pub applicability: Applicability,
+    // Whether the violation was found with a heuristic. This is set by the
+    // rule and used by `--min-confidence`.
+    #[serde(default)]
+    pub confidence: Confidence,
+    // Severity configured in `jarl.toml`, if any. Violations without a
+    // configured severity are displayed as warnings but still make
+    // `jarl check` fail.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    // Name of the function in which the violation is located. Only computed
+    // with `--show-context`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enclosing_function: Option<String>,
 }
 
 impl<T: Violation> From<T> for ViolationData {
@@ -86,12 +216,27 @@ impl ViolationData {
 
 impl Diagnostic {
     pub fn new<T: Into<ViolationData>>(message: T, range: TextRange, fix: Fix) -> Self {
+        let message = message.into();
+        // The applicability of a fix is inherited from the fix status of the
+        // rule that reported it.
+        let rule = Rule::from_name(&message.name);
+        let applicability = rule
+            .map(|r| Applicability::from(r.fix_status()))
+            .unwrap_or_default();
+        // Rules can also lower the confidence of specific violations with
+        // `with_confidence()`.
+        let confidence = rule.map(|r| r.confidence()).unwrap_or_default();
         Self {
-            message: message.into(),
+            message,
             range,
             location: None,
+            end_location: None,
             fix,
             filename: "".into(),
+            applicability,
+            confidence,
+            severity: None,
+            enclosing_function: None,
         }
     }
 
@@ -100,36 +245,81 @@ impl Diagnostic {
             message: ViolationData::empty(),
             range: TextRange::empty(0.into()),
             location: None,
+            end_location: None,
             fix: Fix::empty(),
             filename: "".into(),
+            applicability: Applicability::DisplayOnly,
+            confidence: Confidence::default(),
+            severity: None,
+            enclosing_function: None,
         }
     }
 
     // TODO: in these three functions, the first condition should be removed
     // once comments in nodes are better handled, #95.
     pub fn has_safe_fix(&self) -> bool {
-        if self.fix.to_skip || self.fix.content.is_empty() {
+        if self.fix.to_skip {
             return false;
         }
-        Rule::from_name(&self.message.name)
-            .map(|r| r.fix_status() == FixStatus::Safe)
-            .unwrap_or(false)
+        self.applicability == Applicability::Always
     }
     pub fn has_unsafe_fix(&self) -> bool {
-        if self.fix.to_skip || self.fix.content.is_empty() {
+        if self.fix.to_skip {
             return false;
         }
-        Rule::from_name(&self.message.name)
-            .map(|r| r.fix_status() == FixStatus::Unsafe)
-            .unwrap_or(false)
+        self.applicability == Applicability::MaybeIncorrect
     }
     pub fn has_no_fix(&self) -> bool {
         if self.fix.to_skip {
             return true;
         }
-        Rule::from_name(&self.message.name)
-            .map(|r| r.fix_status() == FixStatus::None)
-            .unwrap_or(true)
+        self.applicability == Applicability::DisplayOnly
+    }
+
+    /// Set the confidence of this violation, e.g. for rules that only use a
+    /// heuristic in some cases.
+    pub fn with_confidence(mut self, confidence: Confidence) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// Set the applicability of the fix of this violation, e.g. for rules whose
+    /// fix is only unsafe in some cases.
+    pub fn with_applicability(mut self, applicability: Applicability) -> Self {
+        self.applicability = applicability;
+        self
+    }
+
+    /// Prefix the name of the violated rule with [`RULE_NAMESPACE`].
+    pub fn namespace_rule_id(&mut self) {
+        self.message.name = rule_id(&self.message.name, true);
+    }
+
+    /// The violated rule, also when its name is prefixed with
+    /// [`RULE_NAMESPACE`].
+    pub fn rule(&self) -> Option<Rule> {
+        let name = &self.message.name;
+        let name = name
+            .strip_prefix(RULE_NAMESPACE)
+            .and_then(|name| name.strip_prefix(':'))
+            .unwrap_or(name);
+        Rule::from_name(name)
+    }
+
+    /// Count the columns of the start and end locations from 1 instead of 0.
+    pub fn use_one_based_column(&mut self) {
+        let one_based = |location: Location| Location::new(location.row(), location.column() + 1);
+        self.location = self.location.map(one_based);
+        self.end_location = self.end_location.map(one_based);
+    }
+
+    /// Whether this violation should make `jarl check` fail.
+    pub fn is_failure(&self, warnings_as_errors: bool) -> bool {
+        match self.severity {
+            None | Some(Severity::Error) => true,
+            Some(Severity::Warning) => warnings_as_errors,
+            Some(Severity::Info) => false,
+        }
     }
 }
 
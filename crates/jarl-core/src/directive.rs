@@ -1,3 +1,5 @@
+use crate::rule_set::Rule;
+
 #[derive(Debug, PartialEq)]
 pub enum LintDirective {
     /// Skip all lints for the next node
@@ -14,6 +16,21 @@ pub enum LintDirective {
     SkipEnd,
 }
 
+impl LintDirective {
+    /// Rule names listed in the directive that don't match any rule, e.g.
+    /// "assignmnet" in "# nolint: assignmnet"
+    pub fn unknown_rules(&self) -> Vec<&str> {
+        match self {
+            Self::SkipRules(rules) | Self::SkipStartRules(rules) => rules
+                .iter()
+                .map(String::as_str)
+                .filter(|name| Rule::from_name(name).is_none())
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
 /// Parse a comment directive
 ///
 /// These can take the form:
@@ -100,6 +117,50 @@ pub fn parse_special_skip_file(text: &str) -> Option<LintDirective> {
     None
 }
 
+/// Parse a directive setting the preferred assignment operator of a file
+///
+/// This takes the form:
+///
+/// ```text
+/// # jarl: assignment = "="
+/// # jarl: assignment = "<-"
+/// ```
+///
+/// Returns:
+/// - `Some(operator)` - A valid directive was found, `operator` is `"="` or `"<-"`
+/// - `None` - Invalid directive (e.g. unknown operator) or just a regular comment
+pub fn parse_assignment_directive(text: &str) -> Option<&str> {
+    // Only allow single # followed by space
+    let text = text.trim();
+    let text = text.strip_prefix("# ")?;
+
+    let rest = text.strip_prefix("jarl:")?.trim_start();
+    let rest = rest.strip_prefix("assignment")?.trim_start();
+    let value = rest.strip_prefix('=')?.trim();
+
+    let value = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))?;
+
+    match value {
+        "=" | "<-" => Some(value),
+        _ => None,
+    }
+}
+
+/// Find the preferred assignment operator of a file, if any
+///
+/// The directive must be in the comments at the top of the file, i.e. before
+/// any line of code. See [parse_assignment_directive()] for the format.
+pub fn find_assignment_directive(source: &str) -> Option<&str> {
+    source
+        .lines()
+        .map(str::trim)
+        .take_while(|line| line.is_empty() || line.starts_with('#'))
+        .find_map(parse_assignment_directive)
+}
+
 #[inline]
 fn parse_lint_directive(text: &str) -> Option<LintDirective> {
     // Parse comma-separated rule names, e.g. "any_is_na, coalesce"
@@ -136,6 +197,65 @@ fn parse_lint_directive_for_start(text: &str) -> Option<LintDirective> {
 mod test {
     use crate::directive::LintDirective;
     use crate::directive::parse_comment_directive;
+    use crate::directive::{find_assignment_directive, parse_assignment_directive};
+
+    #[test]
+    fn test_assignment_directive() {
+        assert_eq!(
+            parse_assignment_directive("# jarl: assignment = \"=\""),
+            Some("=")
+        );
+        assert_eq!(
+            parse_assignment_directive("# jarl: assignment = \"<-\""),
+            Some("<-")
+        );
+        assert_eq!(
+            parse_assignment_directive("# jarl: assignment = '='"),
+            Some("=")
+        );
+        assert_eq!(
+            parse_assignment_directive("# jarl:assignment=\"=\"  "),
+            Some("=")
+        );
+
+        // Invalid forms
+        assert_eq!(
+            parse_assignment_directive("#jarl: assignment = \"=\""),
+            None
+        );
+        assert_eq!(
+            parse_assignment_directive("# jarl: assignment = \"->\""),
+            None
+        );
+        assert_eq!(parse_assignment_directive("# jarl: assignment = ="), None);
+        assert_eq!(
+            parse_assignment_directive("# jarl: assignment = \"=\'"),
+            None
+        );
+        assert_eq!(
+            parse_assignment_directive("# jarl assignment = \"=\""),
+            None
+        );
+        assert_eq!(parse_assignment_directive("# jarl: assign = \"=\""), None);
+    }
+
+    #[test]
+    fn test_find_assignment_directive() {
+        assert_eq!(
+            find_assignment_directive("# jarl: assignment = \"=\"\nx = 1"),
+            Some("=")
+        );
+        assert_eq!(
+            find_assignment_directive("# My script\n\n# jarl: assignment = \"=\"\nx = 1"),
+            Some("=")
+        );
+        // Only the comments at the top of the file are considered
+        assert_eq!(
+            find_assignment_directive("x = 1\n# jarl: assignment = \"=\""),
+            None
+        );
+        assert_eq!(find_assignment_directive("x = 1"), None);
+    }
 
     #[test]
     fn test_lint_directive() {
@@ -239,4 +359,32 @@ mod test {
         assert_eq!(parse_comment_directive("# nolint start any_is_na"), None);
         assert_eq!(parse_comment_directive("# nolint ending"), None);
     }
+
+    #[test]
+    fn test_unknown_rules() {
+        let unknown = |text: &str| {
+            parse_comment_directive(text)
+                .unwrap()
+                .unknown_rules()
+                .iter()
+                .map(|name| name.to_string())
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(unknown("# nolint: assignmnet"), vec!["assignmnet"]);
+        assert_eq!(
+            unknown("# nolint: any_is_na, assignmnet, foo_linter"),
+            vec!["assignmnet", "foo"]
+        );
+        assert_eq!(unknown("# nolint start: assignmnet"), vec!["assignmnet"]);
+
+        // Valid rules, with or without the lintr suffix
+        assert!(unknown("# nolint: any_is_na, coalesce_linter").is_empty());
+        assert!(unknown("# nolint start: assignment").is_empty());
+
+        // Directives without rules
+        assert!(unknown("# nolint").is_empty());
+        assert!(unknown("# nolint start").is_empty());
+        assert!(unknown("# nolint end").is_empty());
+    }
 }
@@ -6,11 +6,12 @@
 
 use ignore::DirEntry;
 use rustc_hash::FxHashSet;
+use std::collections::BTreeSet;
 use std::path::Path;
 use std::path::PathBuf;
 
 use crate::fs;
-use crate::fs::has_r_extension;
+use crate::fs::{has_markdown_extension, has_r_extension};
 use crate::settings::Settings;
 use crate::toml::find_jarl_toml_in_directory;
 use crate::toml::parse_jarl_toml;
@@ -29,6 +30,10 @@ pub const DEFAULT_EXCLUDE_PATTERNS: &[&str] = &[
     "import-standalone-*.R",
 ];
 
+/// Name of the files listing paths that are not checked. They use the same
+/// syntax as `.gitignore` files but are independent of git.
+const JARL_IGNORE_FILENAME: &str = ".jarlignore";
+
 #[derive(Debug)]
 pub struct DiscoveredSettings {
     pub directory: PathBuf,
@@ -38,7 +43,7 @@ pub struct DiscoveredSettings {
 }
 
 /// Get the user config directory for jarl
-fn get_user_config_dir() -> Option<PathBuf> {
+pub(crate) fn get_user_config_dir() -> Option<PathBuf> {
     let strategy = etcetera::base_strategy::choose_base_strategy().ok()?;
     Some(strategy.config_dir().join("jarl"))
 }
@@ -51,7 +56,7 @@ fn get_user_config_dir() -> Option<PathBuf> {
 /// For each `path`, we:
 /// - Walk up its ancestors until the user config directory, looking for a `jarl.toml`
 /// - If no config found in ancestors, fall back to checking the user config directory
-/// - TODO(hierarchical): Walk down its children, looking for nested `jarl.toml`s
+/// - Walk down its children, looking for nested `jarl.toml`s
 pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<DiscoveredSettings>> {
     let paths: Vec<PathBuf> = paths.iter().map(fs::normalize_path).collect();
 
@@ -105,12 +110,60 @@ pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<Disc
         }
     }
 
-    // TODO(hierarchical): Also iterate into the directories and collect `jarl.toml`
-    // found nested withing the directories for hierarchical support
+    // Also look into the directories for nested `jarl.toml`, which apply to the
+    // files of their own directory tree.
+    for path in paths.iter().filter(|path| path.is_dir()) {
+        let walker = ignore::WalkBuilder::new(path)
+            .hidden(true)
+            .git_ignore(true)
+            .add_custom_ignore_filename(JARL_IGNORE_FILENAME)
+            .filter_entry(|entry| entry.file_type().is_some_and(|ft| ft.is_dir()))
+            .build();
+
+        for entry in walker.filter_map(Result::ok) {
+            let directory = entry.path();
+
+            // The directory itself was already visited with its ancestors, and
+            // overlapping `paths` lead to the same directories.
+            if entry.depth() == 0
+                || discovered_settings
+                    .iter()
+                    .any(|ds| ds.directory == directory)
+            {
+                continue;
+            }
+
+            if let Some(toml) = find_jarl_toml_in_directory(directory) {
+                let settings = parse_settings(&toml, directory)?;
+                discovered_settings.push(DiscoveredSettings {
+                    directory: directory.to_path_buf(),
+                    settings,
+                    config_path: Some(toml),
+                });
+            }
+        }
+    }
 
     Ok(discovered_settings)
 }
 
+/// Load the settings of a TOML file passed explicitly, e.g. with `--config`,
+/// instead of discovering them from the paths to check. The directory of this
+/// file is used as the root of its `exclude` patterns.
+pub fn load_settings(config_path: &Path) -> anyhow::Result<DiscoveredSettings> {
+    let toml = fs::normalize_path(config_path);
+    if !toml.is_file() {
+        return Err(anyhow::anyhow!(
+            "Configuration file not found: {}",
+            config_path.display()
+        ));
+    }
+
+    let directory = toml.parent().map(Path::to_path_buf).unwrap_or_default();
+    let settings = parse_settings(&toml, &directory)?;
+    Ok(DiscoveredSettings { directory, settings, config_path: Some(toml) })
+}
+
 /// Parse [Settings] from a given `jarl.toml`
 // TODO(hierarchical): Allow for an `extends` option in `jarl.toml`, which will make things
 // more complex, but will be very useful once we support hierarchical configuration as a
@@ -126,6 +179,13 @@ type DiscoveredFiles = Vec<Result<PathBuf, ignore::Error>>;
 /// For each provided `path`, recursively search for any R files within that `path`
 /// that match our inclusion criteria
 ///
+/// If `include_markdown` is true, R Markdown and Quarto files are also
+/// discovered so that their R chunks are checked.
+///
+/// `paths` can mix files and directories. The discovered files are sorted and
+/// deduplicated, so overlapping `paths`, symlinks, and hardlinks don't lead to a
+/// file being checked twice.
+///
 /// NOTE: Make sure that the inclusion criteria that guide `path` discovery are also
 /// consistently applied to [discover_settings()].
 pub fn discover_r_file_paths<P: AsRef<Path>>(
@@ -133,6 +193,7 @@ pub fn discover_r_file_paths<P: AsRef<Path>>(
     resolver: &PathResolver<Settings>,
     use_linter_settings: bool,
     no_default_exclude: bool,
+    include_markdown: bool,
 ) -> DiscoveredFiles {
     let paths: Vec<PathBuf> = paths.iter().map(fs::normalize_path).collect();
 
@@ -160,6 +221,10 @@ pub fn discover_r_file_paths<P: AsRef<Path>>(
     builder.git_global(true);
     builder.git_exclude(true);
 
+    // Like `.gitignore` files, `.jarlignore` files apply to the directory
+    // they're in and to its subdirectories, and nested ones take precedence.
+    builder.add_custom_ignore_filename(JARL_IGNORE_FILENAME);
+
     // Add exclude patterns from settings if linter settings should be used
     if use_linter_settings {
         // Build custom ignore patterns
@@ -213,7 +278,7 @@ pub fn discover_r_file_paths<P: AsRef<Path>>(
 
     // Run the `WalkParallel` to collect all R files.
     let state = FilesState::new();
-    let mut visitor_builder = FilesVisitorBuilder::new(&state);
+    let mut visitor_builder = FilesVisitorBuilder::new(&state, include_markdown);
     walker.visit(&mut visitor_builder);
 
     state.finish()
@@ -229,8 +294,29 @@ impl FilesState {
         Self { files: std::sync::Mutex::new(Vec::new()) }
     }
 
+    /// Collect the discovered files, sorted and deduplicated
+    ///
+    /// The walker runs in parallel so the order in which files are found isn't
+    /// deterministic. Paths passed by the user can also overlap (e.g. `R/` and
+    /// `R/foo.R`), in which case the same file is found several times. Several
+    /// paths can also point to the same physical file (symlinks or hardlinks),
+    /// in which case only the first path in sorted order is kept, so that the
+    /// file isn't reported or fixed twice. Errors are kept after the files, in
+    /// the order in which they were found.
     fn finish(self) -> DiscoveredFiles {
-        self.files.into_inner().unwrap()
+        let files = self.files.into_inner().unwrap();
+
+        let (paths, errors): (Vec<_>, Vec<_>) = files.into_iter().partition(Result::is_ok);
+
+        let paths: BTreeSet<PathBuf> = paths.into_iter().filter_map(Result::ok).collect();
+
+        let mut seen = FxHashSet::default();
+        paths
+            .into_iter()
+            .filter(|path| seen.insert(fs::file_id(path)))
+            .map(Ok)
+            .chain(errors)
+            .collect()
     }
 }
 
@@ -240,18 +326,23 @@ impl FilesState {
 /// [ignore::WalkParallel] utilizes to create one [FilesVisitor] per thread.
 struct FilesVisitorBuilder<'state> {
     state: &'state FilesState,
+    include_markdown: bool,
 }
 
 impl<'state> FilesVisitorBuilder<'state> {
-    fn new(state: &'state FilesState) -> Self {
-        Self { state }
+    fn new(state: &'state FilesState, include_markdown: bool) -> Self {
+        Self { state, include_markdown }
     }
 }
 
 impl<'state> ignore::ParallelVisitorBuilder<'state> for FilesVisitorBuilder<'state> {
     /// Constructs the per-thread [FilesVisitor], called for us by `ignore`
     fn build(&mut self) -> Box<dyn ignore::ParallelVisitor + 'state> {
-        Box::new(FilesVisitor { files: vec![], state: self.state })
+        Box::new(FilesVisitor {
+            files: vec![],
+            state: self.state,
+            include_markdown: self.include_markdown,
+        })
     }
 }
 
@@ -263,6 +354,8 @@ impl<'state> ignore::ParallelVisitorBuilder<'state> for FilesVisitorBuilder<'sta
 struct FilesVisitor<'state> {
     files: DiscoveredFiles,
     state: &'state FilesState,
+    /// Whether R Markdown and Quarto files are accepted
+    include_markdown: bool,
 }
 
 impl ignore::ParallelVisitor for FilesVisitor<'_> {
@@ -320,6 +413,12 @@ impl ignore::ParallelVisitor for FilesVisitor<'_> {
             return ignore::WalkState::Continue;
         }
 
+        if self.include_markdown && !is_directory && has_markdown_extension(path) {
+            tracing::trace!("Included R Markdown file {path}", path = path.display());
+            self.files.push(Ok(entry.into_path()));
+            return ignore::WalkState::Continue;
+        }
+
         // Didn't accept this file, just keep going
         tracing::trace!(
             "Excluded file due to fallthrough {path}",
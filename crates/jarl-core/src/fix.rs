@@ -1,4 +1,11 @@
 use crate::diagnostic::*;
+use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::Path;
+
+/// Maximum number of passes made by `fix_until_stable()` if the user doesn't
+/// pass `--fix-iterations`.
+pub const DEFAULT_FIX_ITERATIONS: usize = 5;
 
 /// Takes all diagnostics found in a given file and the content of this file,
 /// and applies automatic fixes.
@@ -8,58 +15,314 @@ use crate::diagnostic::*;
 ///
 /// ## Overlapping fixes
 ///
-/// When several fixes have overlapping ranges, it becomes hard to apply all of
-/// them in a single pass.
-/// TODO: it should still be possible since we know the length of the fix so we
-/// should be able to update the range of the subsequent fixes. After all this
-/// is what I do to update the range of following non-overlapping fixes. The
-/// approach below looks quite expensive. This is also how Ruff does it though:
-/// https://github.com/astral-sh/ruff/blob/main/crates/ruff_linter/src/linter.rs#L559
+/// When several fixes have overlapping ranges, e.g. when a rule reports some
+/// code that contains the code reported by another rule, applying both of them
+/// would corrupt the file since the range of the second fix doesn't match the
+/// content anymore.
 ///
-/// Therefore, the current approach is to signal to the caller function that
-/// some fixes were skipped. This caller function then takes care of removing
-/// from the list of diagnostics those that have already been addressed, and
-/// then re-runs the diagnostic detection to get the new ranges. This loop
-/// continues until there are no more skipped fixes.
+/// Therefore, the fixes are applied from the start of the file, and a fix that
+/// starts before the end of the previous one is skipped. This signals to the
+/// caller function that some fixes were skipped. This caller function then
+/// re-runs the diagnostic detection on the fixed content to get the new ranges,
+/// which is done in `fix_until_stable()`. This is also how Ruff does it:
+/// https://github.com/astral-sh/ruff/blob/main/crates/ruff_linter/src/linter.rs#L559
 pub fn apply_fixes(fixes: &[Diagnostic], contents: &str) -> (bool, String) {
-    let fixes = fixes
+    let (to_apply, conflicting) = partition_fixes(fixes);
+
+    let mut new_content = String::with_capacity(contents.len());
+    let mut last_end = 0;
+
+    for fix in to_apply {
+        new_content.push_str(&contents[last_end..fix.start]);
+        new_content.push_str(&fix.content);
+        last_end = fix.end;
+    }
+    new_content.push_str(&contents[last_end..]);
+
+    (!conflicting.is_empty(), new_content)
+}
+
+/// Splits the fixes of the diagnostics between those that can be applied in a
+/// single pass, sorted by position, and the diagnostics whose fix overlaps with
+/// one of those.
+fn partition_fixes(diagnostics: &[Diagnostic]) -> (Vec<&Fix>, Vec<&Diagnostic>) {
+    let mut diagnostics = diagnostics
         .iter()
-        .map(|diagnostic| &diagnostic.fix)
+        .filter(|diagnostic| !diagnostic.fix.to_skip)
         .collect::<Vec<_>>();
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.fix.start, diagnostic.fix.end));
 
-    let old_content = contents;
-    let mut new_content = old_content.to_string();
-    let mut last_modified_pos = 0;
-    let mut has_skipped_fixes = false;
+    let mut to_apply = vec![];
+    let mut conflicting = vec![];
+    let mut last_end = 0;
 
-    let old_length = old_content.chars().count() as i32;
-    let mut new_length = old_length;
+    for diagnostic in diagnostics {
+        if diagnostic.fix.start < last_end {
+            conflicting.push(diagnostic);
+        } else {
+            last_end = diagnostic.fix.end;
+            to_apply.push(&diagnostic.fix);
+        }
+    }
 
-    for fix in fixes {
-        let mut start: i32 = fix.start.try_into().unwrap();
-        let mut end: i32 = fix.end.try_into().unwrap();
+    (to_apply, conflicting)
+}
 
-        // Adjust the range of the fix based on the changes in the contents due
-        // to previous fixes.
-        let diff_length = new_length - old_length;
-        start += diff_length;
-        end += diff_length;
+/// Lints `contents` with `get_checks` and applies the fixes, again and again,
+/// until the content doesn't change anymore.
+///
+/// A single pass is not always enough: overlapping fixes are skipped (see
+/// `apply_fixes()`), and the fixed code can have new violations, e.g. when
+/// the fix of a rule produces code that is reported by another rule.
+///
+/// At most `max_iterations` passes are made. This also stops when a pass gives
+/// back some content that was already seen, which happens when the fixes of
+/// several rules undo each other. In those cases, some overlapping fixes may
+/// never be applied, and a warning is logged for each of them.
+///
+/// This returns the diagnostics of the final content and this content.
+pub fn fix_until_stable<F>(
+    contents: &str,
+    max_iterations: usize,
+    mut get_checks: F,
+) -> anyhow::Result<(Vec<Diagnostic>, String)>
+where
+    F: FnMut(&str) -> anyhow::Result<Vec<Diagnostic>>,
+{
+    let mut contents = contents.to_string();
+    let mut seen = HashSet::from([hash_content(&contents)]);
 
-        // We don't know how to handle these cases in a single pass yet.
-        if start < last_modified_pos {
-            if !has_skipped_fixes {
-                has_skipped_fixes = true;
-            }
+    for _ in 0..max_iterations {
+        let checks = get_checks(&contents)?;
+        let (_, fixed) = apply_fixes(&checks, &contents);
+
+        if fixed == contents || !seen.insert(hash_content(&fixed)) {
+            warn_conflicting_fixes(&checks);
+            return Ok((checks, contents));
+        }
+        contents = fixed;
+    }
+
+    let checks = get_checks(&contents)?;
+    warn_conflicting_fixes(&checks);
+    Ok((checks, contents))
+}
+
+/// Log the fixes that were skipped because they overlap with the fix of
+/// another violation, and that could not be applied in a later pass.
+fn warn_conflicting_fixes(checks: &[Diagnostic]) {
+    for diagnostic in partition_fixes(checks).1 {
+        let (row, column) = diagnostic
+            .location
+            .map(|loc| (loc.row(), loc.column() + 1))
+            .unwrap_or((0, 0));
+        tracing::warn!(
+            "{} [{row}:{column}]: the fix of `{}` was skipped because it conflicts with the fix of another violation.",
+            diagnostic.filename.display(),
+            diagnostic.message.name
+        );
+    }
+}
+
+fn hash_content(contents: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The content of a file before and after applying fixes.
+pub struct FileFix<'a> {
+    pub path: &'a Path,
+    pub original: &'a str,
+    pub fixed: &'a str,
+}
+
+/// Writes the fixed content of several files so that fixes are either applied
+/// on all of them or on none of them.
+///
+/// If writing a file fails, the files that were already written (and the one
+/// that failed, in case it was partially written) are restored to their
+/// original content.
+///
+/// `write` is the function used to write a file, e.g. `std::fs::write`. This
+/// makes it possible to simulate write failures in tests.
+pub fn write_fixes_atomically<F>(files: &[FileFix], mut write: F) -> anyhow::Result<()>
+where
+    F: FnMut(&Path, &str) -> std::io::Result<()>,
+{
+    for (i, file) in files.iter().enumerate() {
+        let Err(error) = write(file.path, file.fixed) else {
             continue;
+        };
+
+        let not_restored = files[..=i]
+            .iter()
+            .filter(|written| write(written.path, written.original).is_err())
+            .map(|written| written.path.display().to_string())
+            .collect::<Vec<_>>();
+
+        let message = if not_restored.is_empty() {
+            "No fixes were applied.".to_string()
+        } else {
+            format!(
+                "The original content of these files couldn't be restored: {}",
+                not_restored.join(", ")
+            )
+        };
+
+        return Err(anyhow::anyhow!(
+            "Failed to write file: {} ({error}). {message}",
+            file.path.display()
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::diagnostic::{Diagnostic, Fix};
+    use crate::fix::{FileFix, apply_fixes, fix_until_stable, write_fixes_atomically};
+    use std::path::Path;
+
+    /// A diagnostic whose fix replaces `contents[start..end]` with `fixed`.
+    fn replace(start: usize, end: usize, fixed: &str) -> Diagnostic {
+        Diagnostic {
+            fix: Fix {
+                content: fixed.to_string(),
+                start,
+                end,
+                to_skip: false,
+            },
+            ..Diagnostic::empty()
         }
+    }
+
+    /// A diagnostic whose fix replaces all of `contents` with `fixed`.
+    fn replace_all(contents: &str, fixed: &str) -> Diagnostic {
+        replace(0, contents.len(), fixed)
+    }
+
+    #[test]
+    fn test_apply_fixes_sorted_by_position() {
+        let contents = "a <- 1; é <- 2; b <- 3";
+        let (has_skipped_fixes, fixed) = apply_fixes(
+            &[replace(17, 23, "b <- 4"), replace(0, 6, "a <- 0")],
+            contents,
+        );
+        assert!(!has_skipped_fixes);
+        assert_eq!(fixed, "a <- 0; é <- 2; b <- 4");
+    }
 
-        let start_usize = start as usize;
-        let end_usize = end as usize;
+    #[test]
+    fn test_apply_fixes_overlapping() {
+        // Two rules report overlapping code: only the first fix is applied,
+        // the other one is left for the next pass.
+        let contents = "any(is.na(x)) == TRUE";
+        let (has_skipped_fixes, fixed) = apply_fixes(
+            &[replace(0, 21, "any(is.na(x))"), replace(0, 13, "anyNA(x)")],
+            contents,
+        );
+        assert!(has_skipped_fixes);
+        assert_eq!(fixed, "anyNA(x) == TRUE");
+    }
 
-        new_content.replace_range(start_usize..end_usize, &fix.content);
-        new_length = new_content.chars().count() as i32;
-        last_modified_pos = end + diff_length;
+    #[test]
+    fn test_fix_until_stable_overlapping() {
+        // Fake rules: one removes `== TRUE`, the other replaces
+        // `any(is.na(x))` by `anyNA(x)`. Their fixes overlap.
+        let (checks, fixed) = fix_until_stable("any(is.na(x)) == TRUE", 5, |contents| {
+            let mut checks = vec![];
+            if let Some(lhs) = contents.strip_suffix(" == TRUE") {
+                checks.push(replace_all(contents, lhs));
+            }
+            if let Some(start) = contents.find("any(is.na(x))") {
+                checks.push(replace(start, start + 13, "anyNA(x)"));
+            }
+            Ok(checks)
+        })
+        .unwrap();
+        assert_eq!(fixed, "anyNA(x)");
+        assert!(checks.is_empty());
     }
 
-    (has_skipped_fixes, new_content)
+    #[test]
+    fn test_fix_until_stable_max_iterations() {
+        // Each pass adds a character, so the content never stabilizes.
+        let (_, fixed) = fix_until_stable("a", 3, |contents| {
+            Ok(vec![replace_all(contents, &format!("{contents}a"))])
+        })
+        .unwrap();
+        assert_eq!(fixed, "aaaa");
+    }
+
+    #[test]
+    fn test_fix_until_stable_stops_when_stable() {
+        let mut n_passes = 0;
+        let (checks, fixed) = fix_until_stable("aaa", 10, |contents| {
+            n_passes += 1;
+            if contents.len() == 1 {
+                return Ok(vec![]);
+            }
+            Ok(vec![replace_all(contents, &contents[1..])])
+        })
+        .unwrap();
+        assert_eq!(fixed, "a");
+        assert!(checks.is_empty());
+        assert_eq!(n_passes, 3);
+    }
+
+    #[test]
+    fn test_fix_until_stable_stops_on_cycle() {
+        // The fixes go back and forth between "a" and "b".
+        let mut n_passes = 0;
+        let (checks, fixed) = fix_until_stable("a", 10, |contents| {
+            n_passes += 1;
+            let other = if contents == "a" { "b" } else { "a" };
+            Ok(vec![replace_all(contents, other)])
+        })
+        .unwrap();
+        assert_eq!(fixed, "b");
+        assert_eq!(checks.len(), 1);
+        assert_eq!(n_passes, 2);
+    }
+
+    #[test]
+    fn test_write_fixes_atomically() {
+        let directory = tempfile::TempDir::new().unwrap();
+        let paths = ["a.R", "b.R", "c.R"].map(|name| directory.path().join(name));
+        for path in &paths {
+            std::fs::write(path, "any(is.na(x))").unwrap();
+        }
+        let files = paths
+            .iter()
+            .map(|path| FileFix { path, original: "any(is.na(x))", fixed: "anyNA(x)" })
+            .collect::<Vec<_>>();
+
+        // Writing the third file fails, after the first two were written.
+        let result = write_fixes_atomically(&files, |path: &Path, contents: &str| {
+            if path.ends_with("c.R") && contents == "anyNA(x)" {
+                return Err(std::io::Error::other("disk full"));
+            }
+            std::fs::write(path, contents)
+        });
+
+        let error = result.unwrap_err().to_string();
+        assert!(
+            error.contains("c.R (disk full). No fixes were applied."),
+            "{error}"
+        );
+        for path in &paths {
+            assert_eq!(std::fs::read_to_string(path).unwrap(), "any(is.na(x))");
+        }
+
+        // Without failures, all files are written.
+        write_fixes_atomically(&files, |path: &Path, contents: &str| {
+            std::fs::write(path, contents)
+        })
+        .unwrap();
+        for path in &paths {
+            assert_eq!(std::fs::read_to_string(path).unwrap(), "anyNA(x)");
+        }
+    }
 }
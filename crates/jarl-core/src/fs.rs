@@ -19,6 +19,18 @@ pub fn is_r_extension(extension: &str) -> bool {
     matches!(extension, "r" | "R")
 }
 
+/// Whether the file is an R Markdown or Quarto document, whose R code is in
+/// chunks.
+pub fn has_markdown_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(OsStr::to_str)
+        .is_some_and(is_markdown_extension)
+}
+
+pub fn is_markdown_extension(extension: &str) -> bool {
+    matches!(extension, "rmd" | "Rmd" | "qmd")
+}
+
 /// Convert any path to an absolute path (based on the current working
 /// directory).
 pub fn normalize_path<P: AsRef<Path>>(path: P) -> PathBuf {
@@ -30,6 +42,33 @@ pub fn normalize_path<P: AsRef<Path>>(path: P) -> PathBuf {
     }
 }
 
+/// Identifier of the physical file behind a path.
+///
+/// Two paths get the same identifier when they point to the same file, either
+/// because one of them is a symlink or because they are hardlinks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FileId {
+    /// Device and inode numbers.
+    Inode(u64, u64),
+    /// Canonical path, used when the inode isn't available.
+    Path(PathBuf),
+}
+
+/// Get the [FileId] of a path, following symlinks.
+pub fn file_id<P: AsRef<Path>>(path: P) -> FileId {
+    let path = path.as_ref();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        if let Ok(metadata) = std::fs::metadata(path) {
+            return FileId::Inode(metadata.dev(), metadata.ino());
+        }
+    }
+
+    FileId::Path(std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()))
+}
+
 /// Convert an absolute path to be relative to the current working directory.
 pub fn relativize_path<P: AsRef<Path>>(path: P) -> String {
     let path = path.as_ref();
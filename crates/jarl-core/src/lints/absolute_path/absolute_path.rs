@@ -0,0 +1,120 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name};
+use crate::utils_ast::string_literal_content;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+pub struct AbsolutePath;
+
+/// ## What it does
+///
+/// Checks for strings that look like absolute paths, such as
+/// `"/home/user/data.csv"`, `"C:/Users/data.csv"`, or `"~/data.csv"`.
+///
+/// ## Why is this bad?
+///
+/// Absolute paths only exist on the computer where the code was written. The
+/// code will fail on another computer, or even on the same computer if the
+/// project is moved to another folder.
+///
+/// It is better to use paths relative to the project, for instance with
+/// `file.path()` or `here::here()`.
+///
+/// This rule ignores URLs, very short strings such as `"/"`, and regular
+/// expressions passed to `grepl()`, `gsub()`, and similar functions.
+///
+/// Since this rule guesses whether a string is a path, its violations have a
+/// heuristic confidence and can be hidden with `--min-confidence high`.
+///
+/// This rule is disabled by default because it can report strings that are
+/// not paths. It doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// data <- read.csv("/home/user/project/data/raw.csv")
+/// ```
+///
+/// Use instead:
+/// ```r
+/// data <- read.csv(file.path("data", "raw.csv"))
+/// # or
+/// data <- read.csv(here::here("data", "raw.csv"))
+/// ```
+impl Violation for AbsolutePath {
+    fn name(&self) -> String {
+        "absolute_path".to_string()
+    }
+    fn body(&self) -> String {
+        "Absolute paths are not portable.".to_string()
+    }
+    fn suggestion(&self) -> Option<String> {
+        Some("Use a relative path, e.g. with `file.path()` or `here::here()`.".to_string())
+    }
+}
+
+/// Functions whose first argument is a regular expression.
+const REGEX_FUNCTIONS: [&str; 8] = [
+    "grep", "grepl", "gsub", "sub", "regexpr", "gregexpr", "regexec", "gregexec",
+];
+
+pub fn absolute_path(ast: &AnyRValue) -> anyhow::Result<Option<Diagnostic>> {
+    let string = unwrap_or_return_none!(ast.as_r_string_value());
+    let content = unwrap_or_return_none!(string_literal_content(string));
+
+    if !is_absolute_path(&content) || is_regex_pattern(ast.syntax()) {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(AbsolutePath, range, Fix::empty());
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether the content of a string looks like an absolute path. This is a
+/// heuristic, the string must:
+/// - start with `/` followed by a file name, e.g. `/home/...`;
+/// - or start with a drive letter, e.g. `C:\\...` or `C:/...`;
+/// - or start with `~/`, e.g. `~/Documents/...`.
+fn is_absolute_path(content: &str) -> bool {
+    // Don't report short strings such as "/" or "~/" and multi-line strings.
+    if content.len() < 3 || content.contains('\n') || content.contains("://") {
+        return false;
+    }
+
+    let mut chars = content.chars();
+    let first = chars.next();
+    let second = chars.next();
+    let third = chars.next();
+
+    match (first, second, third) {
+        (Some('/'), Some(c), _) => c.is_alphanumeric() || matches!(c, '.' | '_'),
+        (Some('~'), Some('/' | '\\'), _) => true,
+        (Some(drive), Some(':'), Some('/' | '\\')) => drive.is_ascii_alphabetic(),
+        _ => false,
+    }
+}
+
+/// Whether `node` is the `pattern` of a function using regular expressions,
+/// e.g. `"^/home"` in `grepl("^/home", x)`.
+fn is_regex_pattern(node: &RSyntaxNode) -> bool {
+    let Some(argument) = node.parent().and_then(RArgument::cast) else {
+        return false;
+    };
+    let Some(call) = argument.syntax().ancestors().skip(1).find_map(RCall::cast) else {
+        return false;
+    };
+    let Ok(function) = call.function() else {
+        return false;
+    };
+    if !REGEX_FUNCTIONS.contains(&get_function_name(function).as_str()) {
+        return false;
+    }
+    let Ok(arguments) = call.arguments() else {
+        return false;
+    };
+
+    get_arg_by_name_then_position(&arguments.items(), "pattern", 1)
+        .is_some_and(|pattern| pattern.syntax() == argument.syntax())
+}
@@ -0,0 +1,85 @@
+pub(crate) mod absolute_path;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_absolute_path() {
+        expect_no_lint("x <- 'data/raw.csv'", "absolute_path", None);
+        expect_no_lint("x <- './data/raw.csv'", "absolute_path", None);
+        expect_no_lint("x <- '../data/raw.csv'", "absolute_path", None);
+        expect_no_lint("file.path('/', 'x')", "absolute_path", None);
+        expect_no_lint("x <- '~/'", "absolute_path", None);
+        expect_no_lint("x <- '/ by zero'", "absolute_path", None);
+        expect_no_lint("x <- 'a/b'", "absolute_path", None);
+        expect_no_lint("x <- 'http://example.com/home'", "absolute_path", None);
+        expect_no_lint("x <- 'file:///home/user'", "absolute_path", None);
+        expect_no_lint("x <- 'C:'", "absolute_path", None);
+        expect_no_lint("x <- 'ab:/cd'", "absolute_path", None);
+        expect_no_lint("x <- 1 / 2", "absolute_path", None);
+        expect_no_lint("grepl('/home/user', x)", "absolute_path", None);
+        expect_no_lint("gsub('/home/user', '', x)", "absolute_path", None);
+        expect_no_lint(
+            "base::sub(pattern = '/home/user', '', x)",
+            "absolute_path",
+            None,
+        );
+        expect_no_lint("grepl(x = y, '/home/user')", "absolute_path", None);
+    }
+
+    #[test]
+    fn test_lint_absolute_path() {
+        let expected_message = "Absolute paths are not portable";
+        expect_lint(
+            "x <- '/home/user/data.csv'",
+            expected_message,
+            "absolute_path",
+            None,
+        );
+        expect_lint(
+            "x <- \"/home/user\"",
+            expected_message,
+            "absolute_path",
+            None,
+        );
+        expect_lint(
+            "x <- '/.config/jarl'",
+            expected_message,
+            "absolute_path",
+            None,
+        );
+        expect_lint("x <- '~/data.csv'", expected_message, "absolute_path", None);
+        expect_lint(
+            "x <- 'C:/Users/data.csv'",
+            expected_message,
+            "absolute_path",
+            None,
+        );
+        expect_lint(
+            r#"x <- "C:\\Users\\data.csv""#,
+            expected_message,
+            "absolute_path",
+            None,
+        );
+        expect_lint(
+            r#"x <- r"(D:\Users\data.csv)""#,
+            expected_message,
+            "absolute_path",
+            None,
+        );
+        expect_lint(
+            "read.csv('/home/user/data.csv')",
+            expected_message,
+            "absolute_path",
+            None,
+        );
+        // Only the pattern of regex functions is ignored.
+        expect_lint(
+            "gsub('a', '/home/user', x)",
+            expected_message,
+            "absolute_path",
+            None,
+        );
+    }
+}
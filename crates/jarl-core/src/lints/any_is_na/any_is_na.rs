@@ -1,11 +1,12 @@
 use crate::diagnostic::*;
-use crate::utils::{get_nested_functions_content, node_contains_comments};
+use crate::utils::{get_function_name, get_nested_functions_content, node_contains_comments};
 use air_r_syntax::*;
 use biome_rowan::AstNode;
 
 /// ## What it does
 ///
-/// Checks for usage of `any(is.na(...))` and `NA %in% x`.
+/// Checks for usage of `any(is.na(...))`, `NA %in% x`, and
+/// `TRUE %in% is.na(x)`.
 ///
 /// ## Why is this bad?
 ///
@@ -18,6 +19,7 @@ use biome_rowan::AstNode;
 /// x <- c(1:10000, NA)
 /// any(is.na(x))
 /// NA %in% x
+/// TRUE %in% is.na(x)
 /// ```
 ///
 /// Use instead:
@@ -68,6 +70,29 @@ pub fn any_is_na_2(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>
         return Ok(None);
     };
 
+    // `TRUE %in% is.na(x)` is equivalent to `anyNA(x)`. This isn't the case of
+    // `is.na(x) %in% TRUE`, which returns a vector.
+    if left.as_r_true_expression().is_some()
+        && let Some(inner_content) = get_is_na_argument(&right)
+    {
+        let range = ast.syntax().text_trimmed_range();
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "any_is_na".to_string(),
+                "`TRUE %in% is.na(x)` is inefficient.".to_string(),
+                Some("Use `anyNA(x)` instead.".to_string()),
+            ),
+            range,
+            Fix {
+                content: format!("anyNA({inner_content})"),
+                start: range.start().into(),
+                end: range.end().into(),
+                to_skip: node_contains_comments(ast.syntax()),
+            },
+        );
+        return Ok(Some(diagnostic));
+    }
+
     let left_is_na = left.as_r_na_expression().is_some();
     let right_is_na = right.as_r_na_expression().is_some();
 
@@ -100,3 +125,23 @@ pub fn any_is_na_2(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>
 
     Ok(Some(diagnostic))
 }
+
+/// Returns the argument of `is.na()` if `expr` is a call to `is.na()` with a
+/// single unnamed argument.
+fn get_is_na_argument(expr: &AnyRExpression) -> Option<String> {
+    let call = expr.as_r_call()?;
+    if get_function_name(call.function().ok()?) != "is.na" {
+        return None;
+    }
+
+    let args = call.arguments().ok()?.items();
+    if args.len() != 1 {
+        return None;
+    }
+    let arg = args.into_iter().next()?.ok()?;
+    if arg.name_clause().is_some() {
+        return None;
+    }
+
+    Some(arg.value()?.to_trimmed_string())
+}
@@ -12,6 +12,12 @@ mod tests {
         expect_no_lint("any(!is.na(foo(x)))", "any_is_na", None);
         expect_no_lint("any()", "any_is_na", None);
         expect_no_lint("any(na.rm = TRUE)", "any_is_na", None);
+        expect_no_lint("1 %in% is.na(x)", "any_is_na", None);
+        expect_no_lint("FALSE %in% is.na(x)", "any_is_na", None);
+        expect_no_lint("TRUE %in% x", "any_is_na", None);
+        expect_no_lint("TRUE %in% is.na(x, y)", "any_is_na", None);
+        // This returns a vector, not a single value.
+        expect_no_lint("is.na(x) %in% TRUE", "any_is_na", None);
     }
 
     #[test]
@@ -40,6 +46,18 @@ mod tests {
             "any_is_na",
             None,
         );
+        expect_lint(
+            "TRUE %in% is.na(x)",
+            "`TRUE %in% is.na(x)` is inefficient.",
+            "any_is_na",
+            None,
+        );
+        expect_lint(
+            "TRUE %in% base::is.na(foo(x))",
+            "`TRUE %in% is.na(x)` is inefficient.",
+            "any_is_na",
+            None,
+        );
 
         assert_snapshot!(
             "fix_output",
@@ -47,6 +65,7 @@ mod tests {
                 vec![
                     "any(is.na(x))",
                     "NA %in% x",
+                    "TRUE %in% is.na(x)",
                     "any(is.na(foo(x)))",
                     "any(is.na(x), na.rm = TRUE)",
                 ],
@@ -16,7 +16,9 @@ use biome_rowan::AstNode;
 /// simply uses it as default. To use `=` as the preferred operator:
 ///
 /// - in the CLI (temporary change), use `--assignment "="`;
-/// - in `jarl.toml` (permanent change): set `assignment = "="`.
+/// - in `jarl.toml` (permanent change): set `assignment = "="`;
+/// - in a single file: add `# jarl: assignment = "="` in the comments at the
+///   top of the file. This overrides the two options above for this file.
 ///
 /// ## Example
 ///
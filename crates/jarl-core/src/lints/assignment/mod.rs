@@ -46,6 +46,32 @@ mod tests {
         expect_no_lint("y == 1", "assignment", None);
     }
 
+    #[test]
+    fn test_assignment_file_directive() {
+        // The directive at the top of the file flips the expected operator.
+        expect_lint(
+            "# jarl: assignment = \"=\"\nx <- 1",
+            "Use `=` for assignment",
+            "assignment",
+            None,
+        );
+        expect_lint(
+            "# jarl: assignment = \"=\"\n1 -> x",
+            "Use `=` for assignment",
+            "assignment",
+            None,
+        );
+        expect_no_lint("# jarl: assignment = \"=\"\nx = 1", "assignment", None);
+        expect_no_lint("# jarl: assignment = \"<-\"\nx <- 1", "assignment", None);
+
+        // The directive is ignored if it isn't at the top of the file.
+        expect_no_lint(
+            "x <- 1\n# jarl: assignment = \"=\"\ny <- 1",
+            "assignment",
+            None,
+        );
+    }
+
     #[test]
     fn test_assignment_diagnostic_ranges() {
         use crate::utils_test::expect_diagnostic_highlight;
@@ -0,0 +1,200 @@
+use crate::diagnostic::*;
+use crate::utils_ast::enclosing_statement;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// ## What it does
+///
+/// Checks the placement of braces:
+///
+/// - `{` must be on the same line as the `if`, `else`, `for`, `while`,
+///   `repeat`, or `function` it belongs to;
+/// - `}` must be on its own line, unless the whole block is on a single line,
+///   e.g. `function(x) { x + 1 }`;
+/// - `else` must be on the same line as the `}` that precedes it, i.e.
+///   `} else {`.
+///
+/// ## Why is this bad?
+///
+/// Placing braces consistently makes the structure of the code easier to see.
+/// This is the style recommended by the tidyverse style guide.
+///
+/// This rule is disabled by default. Its fix moves the brace or the `else`.
+/// The fix is not available when this would move a comment.
+///
+/// ## Example
+///
+/// ```r
+/// if (x > 0)
+/// {
+///   y <- 1 }
+/// else {
+///   y <- 2
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// if (x > 0) {
+///   y <- 1
+/// } else {
+///   y <- 2
+/// }
+/// ```
+pub fn brace_style(root: &RSyntaxNode) -> anyhow::Result<Vec<(RSyntaxNode, Diagnostic)>> {
+    let source = root.text().to_string();
+    let newline = if source.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+
+    let mut diagnostics = vec![];
+    for node in root.descendants() {
+        if let Some(braced) = RBracedExpressions::cast_ref(&node) {
+            let opening = opening_brace(&braced, &source);
+            let closing = closing_brace(&braced, &source, newline);
+            for diagnostic in opening.into_iter().chain(closing) {
+                diagnostics.push((node.clone(), diagnostic));
+            }
+        } else if RElseClause::can_cast(node.kind())
+            && let Some(diagnostic) = else_placement(&node, &source)
+        {
+            diagnostics.push((node.clone(), diagnostic));
+        }
+    }
+
+    Ok(diagnostics
+        .into_iter()
+        .map(|(node, diagnostic)| (enclosing_statement(&node), diagnostic))
+        .collect())
+}
+
+/// Report a `{` that isn't on the same line as the keyword it belongs to.
+fn opening_brace(braced: &RBracedExpressions, source: &str) -> Option<Diagnostic> {
+    let parent = braced.syntax().parent()?;
+    let keyword = if RIfStatement::can_cast(parent.kind()) {
+        "if"
+    } else if RElseClause::can_cast(parent.kind()) {
+        "else"
+    } else if RForStatement::can_cast(parent.kind()) {
+        "for"
+    } else if RWhileStatement::can_cast(parent.kind()) {
+        "while"
+    } else if RRepeatStatement::can_cast(parent.kind()) {
+        "repeat"
+    } else if RFunctionDefinition::can_cast(parent.kind()) {
+        "function"
+    } else {
+        return None;
+    };
+
+    let brace = braced.syntax().first_token()?;
+    let previous = brace.prev_token()?;
+    // Skip braces in the condition, e.g. `if ({ x })`.
+    if !matches!(previous.text_trimmed(), ")" | "else" | "repeat") {
+        return None;
+    }
+
+    let start: usize = previous.text_trimmed_range().end().into();
+    let end: usize = brace.text_trimmed_range().start().into();
+    let gap = &source[start..end];
+    if !gap.contains('\n') {
+        return None;
+    }
+
+    let fix = if gap.contains('#') {
+        Fix::empty()
+    } else {
+        Fix {
+            content: " {".to_string(),
+            start,
+            end: brace.text_trimmed_range().end().into(),
+            to_skip: false,
+        }
+    };
+
+    Some(Diagnostic::new(
+        ViolationData::new(
+            "brace_style".to_string(),
+            format!("`{{` should be on the same line as `{keyword}`."),
+            Some("Move it to the end of the previous line.".to_string()),
+        ),
+        brace.text_trimmed_range(),
+        fix,
+    ))
+}
+
+/// Report a `}` that isn't on its own line in a block spanning several lines.
+fn closing_brace(braced: &RBracedExpressions, source: &str, newline: &str) -> Option<Diagnostic> {
+    let range = braced.syntax().text_trimmed_range();
+    if !source[usize::from(range.start())..usize::from(range.end())].contains('\n') {
+        return None;
+    }
+
+    let brace = braced.syntax().last_token()?;
+    let previous = brace.prev_token()?;
+    let start: usize = previous.text_trimmed_range().end().into();
+    let end: usize = brace.text_trimmed_range().start().into();
+    if source[start..end].contains('\n') {
+        return None;
+    }
+
+    // Align the `}` with the start of the line containing the `{`.
+    let block_start: usize = range.start().into();
+    let line_start = source[..block_start].rfind('\n').map_or(0, |i| i + 1);
+    let line = &source[line_start..];
+    let indentation = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+
+    Some(Diagnostic::new(
+        ViolationData::new(
+            "brace_style".to_string(),
+            "`}` should be on its own line.".to_string(),
+            Some("Move it to a new line.".to_string()),
+        ),
+        brace.text_trimmed_range(),
+        Fix {
+            content: format!("{newline}{indentation}"),
+            start,
+            end,
+            to_skip: false,
+        },
+    ))
+}
+
+/// Report an `else` that isn't on the same line as the `}` before it.
+fn else_placement(else_clause: &RSyntaxNode, source: &str) -> Option<Diagnostic> {
+    let else_token = else_clause.first_token()?;
+    let previous = else_token.prev_token()?;
+    if previous.text_trimmed() != "}" {
+        return None;
+    }
+
+    let start: usize = previous.text_trimmed_range().end().into();
+    let end: usize = else_token.text_trimmed_range().start().into();
+    let gap = &source[start..end];
+    if !gap.contains('\n') {
+        return None;
+    }
+
+    let fix = if gap.contains('#') {
+        Fix::empty()
+    } else {
+        Fix {
+            content: " ".to_string(),
+            start,
+            end,
+            to_skip: false,
+        }
+    };
+
+    Some(Diagnostic::new(
+        ViolationData::new(
+            "brace_style".to_string(),
+            "`else` should be on the same line as the `}` before it.".to_string(),
+            Some("Use `} else`.".to_string()),
+        ),
+        else_token.text_trimmed_range(),
+        fix,
+    ))
+}
@@ -0,0 +1,129 @@
+pub(crate) mod brace_style;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_brace_style() {
+        expect_no_lint("if (x) {\n  y\n}", "brace_style", None);
+        expect_no_lint("if (x) { y }", "brace_style", None);
+        expect_no_lint("if (x) {\n  y\n} else {\n  z\n}", "brace_style", None);
+        expect_no_lint("if (x) y else z", "brace_style", None);
+        expect_no_lint("for (i in x) {\n  f(i)\n}", "brace_style", None);
+        expect_no_lint("while (x) {\n  y\n}", "brace_style", None);
+        expect_no_lint("repeat {\n  break\n}", "brace_style", None);
+        expect_no_lint("f <- function(x) {\n  x\n}", "brace_style", None);
+        expect_no_lint("f <- function(x) x + 1", "brace_style", None);
+        expect_no_lint("f <- function() {}", "brace_style", None);
+        expect_no_lint("f <- function() {\n}", "brace_style", None);
+        expect_no_lint("tryCatch({\n  x\n}, error = identity)", "brace_style", None);
+        expect_no_lint(
+            "# nolint: brace_style\nif (x)\n{\n  y\n}",
+            "brace_style",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_brace_style() {
+        use insta::assert_snapshot;
+
+        expect_lint(
+            "if (x)\n{\n  y\n}",
+            "`{` should be on the same line as `if`",
+            "brace_style",
+            None,
+        );
+        expect_lint(
+            "f <- function(x)\n{\n  x\n}",
+            "`{` should be on the same line as `function`",
+            "brace_style",
+            None,
+        );
+        expect_lint(
+            "for (i in x)\n{\n  f(i)\n}",
+            "`{` should be on the same line as `for`",
+            "brace_style",
+            None,
+        );
+        expect_lint(
+            "while (x)\n{\n  y\n}",
+            "`{` should be on the same line as `while`",
+            "brace_style",
+            None,
+        );
+        expect_lint(
+            "repeat\n{\n  break\n}",
+            "`{` should be on the same line as `repeat`",
+            "brace_style",
+            None,
+        );
+        expect_lint(
+            "if (x) {\n  y\n} else\n{\n  z\n}",
+            "`{` should be on the same line as `else`",
+            "brace_style",
+            None,
+        );
+        expect_lint(
+            "if (x) {\n  y }",
+            "`}` should be on its own line",
+            "brace_style",
+            None,
+        );
+        expect_lint(
+            "{\n  if (x) {\n    y\n  }\n  else {\n    z\n  }\n}",
+            "`else` should be on the same line as the `}` before it",
+            "brace_style",
+            None,
+        );
+
+        assert_eq!(
+            check_code("for (i in x)\n{\n  f(i) }", "brace_style", None).len(),
+            2
+        );
+        assert_eq!(
+            check_code(
+                "f <- function() {\n  if (x) {\n    y }}",
+                "brace_style",
+                None
+            )
+            .len(),
+            2
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "if (x)\n{\n  y\n}",
+                    "f <- function(x)\n{\n  x\n}",
+                    "repeat\n{\n  break\n}",
+                    "if (x) {\n  y }",
+                    "for (i in x)\n{\n  f(i) }",
+                    "f <- function() {\n  if (x) {\n    y }}",
+                    "{\n  if (x) {\n    y\n  }\n  else {\n    z\n  }\n}",
+                ],
+                "brace_style",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_brace_style_with_comments_no_fix() {
+        use insta::assert_snapshot;
+
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_fixed_text(
+                vec![
+                    "if (x) # comment\n{\n  y\n}",
+                    "{\n  if (x) {\n    y\n  }\n  # comment\n  else {\n    z\n  }\n}",
+                ],
+                "brace_style",
+                None
+            )
+        );
+    }
+}
@@ -10,6 +10,7 @@ mod tests {
 
         expect_no_lint("if (is.null(x)) y", "coalesce", version);
         expect_no_lint("if (!is.null(x)) y", "coalesce", version);
+        // Reported by the opt-in rule `null_check_without_else`
         expect_no_lint("if (!is.null(x)) x", "coalesce", version);
         expect_no_lint("if (is.null(x)) x", "coalesce", version);
         expect_no_lint("c(if (!is.null(E)) E)", "coalesce", version);
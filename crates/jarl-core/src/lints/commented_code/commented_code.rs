@@ -0,0 +1,123 @@
+use crate::diagnostic::*;
+use crate::directive::{parse_assignment_directive, parse_comment_directive};
+use crate::utils_ast::enclosing_statement;
+use air_r_parser::RParserOptions;
+use air_r_syntax::*;
+use biome_rowan::Direction;
+
+/// ## What it does
+///
+/// Checks for comments that contain R code, such as `# x <- foo(bar)`.
+///
+/// ## Why is this bad?
+///
+/// Code that is commented out is not run, so it quickly gets out of date and
+/// makes the surrounding code harder to read. Version control is a better way
+/// to keep old code around.
+///
+/// A comment is reported if its content can be parsed as R code and looks
+/// like a statement, such as a function call or an assignment. Comments that
+/// contain a single word or a number are not reported, and neither are
+/// roxygen2 comments (`#'`), shebangs (`#!`), and `# nolint` or `# jarl:`
+/// directives.
+///
+/// Since this rule guesses whether a comment is code, its violations have a
+/// heuristic confidence and can be hidden with `--min-confidence high`. It is
+/// disabled by default.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// x <- 1
+/// # y <- foo(x)
+/// z <- 2
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- 1
+/// z <- 2
+/// ```
+pub fn commented_code(root: &RSyntaxNode) -> anyhow::Result<Vec<(RSyntaxNode, Diagnostic)>> {
+    let mut diagnostics = vec![];
+    for token in root.descendants_tokens(Direction::Next) {
+        let Some(parent) = token.parent() else {
+            continue;
+        };
+        for piece in token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces())
+        {
+            if !piece.is_comments() || !is_commented_code(piece.text()) {
+                continue;
+            }
+
+            let diagnostic = Diagnostic::new(
+                ViolationData::new(
+                    "commented_code".to_string(),
+                    "This comment contains code.".to_string(),
+                    Some("Remove the commented code.".to_string()),
+                ),
+                piece.text_range(),
+                Fix::empty(),
+            );
+
+            // Suppression comments apply to the whole statement.
+            diagnostics.push((enclosing_statement(&parent), diagnostic));
+        }
+    }
+
+    Ok(diagnostics)
+}
+
+/// Whether the text of a comment, including its `#`, is valid R code that
+/// looks like a statement.
+fn is_commented_code(comment: &str) -> bool {
+    if comment.starts_with("#'")
+        || comment.starts_with("#!")
+        || parse_comment_directive(comment).is_some()
+        || parse_assignment_directive(comment).is_some()
+    {
+        return false;
+    }
+
+    let content = comment.trim_start_matches('#').trim();
+    if content.is_empty() || content.starts_with("nolint") || content.starts_with("jarl:") {
+        return false;
+    }
+
+    let parsed = air_r_parser::parse(content, RParserOptions::default());
+    if parsed.has_error() {
+        return false;
+    }
+
+    let expressions: Vec<_> = parsed.tree().expressions().into_iter().collect();
+    !expressions.is_empty() && expressions.iter().all(is_statement)
+}
+
+/// Whether an expression looks like a statement rather than prose that
+/// happens to be valid R code, e.g. a single word.
+fn is_statement(expression: &AnyRExpression) -> bool {
+    match expression {
+        AnyRExpression::RBinaryExpression(binary) => {
+            // `see ?foo` is parsed as a call to `?`.
+            binary
+                .operator()
+                .is_ok_and(|operator| operator.text_trimmed() != "?")
+        }
+        AnyRExpression::RCall(_)
+        | AnyRExpression::RFunctionDefinition(_)
+        | AnyRExpression::RIfStatement(_)
+        | AnyRExpression::RForStatement(_)
+        | AnyRExpression::RWhileStatement(_)
+        | AnyRExpression::RRepeatStatement(_)
+        | AnyRExpression::RSubset(_)
+        | AnyRExpression::RSubset2(_)
+        | AnyRExpression::RExtractExpression(_)
+        | AnyRExpression::RNamespaceExpression(_) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,99 @@
+pub(crate) mod commented_code;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_commented_code() {
+        expect_no_lint("# This is a comment\nx <- 1", "commented_code", None);
+        expect_no_lint("# TODO: fix this\nx <- 1", "commented_code", None);
+        expect_no_lint("# x\nx <- 1", "commented_code", None);
+        expect_no_lint("# 1\nx <- 1", "commented_code", None);
+        expect_no_lint("# (optional)\nx <- 1", "commented_code", None);
+        expect_no_lint("# see ?mean\nx <- 1", "commented_code", None);
+        expect_no_lint("# Section ----\nx <- 1", "commented_code", None);
+        expect_no_lint("#\nx <- 1", "commented_code", None);
+        expect_no_lint(
+            "#' @examples\n#' f(1)\nf <- function(x) x",
+            "commented_code",
+            None,
+        );
+        expect_no_lint("#!/usr/bin/env Rscript\nx <- 1", "commented_code", None);
+        expect_no_lint("# nolint\nx = 1", "commented_code", None);
+        expect_no_lint("x = 1 # nolint: assignment", "commented_code", None);
+        expect_no_lint(
+            "# nolint start\nx = 1\n# nolint end",
+            "commented_code",
+            None,
+        );
+        expect_no_lint("# jarl: assignment = \"=\"\nx = 1", "commented_code", None);
+        expect_no_lint("x <- '# y <- 2'", "commented_code", None);
+        // Incomplete code can't be parsed.
+        expect_no_lint("# f <- function(x) {\nx <- 1", "commented_code", None);
+        // Suppressed.
+        expect_no_lint(
+            "# nolint: commented_code\nx <- 1 # y <- 2",
+            "commented_code",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_commented_code() {
+        let expected_message = "This comment contains code";
+        expect_lint(
+            "# x <- foo(bar)\ny <- 1",
+            expected_message,
+            "commented_code",
+            None,
+        );
+        expect_lint(
+            "x <- 1\n# print(x)",
+            expected_message,
+            "commented_code",
+            None,
+        );
+        expect_lint("x <- 1 # y <- 2", expected_message, "commented_code", None);
+        expect_lint(
+            "## library(dplyr)",
+            expected_message,
+            "commented_code",
+            None,
+        );
+        expect_lint("#x[1] <- 2", expected_message, "commented_code", None);
+        expect_lint("# df$col", expected_message, "commented_code", None);
+        expect_lint("# dplyr::filter", expected_message, "commented_code", None);
+        expect_lint("# if (x) y", expected_message, "commented_code", None);
+        expect_lint(
+            "# for (i in x) print(i)",
+            expected_message,
+            "commented_code",
+            None,
+        );
+        expect_lint(
+            "f <- function() {\n  # stop('error')\n  1\n}",
+            expected_message,
+            "commented_code",
+            None,
+        );
+
+        assert_eq!(
+            check_code(
+                "# a <- 1\n# b <- 2\n# Some text\nc <- 3",
+                "commented_code",
+                None
+            )
+            .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_commented_code_range() {
+        let diagnostics = check_code("x <- 1 # y <- 2", "commented_code", None);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(u32::from(diagnostics[0].range.start()), 7);
+        assert_eq!(u32::from(diagnostics[0].range.end()), 15);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::diagnostic::*;
-use crate::utils::node_contains_comments;
+use crate::utils::{negated_comparison_operator, node_contains_comments};
 use air_r_syntax::*;
 use biome_rowan::AstNode;
 
@@ -40,30 +40,10 @@ pub fn comparison_negation(ast: &RUnaryExpression) -> anyhow::Result<Option<Diag
     let body = paren_expr.body()?;
     let binary_expression = unwrap_or_return_none!(body.as_r_binary_expression());
     let operator = binary_expression.operator()?;
-    let operator_kind = operator.kind();
     let left = binary_expression.left()?;
     let right = binary_expression.right()?;
 
-    if operator_kind != RSyntaxKind::GREATER_THAN
-        && operator_kind != RSyntaxKind::GREATER_THAN_OR_EQUAL_TO
-        && operator_kind != RSyntaxKind::LESS_THAN
-        && operator_kind != RSyntaxKind::LESS_THAN_OR_EQUAL_TO
-        && operator_kind != RSyntaxKind::EQUAL2
-        && operator_kind != RSyntaxKind::NOT_EQUAL
-    {
-        return Ok(None);
-    }
-
-    let replacement_operator = match operator_kind {
-        RSyntaxKind::GREATER_THAN => "<=",
-        RSyntaxKind::GREATER_THAN_OR_EQUAL_TO => "<",
-        RSyntaxKind::LESS_THAN => ">=",
-        RSyntaxKind::LESS_THAN_OR_EQUAL_TO => ">",
-        RSyntaxKind::EQUAL2 => "!=",
-        RSyntaxKind::NOT_EQUAL => "==",
-        // Safety: returned early if not one of the operators in this statement.
-        _ => unreachable!(),
-    };
+    let replacement_operator = unwrap_or_return_none!(negated_comparison_operator(operator.kind()));
 
     let range = ast.syntax().text_trimmed_range();
     let diagnostic = Diagnostic::new(
@@ -0,0 +1,105 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name, get_function_name, get_unnamed_args};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Functions that concatenate their unnamed arguments to build the message of
+/// the condition.
+const CONDITION_FUNCTIONS: &[&str] = &["stop", "warning", "message", "packageStartupMessage"];
+
+/// ## What it does
+///
+/// Checks for usage of `paste()` and `paste0()` to build the message in
+/// `stop()`, `warning()`, `message()`, and `packageStartupMessage()`.
+///
+/// ## Why is this bad?
+///
+/// These functions already concatenate their arguments (without separator),
+/// so there is no need to call `paste()` or `paste0()` first.
+///
+/// This rule doesn't report calls to `paste()` or `paste0()` with `collapse`,
+/// or calls to `paste()` with a custom `sep`, since these change the message.
+/// Note that `paste()` uses a space as separator by default, so spaces must be
+/// added manually when the arguments are passed directly to `stop()`.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// stop(paste0("`x` must be positive, not ", x, "."))
+/// warning(paste("Column", col, "is empty."), call. = FALSE)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// stop("`x` must be positive, not ", x, ".")
+/// warning("Column ", col, " is empty.", call. = FALSE)
+/// ```
+pub fn condition_message(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    let function_name = get_function_name(ast.function()?);
+    if !CONDITION_FUNCTIONS.contains(&function_name.as_str()) {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let paste_call = unwrap_or_return_none!(
+        get_unnamed_args(&args)
+            .iter()
+            .filter_map(|arg| arg.value())
+            .find_map(|value| value.as_r_call().cloned().filter(is_simple_paste))
+    );
+    let paste_name = get_function_name(paste_call.function()?);
+
+    let range = paste_call.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "condition_message".to_string(),
+            format!("Using `{paste_name}()` in `{function_name}()` is unnecessary."),
+            Some(format!(
+                "`{function_name}()` already concatenates its arguments, pass them directly."
+            )),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `call` is `paste()` or `paste0()` without `collapse`, and without
+/// a custom `sep` for `paste()`.
+fn is_simple_paste(call: &RCall) -> bool {
+    let Ok(function) = call.function() else {
+        return false;
+    };
+    let function_name = get_function_name(function);
+    if function_name != "paste" && function_name != "paste0" {
+        return false;
+    }
+
+    let Ok(args) = call.arguments() else {
+        return false;
+    };
+    let args = args.items();
+
+    let is_null_or_missing = |name: &str| match get_arg_by_name(&args, name) {
+        None => true,
+        Some(arg) => arg
+            .value()
+            .is_some_and(|value| value.as_r_null_expression().is_some()),
+    };
+    if !is_null_or_missing("collapse") {
+        return false;
+    }
+
+    function_name == "paste0"
+        || get_arg_by_name(&args, "sep").is_none_or(|sep| {
+            sep.value().is_some_and(|value| {
+                matches!(
+                    value.to_trimmed_string().as_str(),
+                    "\"\"" | "''" | "\" \"" | "' '"
+                )
+            })
+        })
+}
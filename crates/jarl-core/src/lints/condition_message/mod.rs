@@ -0,0 +1,118 @@
+pub(crate) mod condition_message;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_condition_message() {
+        expect_no_lint("stop('a', x)", "condition_message", None);
+        expect_no_lint("stop(x)", "condition_message", None);
+        expect_no_lint("stop()", "condition_message", None);
+        expect_no_lint("stop(foo(x, y))", "condition_message", None);
+        expect_no_lint("foo(paste0('a', x))", "condition_message", None);
+        expect_no_lint("x <- paste0('a', x)", "condition_message", None);
+
+        // `collapse` and custom `sep` change the message
+        expect_no_lint("stop(paste(x, collapse = ', '))", "condition_message", None);
+        expect_no_lint(
+            "stop(paste0('a', x, collapse = ''))",
+            "condition_message",
+            None,
+        );
+        expect_no_lint(
+            "warning(paste(x, y, sep = ', '))",
+            "condition_message",
+            None,
+        );
+        expect_no_lint("warning(paste(x, y, sep = sep))", "condition_message", None);
+
+        // Named arguments are not part of the message
+        expect_no_lint(
+            "stop('a', call. = paste0('a', x))",
+            "condition_message",
+            None,
+        );
+
+        // Functions that don't concatenate their arguments
+        expect_no_lint("rlang::abort(paste0('a', x))", "condition_message", None);
+        expect_no_lint("cli::cli_abort(paste0('a', x))", "condition_message", None);
+    }
+
+    #[test]
+    fn test_lint_condition_message() {
+        let expected_message = "is unnecessary";
+        expect_lint(
+            "stop(paste('a', x))",
+            expected_message,
+            "condition_message",
+            None,
+        );
+        expect_lint(
+            "stop(paste0('a', x))",
+            expected_message,
+            "condition_message",
+            None,
+        );
+        expect_lint(
+            "warning(paste0('a', x))",
+            expected_message,
+            "condition_message",
+            None,
+        );
+        expect_lint(
+            "message(paste0('a', x))",
+            expected_message,
+            "condition_message",
+            None,
+        );
+        expect_lint(
+            "packageStartupMessage(paste0('a', x))",
+            expected_message,
+            "condition_message",
+            None,
+        );
+        expect_lint(
+            "base::stop(base::paste0('a', x))",
+            expected_message,
+            "condition_message",
+            None,
+        );
+        expect_lint(
+            "stop(paste('a', x), call. = FALSE)",
+            expected_message,
+            "condition_message",
+            None,
+        );
+        expect_lint(
+            "stop('a', paste0('b', x), 'c')",
+            expected_message,
+            "condition_message",
+            None,
+        );
+        expect_lint(
+            "stop(paste(x, sep = ''))",
+            expected_message,
+            "condition_message",
+            None,
+        );
+        expect_lint(
+            "stop(paste(x, collapse = NULL))",
+            expected_message,
+            "condition_message",
+            None,
+        );
+
+        // The inner call is reported
+        expect_diagnostic_highlight(
+            "stop(paste0('a', x))",
+            "condition_message",
+            "paste0('a', x)",
+        );
+        expect_diagnostic_highlight(
+            "warning('a', paste('b', x), call. = FALSE)",
+            "condition_message",
+            "paste('b', x)",
+        );
+    }
+}
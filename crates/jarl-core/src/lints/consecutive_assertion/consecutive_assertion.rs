@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+
+use crate::diagnostic::*;
+use crate::utils::{get_function_name, node_contains_comments};
+use crate::utils_ast::AstNodeExt;
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+
+/// Arguments of `stopifnot()` that are not conditions.
+const STOPIFNOT_OPTIONS: [&str; 3] = ["exprs", "exprObject", "local"];
+
+/// ## What it does
+///
+/// Checks for consecutive calls to `stopifnot()` at the same level, e.g. in
+/// the body of a function.
+///
+/// ## Why is this bad?
+///
+/// `stopifnot()` accepts several conditions and checks them in order, so
+/// consecutive calls can be merged into a single one. This makes the list of
+/// assertions easier to read.
+///
+/// This rule has a safe fix. There is no fix if several calls use the same
+/// argument name (e.g. a custom error message), if any call uses the arguments
+/// `exprs`, `exprObject` or `local`, or if there are comments between the calls.
+///
+/// ## Example
+///
+/// ```r
+/// f <- function(x, y) {
+///   stopifnot(is.numeric(x))
+///   stopifnot(is.numeric(y), length(y) == 1)
+///   x + y
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// f <- function(x, y) {
+///   stopifnot(is.numeric(x), is.numeric(y), length(y) == 1)
+///   x + y
+/// }
+/// ```
+pub fn consecutive_assertion(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    if !is_stopifnot(ast) {
+        return Ok(None);
+    }
+
+    // Only report the first call of a sequence.
+    if ast
+        .previous_statement()
+        .is_some_and(|prev| prev.as_r_call().is_some_and(is_stopifnot))
+    {
+        return Ok(None);
+    }
+
+    let mut calls = vec![ast.clone()];
+    while let Some(next) = calls.last().and_then(|call| call.next_statement())
+        && let Some(next) = next.as_r_call()
+        && is_stopifnot(next)
+    {
+        calls.push(next.clone());
+    }
+
+    if calls.len() < 2 {
+        return Ok(None);
+    }
+
+    // Safety: `calls` has at least two elements.
+    let first = calls.first().unwrap();
+    let last = calls.last().unwrap();
+    let range = TextRange::new(
+        first.syntax().text_trimmed_range().start(),
+        last.syntax().text_trimmed_range().end(),
+    );
+
+    let mut arguments = vec![];
+    let mut arg_names = HashSet::new();
+    let mut has_conflicting_names = false;
+    for call in &calls {
+        for arg in call.arguments()?.items().iter() {
+            let arg = arg?;
+            if let Some(name_clause) = arg.name_clause() {
+                let name = name_clause.name()?.to_trimmed_string();
+                let name = name.replace(&['\'', '"', '`'][..], "");
+                if STOPIFNOT_OPTIONS.contains(&name.as_str()) || !arg_names.insert(name) {
+                    has_conflicting_names = true;
+                }
+            }
+            arguments.push(arg.syntax().text_trimmed().to_string());
+        }
+    }
+
+    let last_index = calls.len() - 1;
+    let has_comments = calls.iter().enumerate().any(|(i, call)| {
+        let node = call.syntax();
+        node_contains_comments(node)
+            || (i > 0 && node.has_leading_comments())
+            || (i < last_index && node.has_trailing_comments())
+    });
+
+    let fix = if has_conflicting_names {
+        Fix::empty()
+    } else {
+        Fix {
+            content: format!(
+                "{}({})",
+                first.function()?.syntax().text_trimmed(),
+                arguments.join(", ")
+            ),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: has_comments,
+        }
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "consecutive_assertion".to_string(),
+            format!(
+                "{} consecutive calls to `stopifnot()` can be merged.",
+                calls.len()
+            ),
+            Some("Use a single call to `stopifnot()` with all conditions instead.".to_string()),
+        ),
+        range,
+        fix,
+    );
+
+    Ok(Some(diagnostic))
+}
+
+fn is_stopifnot(call: &RCall) -> bool {
+    call.function()
+        .is_ok_and(|function| get_function_name(function) == "stopifnot")
+}
@@ -0,0 +1,100 @@
+pub(crate) mod consecutive_assertion;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_consecutive_assertion() {
+        expect_no_lint("stopifnot(x)", "consecutive_assertion", None);
+        expect_no_lint("stopifnot(x, y)", "consecutive_assertion", None);
+        expect_no_lint(
+            "stopifnot(x)\nfoo()\nstopifnot(y)",
+            "consecutive_assertion",
+            None,
+        );
+        expect_no_lint(
+            "f <- function() {\n  stopifnot(x)\n  y\n}\nstopifnot(z)",
+            "consecutive_assertion",
+            None,
+        );
+        expect_no_lint(
+            "if (a) stopifnot(x) else stopifnot(y)",
+            "consecutive_assertion",
+            None,
+        );
+        expect_no_lint(
+            "foo(stopifnot(x), stopifnot(y))",
+            "consecutive_assertion",
+            None,
+        );
+        expect_no_lint("assert(x)\nassert(y)", "consecutive_assertion", None);
+    }
+
+    #[test]
+    fn test_lint_consecutive_assertion() {
+        use insta::assert_snapshot;
+
+        expect_lint(
+            "stopifnot(x)\nstopifnot(y)",
+            "2 consecutive calls to `stopifnot()` can be merged",
+            "consecutive_assertion",
+            None,
+        );
+        expect_lint(
+            "f <- function() {\n  stopifnot(x)\n  stopifnot(y)\n  stopifnot(z)\n}",
+            "3 consecutive calls to `stopifnot()` can be merged",
+            "consecutive_assertion",
+            None,
+        );
+        expect_lint(
+            "stopifnot(x); base::stopifnot(y)",
+            "2 consecutive calls to `stopifnot()` can be merged",
+            "consecutive_assertion",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "stopifnot(x)\nstopifnot(y)",
+                    "stopifnot(x); stopifnot(y, z)",
+                    "f <- function() {\n  stopifnot(x)\n  stopifnot(y)\n  stopifnot(z)\n  x\n}",
+                    "stopifnot(\"x must be positive\" = x > 0)\nstopifnot(\"y must be positive\" = y > 0)",
+                    "base::stopifnot(x)\nstopifnot(y)",
+                ],
+                "consecutive_assertion",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_consecutive_assertion_no_fix() {
+        use insta::assert_snapshot;
+        assert_snapshot!(
+            "no_fix",
+            get_fixed_text(
+                vec![
+                    "stopifnot(\"must be positive\" = x > 0)\nstopifnot(\"must be positive\" = y > 0)",
+                    "stopifnot(x)\nstopifnot(exprs = {\n  y\n})",
+                    "stopifnot(x)\n# comment\nstopifnot(y)",
+                    "stopifnot(x) # comment\nstopifnot(y)",
+                    "stopifnot(x, # comment\n  y)\nstopifnot(z)",
+                ],
+                "consecutive_assertion",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_consecutive_assertion_diagnostic_highlight() {
+        expect_diagnostic_highlight(
+            "x <- 1\nstopifnot(x)\nstopifnot(y)\nx",
+            "consecutive_assertion",
+            "stopifnot(x)\nstopifnot(y)",
+        );
+    }
+}
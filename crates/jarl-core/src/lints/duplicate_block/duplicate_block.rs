@@ -0,0 +1,169 @@
+use crate::diagnostic::*;
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstNodeList, Direction, TextRange};
+use std::collections::{HashMap, HashSet};
+
+/// ## What it does
+///
+/// Checks for blocks of consecutive statements that appear several times in
+/// the same file, for instance in two different functions.
+///
+/// ## Why is this bad?
+///
+/// Copy-pasted code is harder to maintain: when one of the copies is modified
+/// (e.g. to fix a bug), the others must be modified in the same way, which is
+/// easy to forget. Extracting the block into a function avoids that.
+///
+/// Two blocks are considered duplicated if their code is the same, ignoring
+/// whitespace and comments. Names of variables must be the same too, so blocks
+/// that only differ by the name of a variable are not reported.
+///
+/// By default, this rule reports blocks of at least 3 statements. This can be
+/// changed with `duplicate-block-min-size` in `jarl.toml`:
+///
+/// ```toml
+/// [lint]
+/// duplicate-block-min-size = 5
+/// ```
+///
+/// This rule is disabled by default because it is more expensive than other
+/// rules. It doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// clean_a <- function(x) {
+///   x <- x[!is.na(x)]
+///   x <- trimws(x)
+///   x <- tolower(x)
+///   table(x)
+/// }
+///
+/// clean_b <- function(x) {
+///   x <- x[!is.na(x)]
+///   x <- trimws(x)
+///   x <- tolower(x)
+///   unique(x)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// clean <- function(x) {
+///   x <- x[!is.na(x)]
+///   x <- trimws(x)
+///   tolower(x)
+/// }
+///
+/// clean_a <- function(x) {
+///   table(clean(x))
+/// }
+///
+/// clean_b <- function(x) {
+///   unique(clean(x))
+/// }
+/// ```
+pub fn duplicate_block(
+    root: &RSyntaxNode,
+    min_size: usize,
+) -> anyhow::Result<Vec<(RSyntaxNode, Diagnostic)>> {
+    // Each diagnostic comes with the first statement of the duplicated block,
+    // which is used to check for suppression comments.
+    let mut windows = vec![];
+
+    for (list_id, list) in root
+        .descendants()
+        .filter_map(RExpressionList::cast)
+        .enumerate()
+    {
+        let statements = list.iter().map(|x| x.into_syntax()).collect::<Vec<_>>();
+        if statements.len() < min_size {
+            continue;
+        }
+        let normalized = statements.iter().map(normalize).collect::<Vec<_>>();
+
+        for start in 0..=(statements.len() - min_size) {
+            let end = start + min_size - 1;
+            windows.push(Window {
+                key: normalized[start..=end].join("\n"),
+                list_id,
+                start,
+                range: TextRange::new(
+                    statements[start].text_trimmed_range().start(),
+                    statements[end].text_trimmed_range().end(),
+                ),
+                first_statement: statements[start].clone(),
+            });
+        }
+    }
+
+    windows.sort_by_key(|window| window.range.start());
+
+    let mut first_seen: HashMap<&str, &Window> = HashMap::new();
+    // Statements that are already part of a reported block, so that a block
+    // longer than `min_size` is reported only once.
+    let mut reported: HashSet<(usize, usize)> = HashSet::new();
+    let mut source: Option<String> = None;
+    let mut diagnostics = vec![];
+
+    for window in &windows {
+        let Some(&first) = first_seen.get(window.key.as_str()) else {
+            first_seen.insert(&window.key, window);
+            continue;
+        };
+
+        let statements = window.start..(window.start + min_size);
+        let overlaps_first =
+            first.list_id == window.list_id && first.start + min_size > window.start;
+        let overlaps_reported = statements
+            .clone()
+            .any(|i| reported.contains(&(window.list_id, i)));
+        if overlaps_first || overlaps_reported {
+            continue;
+        }
+        reported.extend(statements.map(|i| (window.list_id, i)));
+
+        let text = source.get_or_insert_with(|| root.text().to_string());
+        let first_line = text[..usize::from(first.range.start())]
+            .matches('\n')
+            .count()
+            + 1;
+
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "duplicate_block".to_string(),
+                format!(
+                    "This block of {min_size} statements is a duplicate of the block starting at line {first_line}."
+                ),
+                Some("Extract the duplicated code into a function.".to_string()),
+            ),
+            window.range,
+            Fix::empty(),
+        );
+        diagnostics.push((window.first_statement.clone(), diagnostic));
+    }
+
+    Ok(diagnostics)
+}
+
+/// A sequence of `min_size` consecutive statements.
+struct Window {
+    /// Normalized code of the statements.
+    key: String,
+    /// Identifies the list of statements the window belongs to, e.g. the body
+    /// of a function.
+    list_id: usize,
+    /// Position of the first statement in the list.
+    start: usize,
+    range: TextRange,
+    first_statement: RSyntaxNode,
+}
+
+/// The code of a statement without whitespace and comments.
+fn normalize(statement: &RSyntaxNode) -> String {
+    statement
+        .descendants_tokens(Direction::Next)
+        .map(|token| token.text_trimmed().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
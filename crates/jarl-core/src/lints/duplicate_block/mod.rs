@@ -0,0 +1,152 @@
+pub(crate) mod duplicate_block;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_duplicate_block() {
+        expect_no_lint(
+            "
+f <- function(x) {
+  x <- x + 1
+  y <- x * 2
+  z <- y - 3
+}
+g <- function(x) {
+  x <- x + 1
+  y <- x * 3
+  z <- y - 3
+}",
+            "duplicate_block",
+            None,
+        );
+        // Identifiers are not normalized.
+        expect_no_lint(
+            "
+f <- function(x) {
+  x <- x + 1
+  y <- x * 2
+  z <- y - 3
+}
+g <- function(a) {
+  a <- a + 1
+  y <- a * 2
+  z <- y - 3
+}",
+            "duplicate_block",
+            None,
+        );
+        // Blocks that are too short.
+        expect_no_lint(
+            "
+f <- function(x) {
+  x <- x + 1
+  y <- x * 2
+}
+g <- function(x) {
+  x <- x + 1
+  y <- x * 2
+}",
+            "duplicate_block",
+            None,
+        );
+        // Overlapping repetitions of the same statement.
+        expect_no_lint(
+            "x <- x + 1\nx <- x + 1\nx <- x + 1\nx <- x + 1",
+            "duplicate_block",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_duplicate_block() {
+        let expected_message =
+            "This block of 3 statements is a duplicate of the block starting at line 3";
+
+        expect_lint(
+            "
+f <- function(x) {
+  x <- x + 1
+  y <- x * 2
+  z <- y - 3
+}
+g <- function(x) {
+  x <- x + 1
+  y <- x * 2
+  z <- y - 3
+}",
+            expected_message,
+            "duplicate_block",
+            None,
+        );
+        // Whitespace and comments are ignored.
+        expect_lint(
+            "
+f <- function(x) {
+  x <- x + 1
+  y <- x * 2
+  z <- y - 3
+}
+x<-x+1
+# some comment
+y <- x *
+  2
+z <- y - 3 # other comment",
+            expected_message,
+            "duplicate_block",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_duplicate_block_reported_once() {
+        // A block longer than the minimum size is only reported once.
+        let diagnostics = check_code(
+            "
+f <- function(x) {
+  a <- 1
+  b <- 2
+  c <- 3
+  d <- 4
+}
+g <- function(x) {
+  a <- 1
+  b <- 2
+  c <- 3
+  d <- 4
+}",
+            "duplicate_block",
+            None,
+        );
+        assert_eq!(diagnostics.len(), 1);
+
+        // Three copies give two violations.
+        let diagnostics = check_code(
+            "a <- 1\nb <- 2\nc <- 3\n\na <- 1\nb <- 2\nc <- 3\n\na <- 1\nb <- 2\nc <- 3",
+            "duplicate_block",
+            None,
+        );
+        assert_eq!(diagnostics.len(), 2);
+    }
+
+    #[test]
+    fn test_duplicate_block_nolint() {
+        expect_no_lint(
+            "
+f <- function(x) {
+  x <- x + 1
+  y <- x * 2
+  z <- y - 3
+}
+g <- function(x) {
+  # nolint: duplicate_block
+  x <- x + 1
+  y <- x * 2
+  z <- y - 3
+}",
+            "duplicate_block",
+            None,
+        );
+    }
+}
@@ -19,12 +19,28 @@ use biome_rowan::AstNode;
 /// objects. Duplicate-named objects are hard to work with programmatically and
 /// should typically be avoided.
 ///
+/// R also allows partial matching of argument names, so `fun(ver = 1, verbose = 2)`
+/// can be a hidden duplicate if `ver` partially matches the argument `verbose`
+/// of `fun()`. Since Jarl doesn't know the arguments of the called function,
+/// this is only a heuristic: it reports argument names that are a prefix of
+/// another argument name in the same call, and these violations have a
+/// heuristic confidence (see `--min-confidence`). This check is disabled by
+/// default and can be enabled with the `partial_matching` option in `jarl.toml`:
+///
+/// ```toml
+/// [lint.duplicated_arguments]
+/// partial_matching = true
+/// ```
+///
 /// ## Example
 ///
 /// ```r
 /// list(x = 1, x = 2)
 /// ```
-pub fn duplicated_arguments(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+pub fn duplicated_arguments(
+    ast: &RCall,
+    partial_matching: bool,
+) -> anyhow::Result<Option<Diagnostic>> {
     let RCallFields { function, arguments } = ast.as_fields();
 
     let fun_name = match function? {
@@ -115,6 +131,37 @@ pub fn duplicated_arguments(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
         return Ok(Some(diagnostic));
     }
 
+    if !partial_matching {
+        return Ok(None);
+    }
+
+    let partial_matches = get_partial_matches(&arg_names);
+
+    if !partial_matches.is_empty() {
+        let range = ast.syntax().text_trimmed_range();
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "duplicated_arguments".to_string(),
+                [
+                    "Avoid partially matching argument names in function calls. Partial match(es): ",
+                    &partial_matches
+                        .iter()
+                        .map(|(partial, full)| format!("\"{partial}\" and \"{full}\""))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    ".",
+                ]
+                .join("")
+                .to_string(),
+                None,
+            ),
+            range,
+            Fix::empty(),
+        )
+        .with_confidence(Confidence::Heuristic);
+        return Ok(Some(diagnostic));
+    }
+
     Ok(None)
 }
 
@@ -132,3 +179,21 @@ fn get_duplicates(values: &[String]) -> Vec<String> {
     let duplicates_vec: Vec<String> = duplicates.into_iter().collect();
     duplicates_vec
 }
+
+/// Find pairs of argument names where the first one is a prefix of the second
+/// one, e.g. `ver` and `verbose`. The pairs are returned in the order in which
+/// the partial names appear in the call.
+fn get_partial_matches(values: &[String]) -> Vec<(String, String)> {
+    let mut partial_matches = Vec::new();
+    for partial in values {
+        for full in values {
+            if full.len() > partial.len()
+                && full.starts_with(partial.as_str())
+                && !partial_matches.contains(&(partial.clone(), full.clone()))
+            {
+                partial_matches.push((partial.clone(), full.clone()));
+            }
+        }
+    }
+    partial_matches
+}
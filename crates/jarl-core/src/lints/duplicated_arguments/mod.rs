@@ -29,6 +29,9 @@ mod tests {
 
         // `"` and `'` are not the same argument names.
         expect_no_lint("switch(x, `\"` = 1, `'` = 2)", "duplicated_arguments", None);
+
+        // Partial matching is only checked if enabled in `jarl.toml`.
+        expect_no_lint("fun(ver = 1, verbose = 2)", "duplicated_arguments", None);
     }
 
     #[test]
@@ -7,8 +7,8 @@ pub struct EqualsNull;
 
 /// ## What it does
 ///
-/// Check for `x == NULL`, `x != NULL` and `x %in% NULL`, and replaces those by
-/// `is.null()` calls.
+/// Check for `x == NULL`, `x != NULL` and `x %in% NULL`, and replaces the first
+/// two by `is.null()` calls.
 ///
 /// ## Why is this bad?
 ///
@@ -20,6 +20,13 @@ pub struct EqualsNull;
 /// ```
 /// which is very likely not the expected output.
 ///
+/// `x %in% NULL` always returns `FALSE` for each element of `x` (and
+/// `NULL %in% x` always returns `logical(0)`), so it is also reported. It is
+/// unclear what the intended meaning is in this case, so it doesn't have an
+/// automatic fix.
+///
+/// This rule has a safe fix for `x == NULL` and `x != NULL`.
+///
 /// ## Example
 ///
 /// ```r
@@ -99,16 +106,18 @@ pub fn equals_null(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>
                 to_skip: node_contains_comments(ast.syntax()),
             },
         ),
-        RSyntaxKind::SPECIAL if operator.text_trimmed() == "%in%" => Diagnostic::new(
-            EqualsNull,
-            range,
-            Fix {
-                content: format!("is.null({replacement})"),
-                start: range.start().into(),
-                end: range.end().into(),
-                to_skip: node_contains_comments(ast.syntax()),
-            },
-        ),
+        RSyntaxKind::SPECIAL if operator.text_trimmed() == "%in%" => {
+            let body = if left_is_null {
+                "`NULL %in% x` always returns `logical(0)`."
+            } else {
+                "`x %in% NULL` always returns `FALSE`."
+            };
+            Diagnostic::new(
+                ViolationData::new("equals_null".to_string(), body.to_string(), None),
+                range,
+                Fix::empty(),
+            )
+        }
         _ => unreachable!("This case is an early return"),
     };
 
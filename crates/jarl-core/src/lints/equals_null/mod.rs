@@ -12,9 +12,9 @@ mod tests {
 
         expect_lint("x == NULL", expected_message, "equals_null", None);
         expect_lint("x != NULL", expected_message, "equals_null", None);
-        expect_lint("x %in% NULL", expected_message, "equals_null", None);
         expect_lint("foo(x(y)) == NULL", expected_message, "equals_null", None);
         expect_lint("NULL == x", expected_message, "equals_null", None);
+        expect_lint("NULL != x", expected_message, "equals_null", None);
 
         assert_snapshot!(
             "fix_output",
@@ -22,9 +22,9 @@ mod tests {
                 vec![
                     "x == NULL",
                     "x != NULL",
-                    "x %in% NULL",
                     "foo(x(y)) == NULL",
                     "NULL == x",
+                    "NULL != x",
                 ],
                 "equals_null",
                 None,
@@ -32,6 +32,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lint_equals_null_in() {
+        use insta::assert_snapshot;
+
+        expect_lint(
+            "x %in% NULL",
+            "`x %in% NULL` always returns `FALSE`",
+            "equals_null",
+            None,
+        );
+        expect_lint(
+            "NULL %in% x",
+            "`NULL %in% x` always returns `logical(0)`",
+            "equals_null",
+            None,
+        );
+
+        // The intended meaning is unclear so there is no fix.
+        assert_snapshot!(
+            "no_fix_in",
+            get_fixed_text(vec!["x %in% NULL", "NULL %in% x"], "equals_null", None)
+        );
+    }
+
     #[test]
     fn test_no_lint_equals_null() {
         expect_no_lint("x + NULL", "equals_null", None);
@@ -0,0 +1,118 @@
+use crate::diagnostic::*;
+use crate::utils::{
+    get_arg_by_name_then_position, get_function_name, get_function_namespace_prefix,
+    negated_comparison_operator, node_contains_comments,
+};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// ## What it does
+///
+/// Checks for usage of `expect_true(x == y)`, `expect_true(x > y)`, and
+/// similar comparisons in tests.
+///
+/// ## Why is this bad?
+///
+/// `expect_equal()`, `expect_gt()`, `expect_gte()`, `expect_lt()`, and
+/// `expect_lte()` are more explicit than comparing values in `expect_true()`.
+/// They also provide better error messages when tests fail since they show the
+/// values that were compared.
+///
+/// `expect_false()` is also checked, e.g. `expect_false(x > y)` is replaced by
+/// `expect_lte(x, y)`. There is no testthat function for `expect_true(x != y)`
+/// and `expect_false(x == y)`, so those are not reported.
+///
+/// This rule has a safe fix. There is no fix when other arguments are passed
+/// to `expect_true()` or `expect_false()`.
+///
+/// This rule is **disabled by default**. Select it either with the rule name
+/// `"expect_comparison"` or with the rule group `"TESTTHAT"`.
+///
+/// ## Example
+///
+/// ```r
+/// expect_true(x == y)
+/// expect_true(length(x) > 2)
+/// expect_false(x != 1)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// expect_equal(x, y)
+/// expect_gt(length(x), 2)
+/// expect_equal(x, 1)
+/// ```
+pub fn expect_comparison(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    let function = ast.function()?;
+    let function_name = get_function_name(function.clone());
+
+    if function_name != "expect_true" && function_name != "expect_false" {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let object = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "object", 1));
+    let object_value = unwrap_or_return_none!(object.value());
+    let comparison = unwrap_or_return_none!(object_value.as_r_binary_expression());
+    let operator = comparison.operator()?;
+
+    let negated_operator = unwrap_or_return_none!(negated_comparison_operator(operator.kind()));
+
+    // `expect_false(x > y)` is the same as `expect_true(x <= y)`.
+    let tested_operator = if function_name == "expect_false" {
+        negated_operator
+    } else {
+        operator.text_trimmed()
+    };
+
+    let replacement_fn = match tested_operator {
+        "==" => "expect_equal",
+        ">" => "expect_gt",
+        ">=" => "expect_gte",
+        "<" => "expect_lt",
+        "<=" => "expect_lte",
+        _ => return Ok(None),
+    };
+
+    let left = comparison.left()?;
+    let right = comparison.right()?;
+
+    // Preserve namespace prefix if present
+    let namespace_prefix = get_function_namespace_prefix(function).unwrap_or_default();
+    let range = ast.syntax().text_trimmed_range();
+
+    // Other arguments, such as `info` or `label`, would be lost in the fix.
+    let fix = if args.iter().count() > 1 {
+        Fix::empty()
+    } else {
+        Fix {
+            content: format!(
+                "{}{}({}, {})",
+                namespace_prefix,
+                replacement_fn,
+                left.to_trimmed_text(),
+                right.to_trimmed_text()
+            ),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        }
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "expect_comparison".to_string(),
+            format!(
+                "`{}(x {} y)` is not as clear as `{}(x, y)`.",
+                function_name,
+                operator.text_trimmed(),
+                replacement_fn
+            ),
+            Some(format!("Use `{}(x, y)` instead.", replacement_fn)),
+        ),
+        range,
+        fix,
+    );
+
+    Ok(Some(diagnostic))
+}
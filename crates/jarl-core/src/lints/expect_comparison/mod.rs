@@ -0,0 +1,99 @@
+pub(crate) mod expect_comparison;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_expect_comparison() {
+        expect_no_lint("expect_true(x)", "expect_comparison", None);
+        expect_no_lint("expect_true(x && y)", "expect_comparison", None);
+        expect_no_lint("expect_true(x %in% y)", "expect_comparison", None);
+        expect_no_lint("expect_true((x == y))", "expect_comparison", None);
+        expect_no_lint("expect_equal(x == y, TRUE)", "expect_comparison", None);
+        expect_no_lint("foo(x == y)", "expect_comparison", None);
+
+        // No testthat function for these
+        expect_no_lint("expect_true(x != y)", "expect_comparison", None);
+        expect_no_lint("expect_false(x == y)", "expect_comparison", None);
+
+        // Only the first argument is checked
+        expect_no_lint("expect_true(x, info = a == b)", "expect_comparison", None);
+
+        // Wrong syntax but no panic
+        expect_no_lint("expect_true()", "expect_comparison", None);
+        expect_no_lint("expect_true(object =)", "expect_comparison", None);
+    }
+
+    #[test]
+    fn test_lint_expect_comparison() {
+        use insta::assert_snapshot;
+        let expected_message = "is not as clear as";
+
+        expect_lint(
+            "expect_true(x == y)",
+            expected_message,
+            "expect_comparison",
+            None,
+        );
+        expect_lint(
+            "expect_true(x > y)",
+            expected_message,
+            "expect_comparison",
+            None,
+        );
+        expect_lint(
+            "expect_false(x != y)",
+            expected_message,
+            "expect_comparison",
+            None,
+        );
+        expect_lint(
+            "testthat::expect_true(x <= 1)",
+            expected_message,
+            "expect_comparison",
+            None,
+        );
+        expect_lint(
+            "expect_true(object = x < y)",
+            expected_message,
+            "expect_comparison",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "expect_true(x == y)",
+                    "expect_true(length(x) > 2)",
+                    "expect_true(x >= y)",
+                    "expect_true(x < y)",
+                    "testthat::expect_true(x <= y)",
+                    "expect_false(x != 1)",
+                    "expect_false(x > y)",
+                    "expect_false(foo(x) <= bar(y))",
+                ],
+                "expect_comparison",
+                None,
+            )
+        );
+    }
+
+    #[test]
+    fn test_expect_comparison_no_fix() {
+        use insta::assert_snapshot;
+        assert_snapshot!(
+            "no_fix",
+            get_fixed_text(
+                vec![
+                    "expect_true(x == # comment\n y)",
+                    "expect_true(x > y, info = 'x is too small')",
+                    "expect_true(x > y) # trailing comment",
+                ],
+                "expect_comparison",
+                None
+            )
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::diagnostic::*;
 use crate::utils::{
-    get_arg_by_name_then_position, get_function_name, get_function_namespace_prefix,
-    node_contains_comments,
+    get_arg_by_name, get_arg_by_name_then_position, get_function_name,
+    get_function_namespace_prefix, node_contains_comments,
 };
 use air_r_syntax::*;
 use biome_rowan::AstNode;
@@ -17,6 +17,9 @@ use biome_rowan::AstNode;
 /// than comparing with `expect_equal()` or `expect_identical()`. They also
 /// provide better error messages when tests fail.
 ///
+/// This rule has a safe fix. There is no fix when `info` or `label` are
+/// passed since they would be lost.
+///
 /// This rule is **disabled by default**. Select it either with the rule name
 /// `"expect_true_false"` or with the rule group `"TESTTHAT"`.
 ///
@@ -94,6 +97,22 @@ pub fn expect_true_false(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
     // Preserve namespace prefix if present
     let namespace_prefix = get_function_namespace_prefix(function).unwrap_or_default();
     let range = ast.syntax().text_trimmed_range();
+
+    // The fix only keeps the value that is compared, so it would drop the
+    // custom messages.
+    let has_custom_message =
+        get_arg_by_name(&args, "info").is_some() || get_arg_by_name(&args, "label").is_some();
+    let fix = if has_custom_message {
+        Fix::empty()
+    } else {
+        Fix {
+            content: format!("{}{}({})", namespace_prefix, new_function, other_arg_text),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        }
+    };
+
     let diagnostic = Diagnostic::new(
         ViolationData::new(
             "expect_true_false".to_string(),
@@ -101,12 +120,7 @@ pub fn expect_true_false(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
             Some(suggestion.to_string()),
         ),
         range,
-        Fix {
-            content: format!("{}{}({})", namespace_prefix, new_function, other_arg_text),
-            start: range.start().into(),
-            end: range.end().into(),
-            to_skip: node_contains_comments(ast.syntax()),
-        },
+        fix,
     );
 
     Ok(Some(diagnostic))
@@ -68,6 +68,8 @@ mod tests {
                     "expect_equal(TRUE, foo(x))",
                     "expect_equal(FALSE, foo(x))",
                     "testthat::expect_equal(x, TRUE)",
+                    "expect_equal(foo(x), TRUE, info = 'foo')",
+                    "expect_identical(FALSE, foo(x), label = 'foo')",
                 ],
                 "expect_true_false",
                 None,
@@ -1,6 +1,9 @@
 use crate::diagnostic::*;
 use crate::utils::drop_arg_by_name_or_position;
-use crate::utils::{get_arg_by_name_then_position, get_function_name, node_contains_comments};
+use crate::utils::{
+    get_arg_by_name_then_position, get_function_name, get_function_namespace_prefix,
+    node_contains_comments,
+};
 use air_r_syntax::*;
 use biome_rowan::AstNode;
 
@@ -16,7 +19,8 @@ pub struct FixedRegex;
 ///
 /// When a pattern contains no special regex characters, using `fixed = TRUE`
 /// provides a significant performance boost because it uses simple string
-/// matching instead of regex engine pattern matching.
+/// matching instead of regex engine pattern matching. It also makes it clear
+/// to the reader that the pattern must be matched literally.
 ///
 /// This rule has a safe automatic fix.
 ///
@@ -50,6 +54,7 @@ impl Violation for FixedRegex {
 
 pub fn fixed_regex(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
     let function = ast.function()?;
+    let namespace = get_function_namespace_prefix(function.clone()).unwrap_or_default();
     let fn_name = get_function_name(function);
     let args = ast.arguments()?.items();
 
@@ -122,7 +127,7 @@ pub fn fixed_regex(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
             .join(", ")
     };
 
-    let fixed_content = format!("{}({}, fixed = TRUE)", fn_name, args_text);
+    let fixed_content = format!("{namespace}{fn_name}({args_text}, fixed = TRUE)");
 
     let range = ast.syntax().text_trimmed_range();
     let diagnostic = Diagnostic::new(
@@ -140,7 +145,7 @@ pub fn fixed_regex(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
 }
 
 /// Check if a pattern string contains no unescaped regex special characters
-fn is_fixed_pattern(pattern: &str) -> bool {
+pub(crate) fn is_fixed_pattern(pattern: &str) -> bool {
     const REGEX_CHARS: &[u8; 12] = b".*+?[{()|^$\\";
 
     pattern.bytes().all(|b| !REGEX_CHARS.contains(&b))
@@ -90,6 +90,7 @@ mod tests {
                     "gregexpr('a-z', y, fixed = FALSE)",
                     "gregexpr('a-z', y, fixed = FALSE, ignore.case = FALSE)",
                     "gregexpr(pattern = 'a-z', y)",
+                    "base::grepl('abcdefg', x)",
                 ],
                 "fixed_regex",
                 None
@@ -0,0 +1,111 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name, node_contains_comments};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// ## What it does
+///
+/// Checks for usage of `ifelse(x > y, y, x)` and `ifelse(x < y, y, x)` (and
+/// similar patterns), which can be replaced by `pmin(x, y)` and `pmax(x, y)`.
+/// Calls to `dplyr::if_else()` and `data.table::fifelse()` are also checked.
+///
+/// ## Why is this bad?
+///
+/// `pmin()` and `pmax()` are clearer and faster than building the minimum or
+/// maximum of two vectors element-wise with `ifelse()`.
+///
+/// This rule has a safe fix. The comparison operands and the two branches
+/// must be written exactly the same way for the rule to apply.
+///
+/// ## Example
+///
+/// ```r
+/// x <- c(1, 5, 8)
+/// ifelse(x > 4, 4, x)
+/// ifelse(x < 2, 2, x)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- c(1, 5, 8)
+/// pmin(x, 4)
+/// pmax(x, 2)
+/// ```
+pub fn ifelse_censor(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    let fn_name = get_function_name(ast.function()?);
+
+    let (test_name, yes_name, no_name) = match fn_name.as_str() {
+        "ifelse" | "fifelse" => ("test", "yes", "no"),
+        "if_else" => ("condition", "true", "false"),
+        _ => return Ok(None),
+    };
+
+    let args = ast.arguments()?.items();
+
+    // Don't want to handle additional args, e.g. `missing` in `if_else()`.
+    if args.iter().count() != 3 {
+        return Ok(None);
+    }
+
+    let test = unwrap_or_return_none!(get_arg_by_name_then_position(&args, test_name, 1));
+    let yes = unwrap_or_return_none!(get_arg_by_name_then_position(&args, yes_name, 2));
+    let no = unwrap_or_return_none!(get_arg_by_name_then_position(&args, no_name, 3));
+
+    let test = unwrap_or_return_none!(test.value());
+    let yes = unwrap_or_return_none!(yes.value());
+    let no = unwrap_or_return_none!(no.value());
+
+    let comparison = unwrap_or_return_none!(test.as_r_binary_expression());
+    let operator = comparison.operator()?;
+    let is_greater = match operator.kind() {
+        RSyntaxKind::GREATER_THAN | RSyntaxKind::GREATER_THAN_OR_EQUAL_TO => true,
+        RSyntaxKind::LESS_THAN | RSyntaxKind::LESS_THAN_OR_EQUAL_TO => false,
+        _ => return Ok(None),
+    };
+
+    let left = comparison.left()?;
+    let right = comparison.right()?;
+    let left_text = left.syntax().text_trimmed();
+    let right_text = right.syntax().text_trimmed();
+    let yes_text = yes.syntax().text_trimmed();
+    let no_text = no.syntax().text_trimmed();
+
+    // `ifelse(x > y, y, x)` returns the smaller value and `ifelse(x > y, x, y)`
+    // returns the larger value.
+    let returns_right = if yes_text == right_text && no_text == left_text {
+        true
+    } else if yes_text == left_text && no_text == right_text {
+        false
+    } else {
+        return Ok(None);
+    };
+    let replacement_fn = if is_greater == returns_right {
+        "pmin"
+    } else {
+        "pmax"
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "ifelse_censor".to_string(),
+            format!(
+                "`{}(x {} y, {}, {})` can be simplified.",
+                fn_name,
+                operator.text_trimmed(),
+                if returns_right { "y" } else { "x" },
+                if returns_right { "x" } else { "y" },
+            ),
+            Some(format!("Use `{replacement_fn}(x, y)` instead.")),
+        ),
+        range,
+        Fix {
+            content: format!("{replacement_fn}({left_text}, {right_text})"),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
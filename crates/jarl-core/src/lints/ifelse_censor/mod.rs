@@ -0,0 +1,105 @@
+pub(crate) mod ifelse_censor;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_ifelse_censor() {
+        expect_no_lint("ifelse(x > y, a, b)", "ifelse_censor", None);
+        expect_no_lint("ifelse(x > y, x, z)", "ifelse_censor", None);
+        expect_no_lint("ifelse(x > y, y, y)", "ifelse_censor", None);
+        expect_no_lint("ifelse(x == y, y, x)", "ifelse_censor", None);
+        expect_no_lint("ifelse(x != y, y, x)", "ifelse_censor", None);
+        expect_no_lint("ifelse(x > y + 1, y, x)", "ifelse_censor", None);
+        expect_no_lint("ifelse(x > y, y, x, 1)", "ifelse_censor", None);
+        expect_no_lint(
+            "dplyr::if_else(x > y, y, x, missing = 0)",
+            "ifelse_censor",
+            None,
+        );
+        expect_no_lint("foo(x > y, y, x)", "ifelse_censor", None);
+        expect_no_lint("pmin(x, y)", "ifelse_censor", None);
+
+        // Wrong syntax but no panic
+        expect_no_lint("ifelse(x > y, y)", "ifelse_censor", None);
+        expect_no_lint("ifelse(x >, y, x)", "ifelse_censor", None);
+    }
+
+    #[test]
+    fn test_lint_ifelse_censor() {
+        use insta::assert_snapshot;
+
+        let expected_message = "can be simplified";
+        expect_lint(
+            "ifelse(x > y, y, x)",
+            expected_message,
+            "ifelse_censor",
+            None,
+        );
+        expect_lint(
+            "ifelse(x < y, y, x)",
+            expected_message,
+            "ifelse_censor",
+            None,
+        );
+        expect_lint(
+            "ifelse(x >= 0, x, 0)",
+            expected_message,
+            "ifelse_censor",
+            None,
+        );
+        expect_lint(
+            "dplyr::if_else(x <= 1, x, 1)",
+            expected_message,
+            "ifelse_censor",
+            None,
+        );
+        expect_lint(
+            "data.table::fifelse(x > y, y, x)",
+            expected_message,
+            "ifelse_censor",
+            None,
+        );
+        expect_lint(
+            "ifelse(no = x, test = x > y, yes = y)",
+            expected_message,
+            "ifelse_censor",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "ifelse(x > y, y, x)",
+                    "ifelse(x > y, x, y)",
+                    "ifelse(x < y, y, x)",
+                    "ifelse(x < y, x, y)",
+                    "ifelse(x >= 0, x, 0)",
+                    "ifelse(x <= 1, x, 1)",
+                    "dplyr::if_else(foo(x) > 10, 10, foo(x))",
+                    "ifelse(no = x, test = x > y, yes = y)",
+                ],
+                "ifelse_censor",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_ifelse_censor_with_comments_no_fix() {
+        use insta::assert_snapshot;
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_fixed_text(
+                vec![
+                    "ifelse(x > y, # comment\n y, x)",
+                    "ifelse(x > y, y, x) # trailing comment",
+                ],
+                "ifelse_censor",
+                None
+            )
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::diagnostic::*;
-use crate::utils::get_function_name;
+use crate::utils::{get_arg_by_name_then_position, get_function_name};
 use crate::utils_ast::AstNodeExt;
 use air_r_syntax::*;
 use biome_rowan::AstNode;
@@ -13,6 +13,10 @@ use biome_rowan::AstNode;
 /// Assigning inside function calls or other situations such as in `if()` makes
 /// the code difficult to read, and should be avoided.
 ///
+/// `switch()` is handled like `if()`: assigning in its first argument is
+/// reported, but assigning in one of its branches is not, e.g.
+/// `switch(type, a = x <- 1)`.
+///
 /// ## Example
 ///
 /// ```r
@@ -76,6 +80,18 @@ pub fn implicit_assignment(ast: &RBinaryExpression) -> anyhow::Result<Option<Dia
         result
     };
 
+    let in_switch_selector = match parent_switch_argument(ast) {
+        Some((argument, call)) => {
+            let args = call.arguments()?.items();
+            let selector = get_arg_by_name_then_position(&args, "EXPR", 1);
+            if selector.is_none_or(|selector| selector.syntax() != argument.syntax()) {
+                return Ok(None);
+            }
+            true
+        }
+        None => false,
+    };
+
     // Do not report cases like `expect_message(x <- foo)` because this code
     // could test that `<-.foo` returns a message, which is a valid usage.
     if ancestor_is_arg {
@@ -178,7 +194,9 @@ pub fn implicit_assignment(ast: &RBinaryExpression) -> anyhow::Result<Option<Dia
         return Ok(None);
     }
 
-    let msg = if ancestor_is_if {
+    let msg = if in_switch_selector {
+        "Avoid implicit assignments in `switch()` statements."
+    } else if ancestor_is_if {
         "Avoid implicit assignments in `if()` statements."
     } else if ancestor_is_while {
         "Avoid implicit assignments in `while()` statements."
@@ -199,3 +217,12 @@ pub fn implicit_assignment(ast: &RBinaryExpression) -> anyhow::Result<Option<Dia
 
     Ok(Some(diagnostic))
 }
+
+/// If `ast` is directly an argument of `switch()`, returns this argument and
+/// the call to `switch()`.
+fn parent_switch_argument(ast: &RBinaryExpression) -> Option<(RArgument, RCall)> {
+    let argument = ast.parent::<RArgument>()?;
+    let call = argument.syntax().ancestors().find_map(RCall::cast)?;
+    let function_name = get_function_name(call.function().ok()?);
+    (function_name == "switch").then_some((argument, call))
+}
@@ -66,6 +66,30 @@ mod tests {
             "implicit_assignment",
             None,
         );
+        expect_lint(
+            "switch(x <- f(), a = 1)",
+            "in `switch()` statements",
+            "implicit_assignment",
+            None,
+        );
+        expect_lint(
+            "base::switch(EXPR = x <- f(), a = 1)",
+            "in `switch()` statements",
+            "implicit_assignment",
+            None,
+        );
+        expect_lint(
+            "switch(a = 1, EXPR = f() -> x)",
+            "in `switch()` statements",
+            "implicit_assignment",
+            None,
+        );
+        expect_lint(
+            "switch(foo(x <- f()), a = 1)",
+            "in function calls",
+            "implicit_assignment",
+            None,
+        );
     }
 
     #[test]
@@ -104,5 +128,14 @@ mod tests {
         expect_no_lint("suppressMessages(x <- 1)", "implicit_assignment", None);
         expect_no_lint("suppressWarnings(x <- 1)", "implicit_assignment", None);
         expect_no_lint("suppressWarnings({x <- 1})", "implicit_assignment", None);
+
+        // Branches of `switch()` are like the body of `if()`
+        expect_no_lint("switch(x, a = 1)", "implicit_assignment", None);
+        expect_no_lint(
+            "switch(x, a = y <- 1, b = y <- 2)",
+            "implicit_assignment",
+            None,
+        );
+        expect_no_lint("switch(x, a = { y <- 1 })", "implicit_assignment", None);
     }
 }
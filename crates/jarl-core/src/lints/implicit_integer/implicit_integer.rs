@@ -0,0 +1,140 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+pub struct ImplicitInteger;
+
+/// ## What it does
+///
+/// Checks for whole numbers without the `L` suffix passed to arguments that
+/// expect a count or a size, such as `seq_len(10)` or `rep(x, times = 3)`.
+///
+/// This rule is disabled by default because it can be noisy: R converts
+/// those numbers to integers anyway.
+///
+/// This rule has a safe automatic fix. Numbers larger than the largest integer
+/// (`2147483647`) are not reported.
+///
+/// ## Why is this bad?
+///
+/// In R, `10` is a double while `10L` is an integer. Writing `10L` makes it
+/// clear that an integer is expected and avoids a conversion.
+///
+/// The arguments checked by this rule are:
+///
+/// - `length.out` in `seq_len()` and `rep_len()`;
+/// - `times`, `each`, and `length.out` in `rep()` and `rep.int()`;
+/// - `length` in `vector()`, `numeric()`, `integer()`, `character()`, and
+///   `logical()`;
+/// - `length.out` in `complex()`;
+/// - `nrow` and `ncol` in `matrix()`;
+/// - `n` in `head()` and `tail()`;
+/// - `size` in `sample()`.
+///
+/// ## Example
+///
+/// ```r
+/// x <- seq_len(10)
+/// y <- rep(x, times = 3)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- seq_len(10L)
+/// y <- rep(x, times = 3L)
+/// ```
+impl Violation for ImplicitInteger {
+    fn name(&self) -> String {
+        "implicit_integer".to_string()
+    }
+    fn body(&self) -> String {
+        "This number is used as an integer but is a double.".to_string()
+    }
+    fn suggestion(&self) -> Option<String> {
+        Some("Add the `L` suffix to make it an integer.".to_string())
+    }
+}
+
+/// Functions and their arguments that expect a count or a size, with the
+/// position of those arguments.
+const SIZE_ARGUMENTS: &[(&str, &[(&str, usize)])] = &[
+    ("seq_len", &[("length.out", 1)]),
+    ("rep_len", &[("length.out", 2)]),
+    ("rep", &[("times", 2), ("length.out", 3), ("each", 4)]),
+    ("rep.int", &[("times", 2)]),
+    ("vector", &[("length", 2)]),
+    ("numeric", &[("length", 1)]),
+    ("integer", &[("length", 1)]),
+    ("character", &[("length", 1)]),
+    ("logical", &[("length", 1)]),
+    ("complex", &[("length.out", 1)]),
+    ("matrix", &[("nrow", 2), ("ncol", 3)]),
+    ("head", &[("n", 2)]),
+    ("tail", &[("n", 2)]),
+    ("sample", &[("size", 2)]),
+];
+
+pub fn implicit_integer(ast: &AnyRValue) -> anyhow::Result<Option<Diagnostic>> {
+    let double = unwrap_or_return_none!(ast.as_r_double_value());
+    let value = double.value_token()?;
+    let value = value.text_trimmed();
+
+    // Only whole numbers written without decimals or exponent, e.g. `10` but
+    // not `10.0`, `1e3`, or `0x10`.
+    if !value.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(None);
+    }
+    // Numbers that don't fit in an integer stay doubles even with `L`, e.g.
+    // `2147483648L`.
+    if value.parse::<i32>().is_err() {
+        return Ok(None);
+    }
+
+    if !is_size_argument(ast.syntax()) {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ImplicitInteger,
+        range,
+        Fix {
+            content: format!("{value}L"),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: false,
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `node` is the value of one of the [SIZE_ARGUMENTS].
+fn is_size_argument(node: &RSyntaxNode) -> bool {
+    let Some(argument) = node.parent().and_then(RArgument::cast) else {
+        return false;
+    };
+    let Some(call) = argument.syntax().ancestors().skip(1).find_map(RCall::cast) else {
+        return false;
+    };
+    let Ok(function) = call.function() else {
+        return false;
+    };
+    let function_name = get_function_name(function);
+    let Some((_, size_arguments)) = SIZE_ARGUMENTS
+        .iter()
+        .find(|(name, _)| *name == function_name)
+    else {
+        return false;
+    };
+    let Ok(arguments) = call.arguments() else {
+        return false;
+    };
+    let arguments = arguments.items();
+
+    size_arguments.iter().any(|(name, position)| {
+        get_arg_by_name_then_position(&arguments, name, *position)
+            .is_some_and(|arg| arg.syntax() == argument.syntax())
+    })
+}
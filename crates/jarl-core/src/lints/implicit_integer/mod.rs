@@ -0,0 +1,85 @@
+pub(crate) mod implicit_integer;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_implicit_integer() {
+        expect_no_lint("seq_len(10L)", "implicit_integer", None);
+        expect_no_lint("seq_len(10.5)", "implicit_integer", None);
+        expect_no_lint("seq_len(1e3)", "implicit_integer", None);
+        expect_no_lint("seq_len(0x10)", "implicit_integer", None);
+        expect_no_lint("seq_len(2147483648)", "implicit_integer", None);
+        expect_no_lint("seq_len(n)", "implicit_integer", None);
+        expect_no_lint("x[1]", "implicit_integer", None);
+        expect_no_lint("x <- 1", "implicit_integer", None);
+        expect_no_lint("rep(1, x)", "implicit_integer", None);
+        expect_no_lint("head(x, -1)", "implicit_integer", None);
+        expect_no_lint("matrix(0)", "implicit_integer", None);
+        expect_no_lint("foo(10)", "implicit_integer", None);
+        expect_no_lint("seq_len(n + 1)", "implicit_integer", None);
+    }
+
+    #[test]
+    fn test_lint_implicit_integer() {
+        use insta::assert_snapshot;
+
+        let expected_message = "This number is used as an integer but is a double";
+        expect_lint("seq_len(10)", expected_message, "implicit_integer", None);
+        expect_lint(
+            "base::seq_len(10)",
+            expected_message,
+            "implicit_integer",
+            None,
+        );
+        expect_lint("rep(x, 3)", expected_message, "implicit_integer", None);
+        expect_lint(
+            "seq_len(2147483647)",
+            expected_message,
+            "implicit_integer",
+            None,
+        );
+        expect_lint(
+            "rep(x, each = 3)",
+            expected_message,
+            "implicit_integer",
+            None,
+        );
+        expect_lint("rep_len(x, 3)", expected_message, "implicit_integer", None);
+        expect_lint("numeric(3)", expected_message, "implicit_integer", None);
+        expect_lint(
+            "vector('list', 3)",
+            expected_message,
+            "implicit_integer",
+            None,
+        );
+        expect_lint(
+            "matrix(0, ncol = 3)",
+            expected_message,
+            "implicit_integer",
+            None,
+        );
+        expect_lint("head(x, 3)", expected_message, "implicit_integer", None);
+        expect_lint(
+            "sample(x, size = 3)",
+            expected_message,
+            "implicit_integer",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "seq_len(10)",
+                    "rep(x, times = 3, each = 2)",
+                    "matrix(0, 2, 3)",
+                    "tail(x, n = 5)",
+                ],
+                "implicit_integer",
+                None
+            )
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use crate::diagnostic::*;
+use crate::utils::node_contains_comments;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// ## What it does
+///
+/// Checks for `return(x)` used as the last statement of a function.
+///
+/// ## Why is this bad?
+///
+/// R functions return the value of their last expression, so wrapping it in
+/// `return()` is redundant. The tidyverse style guide recommends using
+/// `return()` only for early returns.
+///
+/// This rule is disabled by default. It only reports `return()` when it is
+/// the very last statement of the function body and when its argument is a
+/// simple expression (e.g. not an assignment or a control flow statement).
+///
+/// This rule has an unsafe fix because removing `return()` can change the
+/// behavior of code that relies on `on.exit()` or on the visibility of the
+/// returned value.
+///
+/// ## Example
+///
+/// ```r
+/// foo <- function(x) {
+///   y <- x + 1
+///   return(y)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// foo <- function(x) {
+///   y <- x + 1
+///   y
+/// }
+/// ```
+///
+/// ## References
+///
+/// See:
+///
+/// - [https://style.tidyverse.org/functions.html#return](https://style.tidyverse.org/functions.html#return)
+pub fn implicit_return(ast: &RFunctionDefinition) -> anyhow::Result<Option<Diagnostic>> {
+    let last_statement = match ast.body()? {
+        AnyRExpression::RBracedExpressions(braced) => {
+            unwrap_or_return_none!(braced.expressions().into_iter().last())
+        }
+        body => body,
+    };
+    let call = unwrap_or_return_none!(last_statement.as_r_call());
+
+    let function = call.function()?;
+    let function = unwrap_or_return_none!(function.as_r_identifier());
+    if function.syntax().text_trimmed() != "return" {
+        return Ok(None);
+    }
+
+    let args: Vec<_> = call.arguments()?.items().into_iter().collect();
+    let [arg] = args.as_slice() else {
+        return Ok(None);
+    };
+    let arg = arg.clone()?;
+    if arg.name_clause().is_some() {
+        return Ok(None);
+    }
+    let value = unwrap_or_return_none!(arg.value());
+    if !is_simple_expression(&value) {
+        return Ok(None);
+    }
+
+    let value = value.syntax().text_trimmed().to_string();
+    let range = call.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "implicit_return".to_string(),
+            "`return()` is not needed for the last statement of a function.".to_string(),
+            Some(format!("Use `{value}` instead.")),
+        ),
+        range,
+        Fix {
+            content: value,
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(call.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether the value can be used as last statement of the function as-is.
+/// Assignments, function definitions, and control flow statements are not
+/// considered simple.
+fn is_simple_expression(value: &AnyRExpression) -> bool {
+    match value {
+        AnyRExpression::RBinaryExpression(binary) => binary.operator().is_ok_and(|operator| {
+            !matches!(
+                operator.kind(),
+                RSyntaxKind::ASSIGN
+                    | RSyntaxKind::ASSIGN_RIGHT
+                    | RSyntaxKind::SUPER_ASSIGN
+                    | RSyntaxKind::SUPER_ASSIGN_RIGHT
+                    | RSyntaxKind::EQUAL
+            )
+        }),
+        AnyRExpression::RBracedExpressions(_)
+        | AnyRExpression::RForStatement(_)
+        | AnyRExpression::RFunctionDefinition(_)
+        | AnyRExpression::RIfStatement(_)
+        | AnyRExpression::RRepeatStatement(_)
+        | AnyRExpression::RWhileStatement(_) => false,
+        _ => true,
+    }
+}
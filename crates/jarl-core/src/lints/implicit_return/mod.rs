@@ -0,0 +1,108 @@
+pub(crate) mod implicit_return;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_implicit_return() {
+        expect_no_lint("function(x) x", "implicit_return", None);
+        expect_no_lint("function(x) { x }", "implicit_return", None);
+        expect_no_lint("function(x) {}", "implicit_return", None);
+        expect_no_lint("function(x) return()", "implicit_return", None);
+        expect_no_lint("function(x) { return(); x }", "implicit_return", None);
+        expect_no_lint(
+            "function(x) {\n  if (x) return(1)\n  2\n}",
+            "implicit_return",
+            None,
+        );
+        expect_no_lint(
+            "function(x) {\n  if (x) return(1) else return(2)\n}",
+            "implicit_return",
+            None,
+        );
+        expect_no_lint("function(x) return(x <- 1)", "implicit_return", None);
+        expect_no_lint("function(x) return(x <<- 1)", "implicit_return", None);
+        expect_no_lint("function(x) return(function(y) y)", "implicit_return", None);
+        expect_no_lint("function(x) return({ x })", "implicit_return", None);
+        expect_no_lint(
+            "function(x) return(if (x) 1 else 2)",
+            "implicit_return",
+            None,
+        );
+        expect_no_lint("function(x) return(value = x)", "implicit_return", None);
+        expect_no_lint("function(x) x$return(x)", "implicit_return", None);
+        expect_no_lint("return(x)", "implicit_return", None);
+    }
+
+    #[test]
+    fn test_lint_implicit_return() {
+        use insta::assert_snapshot;
+
+        let expected_message = "`return()` is not needed for the last statement of a function.";
+        expect_lint(
+            "function(x) return(x)",
+            expected_message,
+            "implicit_return",
+            None,
+        );
+        expect_lint(
+            "\\(x) return(x + 1)",
+            expected_message,
+            "implicit_return",
+            None,
+        );
+        expect_lint(
+            "function(x) {\n  y <- x + 1\n  return(y)\n}",
+            expected_message,
+            "implicit_return",
+            None,
+        );
+        expect_lint(
+            "function(x) {\n  if (x) return(1)\n  return(2)\n}",
+            expected_message,
+            "implicit_return",
+            None,
+        );
+        expect_lint(
+            "function(x) return(invisible(x))",
+            expected_message,
+            "implicit_return",
+            None,
+        );
+
+        // Unsafe fix, only applied with `--unsafe-fixes`.
+        assert_snapshot!(
+            "no_safe_fix",
+            get_fixed_text(vec!["function(x) return(x)"], "implicit_return", None)
+        );
+        assert_snapshot!(
+            "fix_output",
+            get_unsafe_fixed_text(
+                vec![
+                    "function(x) return(x)",
+                    "\\(x) return(x + 1)",
+                    "function(x) {\n  y <- x + 1\n  return(y)\n}",
+                    "function(x) {\n  if (x) return(1)\n  return(2)\n}",
+                    "function(x) return(x[1])",
+                ],
+                "implicit_return",
+            )
+        );
+    }
+
+    #[test]
+    fn test_implicit_return_with_comments_no_fix() {
+        use insta::assert_snapshot;
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_unsafe_fixed_text(
+                vec![
+                    "function(x) {\n  return(\n    # a comment\n    x\n  )\n}",
+                    "function(x) {\n  return(x) # trailing comment\n}",
+                ],
+                "implicit_return",
+            )
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use crate::diagnostic::*;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// ## What it does
+///
+/// Checks for binary operators that are not surrounded by spaces, such as
+/// `x<-1` or `a==b`.
+///
+/// ## Why is this bad?
+///
+/// Spaces around operators make the code easier to read, and it is the style
+/// recommended by the tidyverse style guide.
+///
+/// The following operators are not checked since they are conventionally
+/// written without spaces:
+///
+/// - `:`, e.g. `1:10`;
+/// - `?`, e.g. `?mean`;
+/// - `^` and `**`, e.g. `x^2`. These can be checked too by setting the option
+///   `exponent` of `[lint.infix_spaces]` to `true` in `jarl.toml`:
+///
+///   ```toml
+///   [lint.infix_spaces]
+///   exponent = true
+///   ```
+///
+/// Unary operators, such as `-` in `-1`, are never reported. The `=` of named
+/// arguments in function calls (`f(x=1)`) and of default values of parameters
+/// in function definitions (`function(x=1)`) is not an operator and is not
+/// reported either.
+///
+/// This rule is disabled by default. Its fix adds a space on each side of the
+/// operator where one is missing.
+///
+/// ## Example
+///
+/// ```r
+/// x<-1
+/// y <- x+1
+/// if (a==b) z <- 3
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- 1
+/// y <- x + 1
+/// if (a == b) z <- 3
+/// ```
+pub fn infix_spaces(
+    ast: &RBinaryExpression,
+    check_exponent: bool,
+) -> anyhow::Result<Option<Diagnostic>> {
+    let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
+
+    let operator = operator?;
+    let left = left?;
+    let right = right?;
+
+    let operator_text = operator.text_trimmed();
+    match operator_text {
+        ":" | "?" => return Ok(None),
+        "^" | "**" if !check_exponent => return Ok(None),
+        _ => {}
+    }
+
+    let operator_range = operator.text_trimmed_range();
+    // Comments and line breaks count as spaces.
+    let missing_before = left.syntax().text_trimmed_range().end() == operator_range.start();
+    let missing_after = operator_range.end() == right.syntax().text_trimmed_range().start();
+    if !missing_before && !missing_after {
+        return Ok(None);
+    }
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "infix_spaces".to_string(),
+            format!("`{operator_text}` should be surrounded by spaces."),
+            Some("Add spaces around it.".to_string()),
+        ),
+        operator_range,
+        Fix {
+            content: format!(
+                "{}{operator_text}{}",
+                if missing_before { " " } else { "" },
+                if missing_after { " " } else { "" }
+            ),
+            start: operator_range.start().into(),
+            end: operator_range.end().into(),
+            to_skip: false,
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
@@ -0,0 +1,65 @@
+pub(crate) mod infix_spaces;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_infix_spaces() {
+        expect_no_lint("x <- 1", "infix_spaces", None);
+        expect_no_lint("x = 1", "infix_spaces", None);
+        expect_no_lint("a == b && c != d", "infix_spaces", None);
+        expect_no_lint("x %in% y", "infix_spaces", None);
+        expect_no_lint("x |> f()", "infix_spaces", None);
+        expect_no_lint("x <- -1", "infix_spaces", None);
+        expect_no_lint("-x", "infix_spaces", None);
+        expect_no_lint("!x", "infix_spaces", None);
+        expect_no_lint("~x", "infix_spaces", None);
+        expect_no_lint("1:10", "infix_spaces", None);
+        expect_no_lint("x^2", "infix_spaces", None);
+        expect_no_lint("x**2", "infix_spaces", None);
+        expect_no_lint("?mean", "infix_spaces", None);
+        expect_no_lint("f(x=1)", "infix_spaces", None);
+        expect_no_lint("function(x=1) x", "infix_spaces", None);
+        expect_no_lint("x$y", "infix_spaces", None);
+        expect_no_lint("base::mean", "infix_spaces", None);
+        expect_no_lint("x <-\n  1", "infix_spaces", None);
+        expect_no_lint("x +# comment\n  1", "infix_spaces", None);
+        expect_no_lint("x<-1 # nolint: infix_spaces", "infix_spaces", None);
+    }
+
+    #[test]
+    fn test_lint_infix_spaces() {
+        use insta::assert_snapshot;
+
+        let lint = |operator: &str| format!("`{operator}` should be surrounded by spaces");
+        expect_lint("x<-1", &lint("<-"), "infix_spaces", None);
+        expect_lint("x <-1", &lint("<-"), "infix_spaces", None);
+        expect_lint("x<<-1", &lint("<<-"), "infix_spaces", None);
+        expect_lint("1->x", &lint("->"), "infix_spaces", None);
+        expect_lint("x=1", &lint("="), "infix_spaces", None);
+        expect_lint("x+1", &lint("+"), "infix_spaces", None);
+        expect_lint("x -1", &lint("-"), "infix_spaces", None);
+        expect_lint("a==b", &lint("=="), "infix_spaces", None);
+        expect_lint("a&&b", &lint("&&"), "infix_spaces", None);
+        expect_lint("x%in%y", &lint("%in%"), "infix_spaces", None);
+        expect_lint("x|>f()", &lint("|>"), "infix_spaces", None);
+        expect_lint("y~x", &lint("~"), "infix_spaces", None);
+        expect_lint("f(x = a*b)", &lint("*"), "infix_spaces", None);
+        expect_lint("function(x = a/b) x", &lint("/"), "infix_spaces", None);
+
+        assert_eq!(check_code("x<-a+b*c", "infix_spaces", None).len(), 3);
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "x<-1", "x <-1", "x<- 1", "x<--1", "a==b", "x%in%y", "x<-a+b*c", "x+\n  1",
+                    "x<-y^2",
+                ],
+                "infix_spaces",
+                None
+            )
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::diagnostic::*;
+use crate::utils::{get_function_name, is_piped, node_contains_comments};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// Functions that are vectorized and work elementwise, so that calling them
+/// once on the combined vector gives the same result as combining the results
+/// of each call.
+const ELEMENTWISE_FUNCTIONS: [&str; 11] = [
+    "abs", "ceiling", "cos", "exp", "floor", "log", "log10", "log2", "sin", "sqrt", "tan",
+];
+
+/// ## What it does
+///
+/// Checks for calls to `c()` where every element is a call to the same
+/// vectorized function with a single argument, e.g. `c(log(x), log(y))`.
+///
+/// ## Why is this bad?
+///
+/// Calling the function once on the combined vector, e.g. `log(c(x, y))`, is
+/// more readable and more efficient than calling it on every element.
+///
+/// This rule only checks a small set of functions that are known to work
+/// elementwise: `abs()`, `ceiling()`, `cos()`, `exp()`, `floor()`, `log()`,
+/// `log10()`, `log2()`, `sin()`, `sqrt()`, and `tan()`.
+///
+/// This rule has a safe fix.
+///
+/// ## Example
+///
+/// ```r
+/// x <- c(1, 2)
+/// y <- c(3, 4)
+/// c(log(x), log(y))
+/// c(sqrt(x), sqrt(y), sqrt(2))
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- c(1, 2)
+/// y <- c(3, 4)
+/// log(c(x, y))
+/// sqrt(c(x, y, 2))
+/// ```
+pub fn inner_combine(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    let function = ast.function()?;
+    // In `x |> c(log(y), log(z))`, `x` is also combined.
+    if get_function_name(function) != "c" || is_piped(ast) {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let args: Vec<RArgument> = args.iter().filter_map(Result::ok).collect();
+
+    // `c(log(x))` doesn't combine anything.
+    if args.len() < 2 {
+        return Ok(None);
+    }
+
+    let mut inner_function: Option<String> = None;
+    let mut inner_values: Vec<String> = Vec::with_capacity(args.len());
+
+    for arg in args {
+        // `c(a = log(x), b = log(y))` keeps the names.
+        if arg.name_clause().is_some() {
+            return Ok(None);
+        }
+        let value = unwrap_or_return_none!(arg.value());
+        let inner_call = unwrap_or_return_none!(value.as_r_call());
+
+        let inner_fn = inner_call.function()?;
+        if !ELEMENTWISE_FUNCTIONS.contains(&get_function_name(inner_fn.clone()).as_str()) {
+            return Ok(None);
+        }
+
+        // All elements must call the same function, written the same way,
+        // e.g. `log()` and `base::log()` are not mixed.
+        let inner_fn = inner_fn.syntax().text_trimmed().to_string();
+        match &inner_function {
+            Some(previous) if *previous != inner_fn => return Ok(None),
+            Some(_) => {}
+            None => inner_function = Some(inner_fn),
+        }
+
+        let inner_args: Vec<RArgument> = inner_call
+            .arguments()?
+            .items()
+            .iter()
+            .filter_map(Result::ok)
+            .collect();
+        if inner_args.len() != 1 || inner_args[0].name_clause().is_some() {
+            return Ok(None);
+        }
+        let inner_value = unwrap_or_return_none!(inner_args[0].value());
+        inner_values.push(inner_value.syntax().text_trimmed().to_string());
+    }
+
+    let inner_function = unwrap_or_return_none!(inner_function);
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "inner_combine".to_string(),
+            format!("`{inner_function}()` is called on each element of `c()`."),
+            Some(format!(
+                "Call `{inner_function}()` once on the combined vector instead."
+            )),
+        ),
+        range,
+        Fix {
+            content: format!("{inner_function}(c({}))", inner_values.join(", ")),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
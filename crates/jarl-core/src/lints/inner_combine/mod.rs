@@ -0,0 +1,78 @@
+pub(crate) mod inner_combine;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_inner_combine() {
+        expect_no_lint("c(x, y)", "inner_combine", None);
+        expect_no_lint("c(log(x))", "inner_combine", None);
+        expect_no_lint("c(log(x), y)", "inner_combine", None);
+        expect_no_lint("c(log(x), sqrt(y))", "inner_combine", None);
+        expect_no_lint("c(log(x), base::log(y))", "inner_combine", None);
+        expect_no_lint(
+            "c(log(x, base = 2), log(y, base = 2))",
+            "inner_combine",
+            None,
+        );
+        expect_no_lint("c(log(x = x), log(x = y))", "inner_combine", None);
+        expect_no_lint("c(a = log(x), b = log(y))", "inner_combine", None);
+        expect_no_lint("c(log(), log())", "inner_combine", None);
+        expect_no_lint("x |> c(log(y), log(z))", "inner_combine", None);
+        expect_no_lint("list(log(x), log(y))", "inner_combine", None);
+
+        // Functions that don't work elementwise
+        expect_no_lint("c(mean(x), mean(y))", "inner_combine", None);
+        expect_no_lint("c(as.Date(x), as.Date(y))", "inner_combine", None);
+        expect_no_lint("c(foo(x), foo(y))", "inner_combine", None);
+    }
+
+    #[test]
+    fn test_lint_inner_combine() {
+        use insta::assert_snapshot;
+
+        let expected_message = "is called on each element of `c()`";
+        expect_lint("c(log(x), log(y))", expected_message, "inner_combine", None);
+        expect_lint(
+            "c(sqrt(x), sqrt(y), sqrt(2))",
+            expected_message,
+            "inner_combine",
+            None,
+        );
+        expect_lint(
+            "c(base::abs(x), base::abs(y))",
+            expected_message,
+            "inner_combine",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "c(log(x), log(y))",
+                    "c(sqrt(x), sqrt(y), sqrt(2))",
+                    "c(base::abs(x), base::abs(y))",
+                    "c(exp(x + 1), exp(foo(y)))",
+                    "c(\n  cos(x),\n  cos(y)\n)",
+                ],
+                "inner_combine",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_inner_combine_with_comments_no_fix() {
+        use insta::assert_snapshot;
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_fixed_text(
+                vec!["c(\n  # comment\n  log(x),\n  log(y)\n)"],
+                "inner_combine",
+                None
+            )
+        );
+    }
+}
@@ -1,7 +1,6 @@
 use crate::diagnostic::*;
 use crate::utils::{get_arg_by_name_then_position, get_function_name, node_contains_comments};
 use air_r_syntax::*;
-use anyhow::Context;
 use biome_rowan::AstNode;
 
 pub struct Lengths;
@@ -46,40 +45,43 @@ impl Violation for Lengths {
 }
 
 pub fn lengths(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
-    let RCallFields { function, arguments } = ast.as_fields();
-    let function = function?;
-    let fn_name = get_function_name(function);
-
-    let funs_to_watch = ["sapply", "vapply", "map_dbl", "map_int"];
-    if !funs_to_watch.contains(&fn_name.as_str()) {
+    if !applies_length(ast) {
         return Ok(None);
     }
 
-    let arguments = arguments?.items();
-    let arg_x = get_arg_by_name_then_position(&arguments, "x", 1);
-    let arg_fun = get_arg_by_name_then_position(&arguments, "FUN", 2);
+    let arguments = ast.arguments()?.items();
+    let arg_x = unwrap_or_return_none!(get_arg_by_name_then_position(&arguments, "x", 1));
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        Lengths,
+        range,
+        Fix {
+            content: format!("lengths({})", arg_x.into_syntax().text_trimmed()),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+    Ok(Some(diagnostic))
+}
 
-    if let Some(arg_fun) = arg_fun
-        && arg_fun
-            .value()
-            .context("Found named argument without any value")?
-            .into_syntax()
-            .text_trimmed()
-            == "length"
-    {
-        let range = ast.syntax().text_trimmed_range();
-        let diagnostic = Diagnostic::new(
-            Lengths,
-            range,
-            Fix {
-                content: format!("lengths({})", arg_x.unwrap().into_syntax().text_trimmed()),
-                start: range.start().into(),
-                end: range.end().into(),
-                to_skip: node_contains_comments(ast.syntax()),
-            },
-        );
-        return Ok(Some(diagnostic));
+/// Is `ast` a call applying `length()` to each element of a list, such as
+/// `sapply(x, length)`? Other rules use this to avoid reporting the same call
+/// as `lengths`.
+pub(crate) fn applies_length(ast: &RCall) -> bool {
+    let RCallFields { function, arguments } = ast.as_fields();
+    let (Ok(function), Ok(arguments)) = (function, arguments) else {
+        return false;
     };
 
-    Ok(None)
+    let funs_to_watch = ["sapply", "vapply", "map_dbl", "map_int"];
+    if !funs_to_watch.contains(&get_function_name(function).as_str()) {
+        return false;
+    }
+
+    let arguments = arguments.items();
+    get_arg_by_name_then_position(&arguments, "FUN", 2)
+        .and_then(|arg_fun| arg_fun.value())
+        .is_some_and(|value| value.syntax().text_trimmed() == "length")
 }
@@ -0,0 +1,91 @@
+use crate::diagnostic::*;
+use crate::utils::get_function_name;
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// ## What it does
+///
+/// Checks that calls to `library()` and `require()` are at the top of the
+/// file, i.e. before any other statement.
+///
+/// Calls inside functions, conditions, or loops are not reported since they
+/// are usually there on purpose.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// Loading packages in the middle of a script makes it hard to know which
+/// packages the script depends on. Functions from packages loaded later can
+/// also mask functions used before, so the same code can behave differently
+/// depending on where it is in the file.
+///
+/// ## Example
+///
+/// ```r
+/// library(dplyr)
+/// x <- filter(df, y > 1)
+/// library(ggplot2)
+/// ggplot(x)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// library(dplyr)
+/// library(ggplot2)
+/// x <- filter(df, y > 1)
+/// ggplot(x)
+/// ```
+pub fn library_call(root: &RSyntaxNode) -> anyhow::Result<Vec<(RSyntaxNode, Diagnostic)>> {
+    let Some(root) = RRoot::cast_ref(root) else {
+        return Ok(vec![]);
+    };
+
+    let mut diagnostics = vec![];
+    let mut after_other_statement = false;
+    for expression in root.expressions() {
+        let Some(call) = library_call_in(&expression) else {
+            after_other_statement = true;
+            continue;
+        };
+        if !after_other_statement {
+            continue;
+        }
+
+        let function_name = get_function_name(call.function()?);
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "library_call".to_string(),
+                format!("`{function_name}()` should be called at the top of the file."),
+                Some(
+                    "Group all calls to `library()` and `require()` before the rest of the code."
+                        .to_string(),
+                ),
+            ),
+            call.syntax().text_trimmed_range(),
+            Fix::empty(),
+        );
+        diagnostics.push((expression.syntax().clone(), diagnostic));
+    }
+
+    Ok(diagnostics)
+}
+
+/// The call to `library()` or `require()` in a top-level statement, which may
+/// be wrapped in `suppressPackageStartupMessages()` or `suppressMessages()`.
+fn library_call_in(expression: &AnyRExpression) -> Option<RCall> {
+    let call = expression.as_r_call()?;
+    let function_name = get_function_name(call.function().ok()?);
+    match function_name.as_str() {
+        "library" | "require" => Some(call.clone()),
+        "suppressPackageStartupMessages" | "suppressMessages" => {
+            let args = call.arguments().ok()?.items();
+            if args.len() != 1 {
+                return None;
+            }
+            let value = args.into_iter().next()?.ok()?.value()?;
+            library_call_in(&value)
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,84 @@
+pub(crate) mod library_call;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_library_call() {
+        expect_no_lint("library(dplyr)\nx <- 1", "library_call", None);
+        expect_no_lint(
+            "library(dplyr)\nrequire(tidyr)\nx <- 1",
+            "library_call",
+            None,
+        );
+        expect_no_lint(
+            "# Packages\nlibrary(dplyr)\n\n# Code\nx <- 1",
+            "library_call",
+            None,
+        );
+        expect_no_lint(
+            "suppressPackageStartupMessages(library(dplyr))\nlibrary(tidyr)\nx <- 1",
+            "library_call",
+            None,
+        );
+        expect_no_lint(
+            "x <- 1\nf <- function() {\n  library(dplyr)\n}",
+            "library_call",
+            None,
+        );
+        expect_no_lint(
+            "x <- 1\nif (!require(dplyr)) install.packages('dplyr')",
+            "library_call",
+            None,
+        );
+        expect_no_lint("x <- 1\n{\n  library(dplyr)\n}", "library_call", None);
+        expect_no_lint(
+            "x <- 1\n# nolint: library_call\nlibrary(dplyr)",
+            "library_call",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_library_call() {
+        expect_lint(
+            "x <- 1\nlibrary(dplyr)",
+            "`library()` should be called at the top of the file",
+            "library_call",
+            None,
+        );
+        expect_lint(
+            "library(dplyr)\nx <- 1\nrequire(tidyr)",
+            "`require()` should be called at the top of the file",
+            "library_call",
+            None,
+        );
+        expect_lint(
+            "x <- 1\nbase::library(dplyr)",
+            "`library()` should be called at the top of the file",
+            "library_call",
+            None,
+        );
+        expect_lint(
+            "x <- 1\nsuppressPackageStartupMessages(library(dplyr))",
+            "`library()` should be called at the top of the file",
+            "library_call",
+            None,
+        );
+        assert_eq!(
+            check_code(
+                "library(dplyr)\nx <- 1\nlibrary(tidyr)\ny <- 2\nlibrary(ggplot2)",
+                "library_call",
+                None
+            )
+            .len(),
+            2
+        );
+        expect_diagnostic_highlight(
+            "x <- 1\nsuppressMessages(library(dplyr))",
+            "library_call",
+            "library(dplyr)",
+        );
+    }
+}
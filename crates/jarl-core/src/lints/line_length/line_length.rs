@@ -0,0 +1,102 @@
+use crate::diagnostic::*;
+use crate::utils_ast::statement_at_offset;
+use air_r_syntax::*;
+use biome_rowan::{TextRange, TextSize};
+
+/// ## What it does
+///
+/// Checks for lines longer than a maximum number of characters, 80 by
+/// default. This can be changed with the option `max` of `[lint.line_length]`
+/// in `jarl.toml`:
+///
+/// ```toml
+/// [lint.line_length]
+/// max = 120
+/// ```
+///
+/// ## Why is this bad?
+///
+/// Long lines are hard to read, especially when the code is displayed side by
+/// side with other files or in a narrow window.
+///
+/// The length of a line is its number of characters, so a tab or a character
+/// such as `é` counts as one. Comments that only contain a URL, possibly after
+/// a roxygen2 tag such as `@source`, are not reported since URLs can't be
+/// split.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// result <- my_function(first_argument = "value", second_argument = "another value")
+/// ```
+///
+/// Use instead:
+/// ```r
+/// result <- my_function(
+///   first_argument = "value",
+///   second_argument = "another value"
+/// )
+/// ```
+pub fn line_length(
+    root: &RSyntaxNode,
+    max_length: usize,
+) -> anyhow::Result<Vec<(RSyntaxNode, Diagnostic)>> {
+    let source = root.text().to_string();
+
+    let mut diagnostics = vec![];
+    let mut line_start = 0;
+    for line in source.split_inclusive('\n') {
+        let start = line_start;
+        line_start += line.len();
+
+        let line = line.trim_end_matches(['\n', '\r']);
+        // The byte offset of the first character over the limit, if any.
+        let Some((overflow, _)) = line.char_indices().nth(max_length) else {
+            continue;
+        };
+        if is_url_comment(line) {
+            continue;
+        }
+
+        let length = line.chars().count();
+        let range = TextRange::new(
+            TextSize::from((start + overflow) as u32),
+            TextSize::from((start + line.len()) as u32),
+        );
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "line_length".to_string(),
+                format!(
+                    "Lines should not be longer than {max_length} characters, this one has {length}."
+                ),
+                Some("Split it into several lines.".to_string()),
+            ),
+            range,
+            Fix::empty(),
+        );
+        diagnostics.push((statement_at_offset(root, range.start()), diagnostic));
+    }
+
+    Ok(diagnostics)
+}
+
+/// Whether `line` is a comment that only contains a URL, e.g.
+/// `# https://example.com` or `#' @source <https://example.com>`.
+fn is_url_comment(line: &str) -> bool {
+    let Some(content) = line.trim().strip_prefix('#') else {
+        return false;
+    };
+    let content = content.trim_start_matches(['#', '\'']).trim();
+
+    // Skip the roxygen2 tag, if any.
+    let content = match content.strip_prefix('@') {
+        Some(tag) => tag
+            .split_once(char::is_whitespace)
+            .map_or("", |(_, rest)| rest.trim()),
+        None => content,
+    };
+
+    !content.contains(char::is_whitespace) && content.contains("://")
+}
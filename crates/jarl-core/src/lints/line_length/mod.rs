@@ -0,0 +1,86 @@
+pub(crate) mod line_length;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_line_length() {
+        expect_no_lint("x <- 1", "line_length", None);
+        expect_no_lint(&format!("x <- \"{}\"", "a".repeat(72)), "line_length", None);
+        // Characters are counted, not bytes.
+        expect_no_lint(&format!("x <- \"{}\"", "é".repeat(72)), "line_length", None);
+        expect_no_lint(
+            &format!("x <- 1\r\n# {}\r\n", "a".repeat(78)),
+            "line_length",
+            None,
+        );
+        expect_no_lint(
+            &format!("# https://example.com/{}", "a".repeat(100)),
+            "line_length",
+            None,
+        );
+        expect_no_lint(
+            &format!("#' @source <https://example.com/{}>", "a".repeat(100)),
+            "line_length",
+            None,
+        );
+        expect_no_lint(
+            &format!("# nolint: line_length\nx <- \"{}\"", "a".repeat(80)),
+            "line_length",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_line_length() {
+        let expected_message = "Lines should not be longer than 80 characters, this one has 81";
+        expect_lint(
+            &format!("x <- \"{}\"", "a".repeat(74)),
+            expected_message,
+            "line_length",
+            None,
+        );
+        expect_lint(
+            &format!("x <- \"{}\"", "é".repeat(74)),
+            expected_message,
+            "line_length",
+            None,
+        );
+        expect_lint(
+            &format!("# {}", "a".repeat(79)),
+            expected_message,
+            "line_length",
+            None,
+        );
+        expect_lint(
+            &format!("# See https://example.com/{}", "a".repeat(100)),
+            "Lines should not be longer than 80 characters",
+            "line_length",
+            None,
+        );
+        expect_lint(
+            &format!("f <- function() {{\n  x <- \"{}\"\n}}", "a".repeat(72)),
+            expected_message,
+            "line_length",
+            None,
+        );
+
+        let long_line = "a".repeat(90);
+        assert_eq!(
+            check_code(&format!("{long_line}\nb\n{long_line}"), "line_length", None).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_line_length_range() {
+        // The range starts at the first character over the limit.
+        let code = format!("x <- 1\ny <- \"{}\"", "é".repeat(80));
+        let diagnostics = check_code(&code, "line_length", None);
+        assert_eq!(diagnostics.len(), 1);
+        let start = code.char_indices().nth(7 + 80).unwrap().0;
+        assert_eq!(usize::from(diagnostics[0].range.start()), start);
+        assert_eq!(usize::from(diagnostics[0].range.end()), code.len());
+    }
+}
@@ -0,0 +1,229 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name, node_contains_comments};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// ## What it does
+///
+/// Checks for calls to `as.numeric()`, `as.double()`, `as.integer()`,
+/// `as.logical()`, and `as.character()` on literal values, e.g.
+/// `as.numeric("1")`.
+///
+/// ## Why is this bad?
+///
+/// Coercing a literal value is unnecessary because the coerced value can be
+/// written directly. For example, `as.integer("5")` is `5L` and
+/// `as.character(1)` is `"1"`. Writing the value directly is easier to read
+/// and avoids a function call.
+///
+/// This rule only reports cases where the result of the coercion is known
+/// without running the code. For example, `as.numeric("0x1A")` or
+/// `as.numeric(" 1")` are not reported, and neither is `as.numeric("-1")`
+/// because replacing it by `-1` could change the result of the surrounding
+/// expression, e.g. `as.numeric("-1")^2`.
+///
+/// This rule has a safe fix.
+///
+/// ## Example
+///
+/// ```r
+/// as.numeric("1")
+/// as.integer("5")
+/// as.logical("TRUE")
+/// as.character(1)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// 1
+/// 5L
+/// TRUE
+/// "1"
+/// ```
+///
+/// ## References
+///
+/// See `?as.numeric`, `?as.integer`, `?as.logical`, and `?as.character`
+pub fn literal_coercion(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    let RCallFields { function, arguments } = ast.as_fields();
+
+    let function = function?;
+    let fn_name = get_function_name(function);
+
+    if !matches!(
+        fn_name.as_str(),
+        "as.numeric" | "as.double" | "as.integer" | "as.logical" | "as.character"
+    ) {
+        return Ok(None);
+    }
+
+    let arguments = arguments?.items();
+
+    // Additional arguments are passed to methods, don't know what they do.
+    if arguments.iter().count() != 1 {
+        return Ok(None);
+    }
+
+    let x = unwrap_or_return_none!(get_arg_by_name_then_position(&arguments, "x", 1));
+    let x_value = unwrap_or_return_none!(x.value());
+    let literal = unwrap_or_return_none!(Literal::from_expression(&x_value));
+
+    let replacement = match fn_name.as_str() {
+        "as.numeric" | "as.double" => literal.to_numeric(),
+        "as.integer" => literal.to_integer(),
+        "as.logical" => literal.to_logical(),
+        "as.character" => literal.to_character(),
+        _ => unreachable!(),
+    };
+    let replacement = unwrap_or_return_none!(replacement);
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "literal_coercion".to_string(),
+            format!("Coercing a literal with `{fn_name}()` is unnecessary."),
+            Some(format!("Use `{replacement}` instead.")),
+        ),
+        range,
+        Fix {
+            content: replacement,
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// A literal value, as written in the code.
+enum Literal {
+    /// The content of the string (without quotes) and the string as written.
+    String {
+        content: String,
+        text: String,
+    },
+    Double(String),
+    /// The value without the `L` suffix.
+    Integer(String),
+    Logical(bool),
+}
+
+impl Literal {
+    fn from_expression(expr: &AnyRExpression) -> Option<Self> {
+        if expr.as_r_true_expression().is_some() {
+            return Some(Literal::Logical(true));
+        }
+        if expr.as_r_false_expression().is_some() {
+            return Some(Literal::Logical(false));
+        }
+
+        let value = expr.as_any_r_value()?;
+
+        if let Some(string) = value.as_r_string_value() {
+            let text = string.to_trimmed_string();
+            // Don't handle raw strings and escaped characters.
+            if text.starts_with(['r', 'R']) || text.contains('\\') || text.len() < 2 {
+                return None;
+            }
+            let content = text[1..text.len() - 1].to_string();
+            return Some(Literal::String { content, text });
+        }
+        if let Some(double) = value.as_r_double_value() {
+            let text = double.value_token().ok()?.text_trimmed().to_string();
+            return Some(Literal::Double(text));
+        }
+        if let Some(integer) = value.as_r_integer_value() {
+            let text = integer.value_token().ok()?.text_trimmed().to_string();
+            return Some(Literal::Integer(text.strip_suffix('L')?.to_string()));
+        }
+
+        None
+    }
+
+    fn to_numeric(&self) -> Option<String> {
+        match self {
+            Literal::String { content, .. } if is_decimal_number(content) => Some(content.clone()),
+            Literal::Double(value) | Literal::Integer(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    fn to_integer(&self) -> Option<String> {
+        let value = match self {
+            Literal::String { content: value, .. }
+            | Literal::Double(value)
+            | Literal::Integer(value) => value,
+            Literal::Logical(_) => return None,
+        };
+        // Values that are too large are converted to `NA` with a warning.
+        if !is_digits(value) {
+            return None;
+        }
+        let value = value.parse::<i32>().ok()?;
+        Some(format!("{value}L"))
+    }
+
+    fn to_logical(&self) -> Option<String> {
+        match self {
+            Literal::String { content, .. } => match content.as_str() {
+                "TRUE" | "true" | "True" | "T" => Some("TRUE".to_string()),
+                "FALSE" | "false" | "False" | "F" => Some("FALSE".to_string()),
+                _ => None,
+            },
+            Literal::Logical(true) => Some("TRUE".to_string()),
+            Literal::Logical(false) => Some("FALSE".to_string()),
+            _ => None,
+        }
+    }
+
+    fn to_character(&self) -> Option<String> {
+        match self {
+            Literal::String { text, .. } => Some(text.clone()),
+            // R formats numbers when converting them to strings, e.g.
+            // `as.character(1.50)` is `"1.5"` and `as.character(100000)` is
+            // `"1e+05"`, so only handle small integers written without leading
+            // zeros.
+            Literal::Double(value) | Literal::Integer(value)
+                if is_digits(value)
+                    && (value == "0" || !value.starts_with('0'))
+                    && value.len() <= 5 =>
+            {
+                Some(format!("\"{value}\""))
+            }
+            Literal::Logical(true) => Some("\"TRUE\"".to_string()),
+            Literal::Logical(false) => Some("\"FALSE\"".to_string()),
+            _ => None,
+        }
+    }
+}
+
+fn is_digits(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Whether `text` is a positive decimal number such as `1`, `1.5`, `.5`, or
+/// `1e3`. Hexadecimal numbers, `Inf`, `NaN`, or numbers with surrounding
+/// whitespace are not considered.
+fn is_decimal_number(text: &str) -> bool {
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (text, None),
+    };
+
+    let mantissa_is_valid = match mantissa.split_once('.') {
+        Some((int, frac)) => {
+            (int.is_empty() || is_digits(int))
+                && (frac.is_empty() || is_digits(frac))
+                && !(int.is_empty() && frac.is_empty())
+        }
+        None => is_digits(mantissa),
+    };
+
+    let exponent_is_valid = match exponent {
+        Some(exponent) => is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent)),
+        None => true,
+    };
+
+    mantissa_is_valid && exponent_is_valid
+}
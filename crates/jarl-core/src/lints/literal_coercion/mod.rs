@@ -0,0 +1,154 @@
+pub(crate) mod literal_coercion;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_literal_coercion() {
+        expect_no_lint("as.numeric(x)", "literal_coercion", None);
+        expect_no_lint("as.numeric(foo())", "literal_coercion", None);
+        expect_no_lint("as.integer(1.5)", "literal_coercion", None);
+        expect_no_lint("as.integer('1.5')", "literal_coercion", None);
+        expect_no_lint("as.logical('yes')", "literal_coercion", None);
+        expect_no_lint("as.logical(1)", "literal_coercion", None);
+        expect_no_lint("as.character(1.50)", "literal_coercion", None);
+        expect_no_lint("as.character(100000)", "literal_coercion", None);
+        expect_no_lint("as.character(007)", "literal_coercion", None);
+
+        // Values that can't be statically coerced
+        expect_no_lint("as.numeric('0x1A')", "literal_coercion", None);
+        expect_no_lint("as.numeric('Inf')", "literal_coercion", None);
+        expect_no_lint("as.numeric(' 1')", "literal_coercion", None);
+        expect_no_lint("as.numeric('1,5')", "literal_coercion", None);
+        expect_no_lint("as.numeric('abc')", "literal_coercion", None);
+        expect_no_lint("as.numeric('1e')", "literal_coercion", None);
+        expect_no_lint("as.numeric('.')", "literal_coercion", None);
+        expect_no_lint("as.integer('99999999999')", "literal_coercion", None);
+        expect_no_lint("as.numeric(r'(1)')", "literal_coercion", None);
+        expect_no_lint("as.character('a\\nb')", "literal_coercion", None);
+
+        // Negative values could change the precedence of operations
+        expect_no_lint("as.numeric('-1')", "literal_coercion", None);
+
+        // Additional arguments
+        expect_no_lint("as.numeric('1', foo = 2)", "literal_coercion", None);
+        expect_no_lint("as.numeric()", "literal_coercion", None);
+
+        // Other functions
+        expect_no_lint("as.complex('1')", "literal_coercion", None);
+        expect_no_lint("foo::as.numeric2('1')", "literal_coercion", None);
+    }
+
+    #[test]
+    fn test_lint_literal_coercion() {
+        use insta::assert_snapshot;
+
+        let expected_message = "Coercing a literal";
+        expect_lint(
+            "as.numeric('1')",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+        expect_lint(
+            "as.numeric(\"1.5\")",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+        expect_lint(
+            "as.double('1e3')",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+        expect_lint("as.numeric(1L)", expected_message, "literal_coercion", None);
+        expect_lint(
+            "as.integer('5')",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+        expect_lint("as.integer(5)", expected_message, "literal_coercion", None);
+        expect_lint(
+            "as.logical('TRUE')",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+        expect_lint(
+            "as.logical('false')",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+        expect_lint(
+            "as.logical(TRUE)",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+        expect_lint(
+            "as.character(1)",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+        expect_lint(
+            "as.character(TRUE)",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+        expect_lint(
+            "as.character('a')",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+        expect_lint(
+            "as.numeric(x = '1')",
+            expected_message,
+            "literal_coercion",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "as.numeric('1')",
+                    "as.numeric(\"1.5\")",
+                    "as.double('1e3')",
+                    "as.numeric(1L)",
+                    "as.integer('5')",
+                    "as.integer(007)",
+                    "as.logical('T')",
+                    "as.logical(FALSE)",
+                    "as.character(1)",
+                    "as.character(2L)",
+                    "as.character(TRUE)",
+                    "as.character('a')",
+                    "base::as.numeric('1')",
+                ],
+                "literal_coercion",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_literal_coercion_with_comments_no_fix() {
+        use insta::assert_snapshot;
+        // Should detect lint but skip fix when comments are present to avoid destroying them
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_fixed_text(
+                vec!["as.numeric(\n  # a comment\n  '1'\n)\n"],
+                "literal_coercion",
+                None
+            )
+        );
+    }
+}
@@ -86,6 +86,9 @@ mod tests {
                     "apply(x, 2, sum, na.rm = TRUE)",
                     "apply(x, 2, sum, na.rm = FALSE)",
                     "apply(x, 2, sum, na.rm = foo)",
+                    "apply(x, 2, mean)",
+                    "apply(x, 1, mean, na.rm = TRUE)",
+                    "apply(x, 2, mean, na.rm = TRUE)",
                 ],
                 "matrix_apply",
                 None
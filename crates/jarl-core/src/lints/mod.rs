@@ -1,19 +1,26 @@
 use crate::rule_set::Rule;
 
+pub(crate) mod absolute_path;
 pub(crate) mod all_equal;
 pub(crate) mod any_duplicated;
 pub(crate) mod any_is_na;
 pub(crate) mod assignment;
+pub(crate) mod brace_style;
 pub(crate) mod browser;
 pub(crate) mod class_equals;
 pub(crate) mod coalesce;
+pub(crate) mod commented_code;
 pub(crate) mod comparison_negation;
+pub(crate) mod condition_message;
+pub(crate) mod consecutive_assertion;
 pub(crate) mod download_file;
+pub(crate) mod duplicate_block;
 pub(crate) mod duplicated_arguments;
 pub(crate) mod empty_assignment;
 pub(crate) mod equals_na;
 pub(crate) mod equals_nan;
 pub(crate) mod equals_null;
+pub(crate) mod expect_comparison;
 pub(crate) mod expect_length;
 pub(crate) mod expect_named;
 pub(crate) mod expect_not;
@@ -24,26 +31,53 @@ pub(crate) mod expect_type;
 pub(crate) mod fixed_regex;
 pub(crate) mod for_loop_index;
 pub(crate) mod grepv;
+pub(crate) mod ifelse_censor;
 pub(crate) mod implicit_assignment;
+pub(crate) mod implicit_integer;
+pub(crate) mod implicit_return;
+pub(crate) mod infix_spaces;
+pub(crate) mod inner_combine;
 pub(crate) mod is_numeric;
 pub(crate) mod length_levels;
 pub(crate) mod length_test;
 pub(crate) mod lengths;
+pub(crate) mod library_call;
+pub(crate) mod line_length;
 pub(crate) mod list2df;
+pub(crate) mod literal_coercion;
 pub(crate) mod matrix_apply;
+pub(crate) mod nested_ifelse;
+pub(crate) mod null_check_without_else;
 pub(crate) mod numeric_leading_zero;
+pub(crate) mod nzchar;
+pub(crate) mod object_name;
+pub(crate) mod order_mismatch;
 pub(crate) mod outer_negation;
+pub(crate) mod paste_sep;
+pub(crate) mod pipe_consistency;
+pub(crate) mod quotes;
 pub(crate) mod redundant_equals;
 pub(crate) mod redundant_ifelse;
 pub(crate) mod repeat;
+pub(crate) mod rev_sort;
 pub(crate) mod sample_int;
+pub(crate) mod sapply_type;
+pub(crate) mod scalar_in;
+pub(crate) mod semicolon;
 pub(crate) mod seq;
 pub(crate) mod seq2;
 pub(crate) mod sort;
 pub(crate) mod sprintf;
 pub(crate) mod string_boundary;
 pub(crate) mod system_file;
+pub(crate) mod trailing_blank_lines;
+pub(crate) mod trailing_whitespace;
 pub(crate) mod true_false_symbol;
+pub(crate) mod undesirable_function;
+pub(crate) mod undesirable_operator;
+pub(crate) mod unknown_nolint_rule;
+pub(crate) mod unnecessary_concatenation;
+pub(crate) mod unnecessary_lambda;
 pub(crate) mod unnecessary_nesting;
 pub(crate) mod unreachable_code;
 pub(crate) mod vector_logic;
@@ -0,0 +1,74 @@
+pub(crate) mod nested_ifelse;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_nested_ifelse() {
+        expect_no_lint("ifelse(a, x, y)", "nested_ifelse", None);
+        expect_no_lint("ifelse(a, x, ifelse(b, y, z))", "nested_ifelse", None);
+        expect_no_lint("foo(a, x, foo(b, y, foo(c, z, w)))", "nested_ifelse", None);
+
+        // Only the `no` argument is followed
+        expect_no_lint(
+            "ifelse(a, ifelse(b, ifelse(c, x, y), z), w)",
+            "nested_ifelse",
+            None,
+        );
+        expect_no_lint(
+            "ifelse(a, x, foo(ifelse(b, y, ifelse(c, z, w))))",
+            "nested_ifelse",
+            None,
+        );
+
+        // Wrong syntax but no panic
+        expect_no_lint("ifelse()", "nested_ifelse", None);
+        expect_no_lint("ifelse(a, x, ifelse())", "nested_ifelse", None);
+    }
+
+    #[test]
+    fn test_lint_nested_ifelse() {
+        let expected_message = "This chain of 3 nested `ifelse()` calls is hard to read.";
+        expect_lint(
+            "ifelse(a, x, ifelse(b, y, ifelse(c, z, w)))",
+            expected_message,
+            "nested_ifelse",
+            None,
+        );
+        expect_lint(
+            "ifelse(no = ifelse(b, y, ifelse(c, z, w)), test = a, yes = x)",
+            expected_message,
+            "nested_ifelse",
+            None,
+        );
+        expect_lint(
+            "dplyr::if_else(a, x, if_else(b, y, false = fifelse(c, z, w)))",
+            expected_message,
+            "nested_ifelse",
+            None,
+        );
+        expect_lint(
+            "ifelse(a, x, ifelse(b, y, ifelse(c, z, ifelse(d, v, w))))",
+            "This chain of 4 nested `ifelse()` calls",
+            "nested_ifelse",
+            None,
+        );
+
+        // The outermost call is reported, once.
+        expect_diagnostic_highlight(
+            "y <- ifelse(a, x, ifelse(b, y, ifelse(c, z, w)))",
+            "nested_ifelse",
+            "ifelse(a, x, ifelse(b, y, ifelse(c, z, w)))",
+        );
+        assert_eq!(
+            check_code(
+                "ifelse(a, x, ifelse(b, y, ifelse(c, z, ifelse(d, v, w))))",
+                "nested_ifelse",
+                None
+            )
+            .len(),
+            1
+        );
+    }
+}
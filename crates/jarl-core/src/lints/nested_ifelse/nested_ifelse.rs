@@ -0,0 +1,104 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// ## What it does
+///
+/// Checks for chains of `ifelse()` calls nested in the `no` argument, e.g.
+/// `ifelse(a, x, ifelse(b, y, z))`. Calls to `dplyr::if_else()` and
+/// `data.table::fifelse()` are also counted.
+///
+/// By default, chains of more than 2 calls are reported. This can be changed
+/// in `jarl.toml`:
+///
+/// ```toml
+/// [lint.nested_ifelse]
+/// max_depth = 3
+/// ```
+///
+/// ## Why is this bad?
+///
+/// Long chains of `ifelse()` are hard to read and to modify. Functions like
+/// `dplyr::case_when()` or `data.table::fcase()` list all cases at the same
+/// level. When the cases are the values of a single variable, `switch()` can
+/// also be used on each value.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// ifelse(x == "a", 1, ifelse(x == "b", 2, ifelse(x == "c", 3, NA)))
+/// ```
+///
+/// Use instead:
+/// ```r
+/// dplyr::case_when(
+///   x == "a" ~ 1,
+///   x == "b" ~ 2,
+///   x == "c" ~ 3,
+///   .default = NA
+/// )
+/// ```
+pub fn nested_ifelse(ast: &RCall, max_depth: usize) -> anyhow::Result<Option<Diagnostic>> {
+    if !is_ifelse(ast) {
+        return Ok(None);
+    }
+
+    // Only report the outermost call of a chain.
+    if let Some(argument) = ast.parent::<RArgument>()
+        && let Some(parent_call) = argument.syntax().ancestors().find_map(RCall::cast)
+        && no_argument(&parent_call).is_some_and(|no| no.syntax() == argument.syntax())
+    {
+        return Ok(None);
+    }
+
+    let depth = ifelse_depth(ast);
+    if depth <= max_depth {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "nested_ifelse".to_string(),
+            format!("This chain of {depth} nested `ifelse()` calls is hard to read."),
+            Some("Use `dplyr::case_when()` or `switch()` instead.".to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+fn is_ifelse(call: &RCall) -> bool {
+    call.function().is_ok_and(|function| {
+        matches!(
+            get_function_name(function).as_str(),
+            "ifelse" | "if_else" | "fifelse"
+        )
+    })
+}
+
+/// The argument used when the condition is false, i.e. `no` in `ifelse()`.
+fn no_argument(call: &RCall) -> Option<RArgument> {
+    let args = call.arguments().ok()?.items();
+    match get_function_name(call.function().ok()?).as_str() {
+        "ifelse" | "fifelse" => get_arg_by_name_then_position(&args, "no", 3),
+        "if_else" => get_arg_by_name_then_position(&args, "false", 3),
+        _ => None,
+    }
+}
+
+/// Number of `ifelse()` calls in the chain starting at `call`, following the
+/// `no` argument.
+fn ifelse_depth(call: &RCall) -> usize {
+    let inner_call = no_argument(call)
+        .and_then(|no| no.value())
+        .and_then(|value| value.as_r_call().cloned())
+        .filter(is_ifelse);
+
+    1 + inner_call.map_or(0, |inner_call| ifelse_depth(&inner_call))
+}
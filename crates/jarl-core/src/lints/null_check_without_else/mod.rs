@@ -0,0 +1,55 @@
+pub(crate) mod null_check_without_else;
+
+#[cfg(test)]
+mod tests {
+    use crate::rule_set::Rule;
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_null_check_without_else() {
+        expect_no_lint("if (!is.null(x)) x else y", "null_check_without_else", None);
+        expect_no_lint("if (!is.null(x)) y", "null_check_without_else", None);
+        expect_no_lint("if (is.null(x)) x", "null_check_without_else", None);
+        expect_no_lint("if (!is.null(x)) x[1]", "null_check_without_else", None);
+        expect_no_lint("if (!is.null(x)) { x; y }", "null_check_without_else", None);
+        expect_no_lint("if (!is.na(x)) x", "null_check_without_else", None);
+        expect_no_lint("if (!is.null(x = x)) x", "null_check_without_else", None);
+    }
+
+    #[test]
+    fn test_lint_null_check_without_else() {
+        let expected_message = "without `else` always returns `x`";
+
+        expect_lint(
+            "if (!is.null(x)) x",
+            expected_message,
+            "null_check_without_else",
+            None,
+        );
+        expect_lint(
+            "if (!is.null(x)) { x }",
+            expected_message,
+            "null_check_without_else",
+            None,
+        );
+        expect_lint(
+            "c(if (!is.null(E)) E)",
+            expected_message,
+            "null_check_without_else",
+            None,
+        );
+        expect_lint(
+            "if (!is.null(foo(x))) foo(x)",
+            expected_message,
+            "null_check_without_else",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_null_check_without_else_is_opt_in() {
+        // This pattern is also valid code, so only report it if the user
+        // explicitly selected the rule.
+        assert!(Rule::NullCheckWithoutElse.is_disabled_by_default());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::diagnostic::*;
+use crate::utils::get_function_name;
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList};
+
+pub struct NullCheckWithoutElse;
+
+/// ## What it does
+///
+/// Checks for usage of `if (!is.null(x)) x` without an `else` branch.
+///
+/// This rule is disabled by default.
+///
+/// ## Why is this bad?
+///
+/// When the condition is `FALSE` and there is no `else` branch, the `if`
+/// statement returns `NULL`. Therefore, `if (!is.null(x)) x` always returns
+/// `x` (invisibly when `x` is `NULL`). This often means that the `else`
+/// branch was forgotten, for instance when the intent was to write
+/// `if (!is.null(x)) x else y` (which can be replaced by `x %||% y`).
+///
+/// ## Example
+///
+/// ```r
+/// x <- NULL
+/// y <- 1
+///
+/// z <- if (!is.null(x)) x
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- NULL
+/// y <- 1
+///
+/// z <- if (!is.null(x)) x else y
+/// # or, if R >= 4.4.0
+/// z <- x %||% y
+/// ```
+///
+/// ## Reference
+///
+/// See `?Control`
+impl Violation for NullCheckWithoutElse {
+    fn name(&self) -> String {
+        "null_check_without_else".to_string()
+    }
+    fn body(&self) -> String {
+        "`if (!is.null(x)) x` without `else` always returns `x`.".to_string()
+    }
+    fn suggestion(&self) -> Option<String> {
+        Some("Add an `else` branch or use `x` directly.".to_string())
+    }
+}
+
+pub fn null_check_without_else(ast: &RIfStatement) -> anyhow::Result<Option<Diagnostic>> {
+    if ast.else_clause().is_some() {
+        return Ok(None);
+    }
+
+    let condition = ast.condition()?;
+    let consequence = ast.consequence()?;
+
+    let condition = unwrap_or_return_none!(condition.as_r_unary_expression());
+    if condition.operator()?.text_trimmed() != "!" {
+        return Ok(None);
+    }
+
+    let argument = condition.argument()?;
+    let call = unwrap_or_return_none!(argument.as_r_call());
+    if get_function_name(call.function()?) != "is.null" {
+        return Ok(None);
+    }
+
+    let args = call.arguments()?.items();
+    if args.len() != 1 {
+        return Ok(None);
+    }
+    let checked = unwrap_or_return_none!(args.into_iter().next().and_then(|x| x.ok()));
+    if checked.name_clause().is_some() {
+        return Ok(None);
+    }
+    let checked = unwrap_or_return_none!(checked.value());
+
+    let consequence = if let Some(braced) = consequence.as_r_braced_expressions() {
+        let expressions: Vec<_> = braced.expressions().into_iter().collect();
+        if expressions.len() != 1 {
+            return Ok(None);
+        }
+        expressions.into_iter().next().unwrap()
+    } else {
+        consequence
+    };
+
+    if checked.to_trimmed_string() != consequence.to_trimmed_string() {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(NullCheckWithoutElse, range, Fix::empty());
+
+    Ok(Some(diagnostic))
+}
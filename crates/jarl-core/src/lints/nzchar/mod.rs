@@ -0,0 +1,104 @@
+pub(crate) mod nzchar;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_nzchar() {
+        expect_no_lint("nzchar(x)", "nzchar", None);
+        expect_no_lint("!nzchar(x)", "nzchar", None);
+        expect_no_lint("nchar(x) > 1", "nzchar", None);
+        expect_no_lint("nchar(x) == 2", "nzchar", None);
+        expect_no_lint("nchar(x) >= 0", "nzchar", None);
+        expect_no_lint("nchar(x) < 0", "nzchar", None);
+        expect_no_lint("nchar(x) + 0", "nzchar", None);
+        expect_no_lint("length(x) > 0", "nzchar", None);
+        expect_no_lint("nchar(x) > y", "nzchar", None);
+    }
+
+    #[test]
+    fn test_lint_nzchar() {
+        use insta::assert_snapshot;
+        let expected_message = "is inefficient to check whether strings are empty";
+
+        expect_lint("nchar(x) > 0", expected_message, "nzchar", None);
+        expect_lint("nchar(x) != 0", expected_message, "nzchar", None);
+        expect_lint("nchar(x) >= 1", expected_message, "nzchar", None);
+        expect_lint("nchar(x) == 0", expected_message, "nzchar", None);
+        expect_lint("nchar(x) <= 0", expected_message, "nzchar", None);
+        expect_lint("nchar(x) < 1", expected_message, "nzchar", None);
+        expect_lint("nchar(x) > 0L", expected_message, "nzchar", None);
+        expect_lint("0 < nchar(x)", expected_message, "nzchar", None);
+        expect_lint("1 > nchar(x)", expected_message, "nzchar", None);
+        expect_lint("base::nchar(x) > 0", expected_message, "nzchar", None);
+        expect_lint(
+            "nchar(x, type = 'bytes') > 0",
+            expected_message,
+            "nzchar",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_unsafe_fixed_text(
+                vec![
+                    "nchar(x) > 0",
+                    "nchar(x) != 0",
+                    "nchar(x) >= 1",
+                    "nchar(x) == 0",
+                    "nchar(x) <= 0",
+                    "nchar(x) < 1",
+                    "0 < nchar(x)",
+                    "1 > nchar(x)",
+                    "x[nchar(x) > 0L]",
+                    "nchar(paste(x, y)) == 0",
+                ],
+                "nzchar"
+            )
+        );
+    }
+
+    #[test]
+    fn test_nzchar_no_safe_fix() {
+        use insta::assert_snapshot;
+        // `nchar(NA) > 0` is `TRUE` but `nchar(NA_character_) > 0` is `NA`.
+        assert_snapshot!(
+            "no_safe_fix",
+            get_fixed_text(vec!["nchar(x) > 0", "nchar(x) == 0"], "nzchar", None)
+        );
+    }
+
+    #[test]
+    fn test_nzchar_no_fix_with_other_arguments() {
+        use insta::assert_snapshot;
+        assert_snapshot!(
+            "no_fix_other_arguments",
+            get_unsafe_fixed_text(
+                vec![
+                    "nchar(x, type = 'bytes') > 0",
+                    "nchar(x, keepNA = TRUE) == 0",
+                ],
+                "nzchar"
+            )
+        );
+    }
+
+    #[test]
+    fn test_nzchar_with_comments_no_fix() {
+        use insta::assert_snapshot;
+        // Should detect lint but skip fix when comments are present to avoid destroying them
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_unsafe_fixed_text(
+                vec![
+                    "# leading comment\nnchar(x) > 0",
+                    "nchar(x) # comment\n> 0",
+                    "nchar(\n  # comment\n  x\n) == 0",
+                    "nchar(x) > 0 # trailing comment",
+                ],
+                "nzchar"
+            )
+        );
+    }
+}
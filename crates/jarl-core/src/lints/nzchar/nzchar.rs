@@ -0,0 +1,128 @@
+use crate::diagnostic::*;
+use crate::utils::{get_function_name, negated_comparison_operator};
+use crate::utils_ast::fix_replacing_node;
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// ## What it does
+///
+/// Checks for usage of `nchar(x) > 0`, `nchar(x) == 0`, and similar comparisons
+/// to test whether strings are empty.
+///
+/// ## Why is this bad?
+///
+/// `nzchar()` is meant to test whether strings are non-empty. It is faster and
+/// easier to read than counting the number of characters with `nchar()` and
+/// comparing the result to 0.
+///
+/// This rule reports the following comparisons (and the equivalent ones with
+/// the arguments swapped, e.g. `0 < nchar(x)`):
+///
+/// - `nchar(x) > 0`, `nchar(x) != 0`, and `nchar(x) >= 1`, that can be replaced
+///   by `nzchar(x)`;
+/// - `nchar(x) == 0`, `nchar(x) <= 0`, and `nchar(x) < 1`, that can be replaced
+///   by `!nzchar(x)`.
+///
+/// This rule has an unsafe fix because `nchar(NA) > 0` is `TRUE` but
+/// `nchar(NA_character_) > 0` is `NA`, while `nzchar()` returns `TRUE` for
+/// both. There is no fix if `nchar()` has other arguments than `x` (e.g. `type`
+/// or `keepNA`) because they can't always be passed to `nzchar()`.
+///
+/// ## Example
+///
+/// ```r
+/// x <- c("a", "", "b")
+/// x[nchar(x) > 0]
+/// x[nchar(x) == 0]
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- c("a", "", "b")
+/// x[nzchar(x)]
+/// x[!nzchar(x)]
+/// ```
+///
+/// ## References
+///
+/// See `?nzchar`
+pub fn nzchar(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
+    let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
+
+    let operator = operator?;
+    let left = left?;
+    let right = right?;
+
+    // Put the call to `nchar()` on the left side so that `0 < nchar(x)` is
+    // handled like `nchar(x) > 0`.
+    let (nchar_call, value, operator_kind) = if let Some(call) = as_nchar_call(&left) {
+        (call, right, operator.kind())
+    } else if let Some(call) = as_nchar_call(&right) {
+        let swapped = match operator.kind() {
+            RSyntaxKind::GREATER_THAN => RSyntaxKind::LESS_THAN,
+            RSyntaxKind::GREATER_THAN_OR_EQUAL_TO => RSyntaxKind::LESS_THAN_OR_EQUAL_TO,
+            RSyntaxKind::LESS_THAN => RSyntaxKind::GREATER_THAN,
+            RSyntaxKind::LESS_THAN_OR_EQUAL_TO => RSyntaxKind::GREATER_THAN_OR_EQUAL_TO,
+            kind => kind,
+        };
+        (call, left, swapped)
+    } else {
+        return Ok(None);
+    };
+
+    let value = value.to_trimmed_string();
+    let is_zero = value == "0" || value == "0L";
+    let is_one = value == "1" || value == "1L";
+
+    // `nchar(x) == 0`, `nchar(x) <= 0`, and `nchar(x) < 1` are the negations
+    // of `nchar(x) != 0`, `nchar(x) > 0`, and `nchar(x) >= 1`.
+    let negation = unwrap_or_return_none!(negated_comparison_operator(operator_kind));
+    let negated = match negation {
+        "==" | "<=" if is_zero => false,
+        "<" if is_one => false,
+        "!=" | ">" if is_zero => true,
+        ">=" if is_one => true,
+        _ => return Ok(None),
+    };
+
+    let suggestion = if negated { "!nzchar(x)" } else { "nzchar(x)" };
+
+    let arguments = nchar_call.arguments()?.items();
+
+    // Other arguments, such as `type` or `keepNA`, can't always be passed to
+    // `nzchar()`.
+    let fix = if arguments.iter().count() == 1
+        && let Some(Ok(argument)) = arguments.iter().next()
+    {
+        let bang = if negated { "!" } else { "" };
+        let content = format!("{bang}nzchar({})", argument.to_trimmed_string());
+        fix_replacing_node(ast.syntax(), content)
+    } else {
+        Fix::empty()
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "nzchar".to_string(),
+            format!(
+                "`{}` is inefficient to check whether strings are empty.",
+                ast.to_trimmed_string()
+            ),
+            Some(format!("Use `{suggestion}` instead.")),
+        ),
+        ast.syntax().text_trimmed_range(),
+        fix,
+    );
+
+    Ok(Some(diagnostic))
+}
+
+fn as_nchar_call(expr: &AnyRExpression) -> Option<RCall> {
+    let call = expr.as_r_call()?;
+    let function = call.function().ok()?;
+    if get_function_name(function) == "nchar" {
+        Some(call.clone())
+    } else {
+        None
+    }
+}
@@ -0,0 +1,71 @@
+pub(crate) mod object_name;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_object_name() {
+        expect_no_lint("x <- 1", "object_name", None);
+        expect_no_lint("my_value <- 1", "object_name", None);
+        expect_no_lint("value_2 = 1", "object_name", None);
+        expect_no_lint("my_value <<- 1", "object_name", None);
+        expect_no_lint("1 -> my_value", "object_name", None);
+        expect_no_lint(".hidden_value <- 1", "object_name", None);
+        expect_no_lint("été <- 1", "object_name", None);
+        expect_no_lint("`%+%` <- function(a, b) a + b", "object_name", None);
+        expect_no_lint("`[.my_class` <- function(x, i) x", "object_name", None);
+        expect_no_lint("print.myClass <- function(x, ...) x", "object_name", None);
+        expect_no_lint(
+            "as.data.frame.myClass <- function(x, ...) x",
+            "object_name",
+            None,
+        );
+        expect_no_lint("x$myValue <- 1", "object_name", None);
+        expect_no_lint("names(x) <- 1", "object_name", None);
+        expect_no_lint("f(myValue = 1)", "object_name", None);
+        expect_no_lint("function(myValue = 1) myValue", "object_name", None);
+        expect_no_lint("myValue == 1", "object_name", None);
+        expect_no_lint("myValue <- 1 # nolint: object_name", "object_name", None);
+    }
+
+    #[test]
+    fn test_lint_object_name() {
+        let expected_message = "doesn't use snake_case";
+        expect_lint("myValue <- 1", expected_message, "object_name", None);
+        expect_lint("MyValue = 1", expected_message, "object_name", None);
+        expect_lint("my.value <<- 1", expected_message, "object_name", None);
+        expect_lint("1 -> MY_VALUE", expected_message, "object_name", None);
+        expect_lint("1 ->> myValue", expected_message, "object_name", None);
+        expect_lint("X <- 1", expected_message, "object_name", None);
+        expect_lint(
+            ".myValue <- 1",
+            "`.myValue` doesn't use snake_case",
+            "object_name",
+            None,
+        );
+        // Not a function, so not an S3 method.
+        expect_lint("print.myClass <- 1", expected_message, "object_name", None);
+        // Not a known generic.
+        expect_lint(
+            "compute.myClass <- function(x) x",
+            expected_message,
+            "object_name",
+            None,
+        );
+        expect_lint(
+            "f <- function() {\n  myValue <- 1\n}",
+            expected_message,
+            "object_name",
+            None,
+        );
+        expect_lint(
+            "computeMean <- function(x) mean(x)",
+            expected_message,
+            "object_name",
+            None,
+        );
+
+        assert_eq!(check_code("aB <- cD <- 1", "object_name", None).len(), 2);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::config::NamingStyle;
+use crate::diagnostic::*;
+use crate::utils_ast::assignment_parts;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// ## What it does
+///
+/// Checks that the names of objects assigned with `<-`, `<<-`, `=`, `->`, or
+/// `->>` follow a naming style, `snake_case` by default.
+///
+/// The accepted styles can be changed with the option `style` of
+/// `[lint.object_name]` in `jarl.toml`. It takes one style or a list of
+/// styles among `"snake_case"`, `"camelCase"`, `"CamelCase"`,
+/// `"dotted.case"`, and `"UPPER_SNAKE"`. Names matching any of them are
+/// accepted:
+///
+/// ```toml
+/// [lint.object_name]
+/// style = ["snake_case", "UPPER_SNAKE"]
+/// ```
+///
+/// ## Why is this bad?
+///
+/// Using the same naming style everywhere makes names easier to remember and
+/// the code easier to read.
+///
+/// Leading dots, as in `.hidden_value`, are ignored. Names written between
+/// backticks, such as `` `%+%` `` or `` `[.my_class` ``, are not checked.
+/// Functions whose name starts with the name of a common S3 generic followed
+/// by a dot, such as `print.myClass` or `as.data.frame.myClass`, are S3
+/// methods and are not checked either since their name is imposed by the
+/// generic.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// myValue <- 1
+/// computeMean <- function(x) mean(x)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// my_value <- 1
+/// compute_mean <- function(x) mean(x)
+/// ```
+pub fn object_name(
+    ast: &RBinaryExpression,
+    styles: &[NamingStyle],
+) -> anyhow::Result<Option<Diagnostic>> {
+    let (target, value) = unwrap_or_return_none!(assignment_parts(ast));
+    let target = unwrap_or_return_none!(target.as_r_identifier());
+
+    let name = target.syntax().text_trimmed().to_string();
+    if name.starts_with('`') || is_s3_method(&name, &value) {
+        return Ok(None);
+    }
+
+    let stripped = name.trim_start_matches('.');
+    if stripped.is_empty() || styles.iter().any(|style| matches_style(stripped, *style)) {
+        return Ok(None);
+    }
+
+    let styles = styles
+        .iter()
+        .map(|style| style.name())
+        .collect::<Vec<_>>()
+        .join(" or ");
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "object_name".to_string(),
+            format!("`{name}` doesn't use {styles}."),
+            None,
+        ),
+        target.syntax().text_trimmed_range(),
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `name` follows `style`. Leading dots must be removed before.
+fn matches_style(name: &str, style: NamingStyle) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    let rest = chars.as_str();
+
+    match style {
+        NamingStyle::SnakeCase => {
+            first.is_lowercase()
+                && rest
+                    .chars()
+                    .all(|c| c.is_lowercase() || c.is_numeric() || c == '_')
+        }
+        NamingStyle::CamelCase => first.is_lowercase() && rest.chars().all(char::is_alphanumeric),
+        NamingStyle::UpperCamelCase => {
+            first.is_uppercase() && rest.chars().all(char::is_alphanumeric)
+        }
+        NamingStyle::DottedCase => {
+            first.is_lowercase()
+                && rest
+                    .chars()
+                    .all(|c| c.is_lowercase() || c.is_numeric() || c == '.')
+        }
+        NamingStyle::UpperSnake => {
+            first.is_uppercase()
+                && rest
+                    .chars()
+                    .all(|c| c.is_uppercase() || c.is_numeric() || c == '_')
+        }
+    }
+}
+
+/// Common S3 generics of base R and recommended packages, whose methods are
+/// named `<generic>.<class>`.
+const S3_GENERICS: &[&str] = &[
+    "aggregate",
+    "all.equal",
+    "anova",
+    "as.character",
+    "as.data.frame",
+    "as.Date",
+    "as.double",
+    "as.environment",
+    "as.function",
+    "as.integer",
+    "as.list",
+    "as.logical",
+    "as.matrix",
+    "as.numeric",
+    "as.POSIXct",
+    "as.vector",
+    "c",
+    "coef",
+    "cut",
+    "dim",
+    "droplevels",
+    "duplicated",
+    "fitted",
+    "format",
+    "head",
+    "is.na",
+    "labels",
+    "length",
+    "levels",
+    "logLik",
+    "mean",
+    "median",
+    "merge",
+    "names",
+    "plot",
+    "predict",
+    "print",
+    "quantile",
+    "range",
+    "rep",
+    "residuals",
+    "rev",
+    "seq",
+    "sort",
+    "split",
+    "str",
+    "subset",
+    "summary",
+    "t",
+    "tail",
+    "toString",
+    "transform",
+    "unique",
+    "update",
+    "vcov",
+    "with",
+    "xtfrm",
+];
+
+/// Whether `name` is the name of an S3 method of one of [S3_GENERICS], e.g.
+/// `print.myClass`. Only function definitions are considered.
+fn is_s3_method(name: &str, value: &AnyRExpression) -> bool {
+    if !matches!(value, AnyRExpression::RFunctionDefinition(_)) {
+        return false;
+    }
+    name.match_indices('.')
+        .any(|(i, _)| i + 1 < name.len() && S3_GENERICS.contains(&&name[..i]))
+}
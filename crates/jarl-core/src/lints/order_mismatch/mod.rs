@@ -0,0 +1,57 @@
+pub(crate) mod order_mismatch;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_order_mismatch() {
+        // Handled by the `sort` rule
+        expect_no_lint("x[order(x)]", "order_mismatch", None);
+        expect_no_lint("x[order(x, decreasing = TRUE)]", "order_mismatch", None);
+
+        expect_no_lint("x[order(y, z)]", "order_mismatch", None);
+        expect_no_lint("x[order(y), ]", "order_mismatch", None);
+        expect_no_lint("df[order(df$x), ]", "order_mismatch", None);
+        expect_no_lint("x[order(df$y)]", "order_mismatch", None);
+        expect_no_lint("x[order(f(y))]", "order_mismatch", None);
+        expect_no_lint("f(x)[order(y)]", "order_mismatch", None);
+        expect_no_lint("x[foo = order(y)]", "order_mismatch", None);
+        expect_no_lint("x[rev(order(y))]", "order_mismatch", None);
+        expect_no_lint("order(y)", "order_mismatch", None);
+    }
+
+    #[test]
+    fn test_lint_order_mismatch() {
+        let expected_message = "`x` is reordered by the order of `y`";
+        expect_lint("x[order(y)]", expected_message, "order_mismatch", None);
+        expect_lint(
+            "x[order(y, decreasing = TRUE)]",
+            expected_message,
+            "order_mismatch",
+            None,
+        );
+        expect_lint(
+            "x[order(method = \"radix\", y)]",
+            expected_message,
+            "order_mismatch",
+            None,
+        );
+        expect_lint(
+            "names[order(ages)]",
+            "Make sure that `names` and `ages` are aligned.",
+            "order_mismatch",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_order_mismatch_disabled_by_default() {
+        // The rule is opt-in, so it isn't run with the default rules.
+        assert!(
+            check_code("x[order(y)]", "", None)
+                .iter()
+                .all(|d| d.message.name != "order_mismatch")
+        );
+    }
+}
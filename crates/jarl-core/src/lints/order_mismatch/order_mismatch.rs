@@ -0,0 +1,97 @@
+use crate::diagnostic::*;
+use crate::utils::{get_function_name, get_unnamed_args};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// ## What it does
+///
+/// Checks for usage of `x[order(y)]`, where `x` and `y` are different
+/// variables.
+///
+/// ## Why is this bad?
+///
+/// Reordering a vector by the order of another vector is valid, but it is
+/// only correct if both vectors are aligned, i.e. if the i-th element of `x`
+/// corresponds to the i-th element of `y`. This is sometimes not the case,
+/// for instance if one of them was filtered or sorted in the meantime.
+///
+/// This rule only looks for this pattern and can't know whether both vectors
+/// are aligned, so it is disabled by default. Reordering a vector by its own
+/// order, e.g. `x[order(x)]`, is reported by the `sort` rule instead.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// names <- c("b", "c", "a")
+/// ages <- c(20, 30, 10)
+/// names <- names[ages > 15]
+/// names[order(ages)]
+/// ```
+///
+/// Use instead:
+/// ```r
+/// names <- c("b", "c", "a")
+/// ages <- c(20, 30, 10)
+/// keep <- ages > 15
+/// names <- names[keep]
+/// ages <- ages[keep]
+/// names[order(ages)]
+/// ```
+pub fn order_mismatch(ast: &RSubset) -> anyhow::Result<Option<Diagnostic>> {
+    let RSubsetFields { function, arguments } = ast.as_fields();
+    let subject = function?;
+    let arguments = arguments?;
+
+    // Only report vectors, i.e. `x[order(y)]` but not `x[order(y), ]`.
+    let inside_brackets: Vec<_> = arguments.items().into_iter().collect();
+    if inside_brackets.len() != 1 {
+        return Ok(None);
+    }
+    // Safety: we know that `inside_brackets` contains a single element.
+    let arg = inside_brackets.first().unwrap().clone()?;
+    if arg.name_clause().is_some() {
+        return Ok(None);
+    }
+
+    let arg_value = unwrap_or_return_none!(arg.value());
+    let order_call = unwrap_or_return_none!(arg_value.as_r_call());
+    if get_function_name(order_call.function()?) != "order" {
+        return Ok(None);
+    }
+
+    // Only one key, `order(y, z)` is most likely meant to break ties.
+    let order_args = order_call.arguments()?.items();
+    let keys = get_unnamed_args(&order_args);
+    if keys.len() != 1 {
+        return Ok(None);
+    }
+    // Safety: we know that `keys` contains a single element.
+    let key = unwrap_or_return_none!(keys.first().unwrap().value());
+
+    // Only compare symbols, more complex expressions such as `df$x` can't be
+    // compared reliably.
+    let subject = unwrap_or_return_none!(subject.as_r_identifier());
+    let key = unwrap_or_return_none!(key.as_r_identifier());
+    let subject_name = subject.syntax().text_trimmed().to_string();
+    let key_name = key.syntax().text_trimmed().to_string();
+    if subject_name == key_name {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "order_mismatch".to_string(),
+            format!("`{subject_name}` is reordered by the order of `{key_name}`."),
+            Some(format!(
+                "Make sure that `{subject_name}` and `{key_name}` are aligned."
+            )),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
@@ -0,0 +1,93 @@
+pub(crate) mod paste_sep;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_paste_sep() {
+        expect_no_lint("paste(x, y)", "paste_sep", None);
+        expect_no_lint("paste(x, y, sep = ' ')", "paste_sep", None);
+        expect_no_lint("paste(x, y, sep = my_sep)", "paste_sep", None);
+        expect_no_lint("paste(x, y, collapse = '')", "paste_sep", None);
+        expect_no_lint("paste0(x, y)", "paste_sep", None);
+        expect_no_lint("paste0(x, y, collapse = ', ')", "paste_sep", None);
+        expect_no_lint("paste0(x, sep = ', ')", "paste_sep", None);
+        expect_no_lint("paste0(..., collapse = ', ')", "paste_sep", None);
+        expect_no_lint("paste(x, y, '')", "paste_sep", None);
+    }
+
+    #[test]
+    fn test_lint_paste_sep() {
+        use insta::assert_snapshot;
+
+        let expected_message = "`paste(..., sep = \"\")` is unnecessarily complex";
+        expect_lint("paste(x, y, sep = '')", expected_message, "paste_sep", None);
+        expect_lint(
+            "paste(sep = \"\", x, y)",
+            expected_message,
+            "paste_sep",
+            None,
+        );
+        expect_lint(
+            "base::paste(x, sep = '')",
+            expected_message,
+            "paste_sep",
+            None,
+        );
+
+        let expected_message = "`paste0(x, collapse = ...)` suggests that an argument is missing";
+        expect_lint(
+            "paste0(x, collapse = ', ')",
+            expected_message,
+            "paste_sep",
+            None,
+        );
+        expect_lint(
+            "paste0(collapse = '', x)",
+            expected_message,
+            "paste_sep",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "paste(x, y, sep = '')",
+                    "paste(sep = \"\", x, y)",
+                    "paste(x, sep = '', y)",
+                    "paste(x, y, sep = '', collapse = ', ')",
+                    "paste(sep = '')",
+                    "base::paste(\n  x,\n  y,\n  sep = ''\n)",
+                    "paste(\n  sep = '',\n  x,\n  y\n)",
+                    "paste0(x, collapse = ', ')",
+                    "base::paste0(collapse = '', x)",
+                ],
+                "paste_sep",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_paste_sep_with_comments() {
+        use insta::assert_snapshot;
+        // Comments are kept, but the fix is skipped when removing `sep = ""`
+        // would remove a comment too.
+        assert_snapshot!(
+            "fix_with_comments",
+            get_fixed_text(
+                vec![
+                    "# leading comment\npaste(x, y, sep = '')",
+                    "paste(\n  x, # comment\n  y,\n  sep = ''\n)",
+                    "paste(x, y, sep = '') # trailing comment",
+                    "paste(\n  x,\n  y, # comment\n  sep = ''\n)",
+                    "paste(\n  sep = '', # comment\n  x\n)",
+                ],
+                "paste_sep",
+                None
+            )
+        );
+    }
+}
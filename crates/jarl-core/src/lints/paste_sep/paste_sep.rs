@@ -0,0 +1,142 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name, get_function_name};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList, TextRange, TextSize};
+
+/// ## What it does
+///
+/// Checks for usage of `paste(..., sep = "")` and of `paste0(x, collapse = ...)`.
+///
+/// ## Why is this bad?
+///
+/// `paste0(...)` is a shortcut for `paste(..., sep = "")`, so there is no need
+/// to specify `sep = ""` manually.
+///
+/// `paste0()` is meant to concatenate several vectors element-wise without
+/// separator. When it is called on a single vector with `collapse`, it does the
+/// same thing as `paste()` but suggests that there is a missing second vector.
+/// Using `paste(x, collapse = ...)` is clearer in this case.
+///
+/// This rule has a safe fix. It is only reported when `sep` is the empty
+/// string literal `""`, not when it is a variable.
+///
+/// ## Example
+///
+/// ```r
+/// paste("a", "b", sep = "")
+/// paste0(letters, collapse = ", ")
+/// ```
+///
+/// Use instead:
+/// ```r
+/// paste0("a", "b")
+/// paste(letters, collapse = ", ")
+/// ```
+pub fn paste_sep(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    let function = ast.function()?;
+    let fn_name = get_function_name(function.clone());
+
+    let items = ast.arguments()?.items();
+    let args = items.iter().collect::<Result<Vec<_>, _>>()?;
+
+    // Only the name of the function is replaced, without the namespace prefix.
+    let name_end = function.syntax().text_trimmed_range().end();
+    let name_start = name_end - TextSize::of(fn_name.as_str());
+
+    let (body, suggestion, fix) = match fn_name.as_str() {
+        "paste" => {
+            let sep = unwrap_or_return_none!(get_arg_by_name(&items, "sep"));
+            let sep_value = unwrap_or_return_none!(sep.value());
+            if !is_empty_string(&sep_value) {
+                return Ok(None);
+            }
+
+            // Remove `sep = ""` with the comma that separates it from the next
+            // argument, or from the previous one if it is the last argument.
+            let sep_range = sep.syntax().text_trimmed_range();
+            let position = args.iter().position(|arg| arg.syntax() == sep.syntax());
+            let removed = match position {
+                Some(i) if i + 1 < args.len() => TextRange::new(
+                    sep_range.start(),
+                    args[i + 1].syntax().text_trimmed_range().start(),
+                ),
+                Some(i) if i > 0 => TextRange::new(
+                    args[i - 1].syntax().text_trimmed_range().end(),
+                    sep_range.end(),
+                ),
+                _ => sep_range,
+            };
+
+            let call_text = ast.syntax().text_trimmed().to_string();
+            let offset = usize::from(ast.syntax().text_trimmed_range().start());
+            let between =
+                &call_text[usize::from(name_end) - offset..usize::from(removed.start()) - offset];
+            // Don't remove comments between `sep = ""` and the adjacent argument.
+            let to_skip = call_text
+                [usize::from(removed.start()) - offset..usize::from(removed.end()) - offset]
+                .contains('#');
+            (
+                "`paste(..., sep = \"\")` is unnecessarily complex.",
+                "Use `paste0(...)` instead.",
+                Fix {
+                    content: format!("paste0{between}"),
+                    start: name_start.into(),
+                    end: removed.end().into(),
+                    to_skip,
+                },
+            )
+        }
+        "paste0" => {
+            let [first, second] = args.as_slice() else {
+                return Ok(None);
+            };
+            let (vector, collapse) = if first.name_clause().is_none() {
+                (first, second)
+            } else {
+                (second, first)
+            };
+            if vector.name_clause().is_some() || !is_named(collapse, "collapse") {
+                return Ok(None);
+            }
+            let vector_value = unwrap_or_return_none!(vector.value());
+            if vector_value.to_trimmed_string() == "..." {
+                return Ok(None);
+            }
+            (
+                "`paste0(x, collapse = ...)` suggests that an argument is missing.",
+                "Use `paste(x, collapse = ...)` instead.",
+                Fix {
+                    content: "paste".to_string(),
+                    start: name_start.into(),
+                    end: name_end.into(),
+                    to_skip: false,
+                },
+            )
+        }
+        _ => return Ok(None),
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "paste_sep".to_string(),
+            body.to_string(),
+            Some(suggestion.to_string()),
+        ),
+        ast.syntax().text_trimmed_range(),
+        fix,
+    );
+
+    Ok(Some(diagnostic))
+}
+
+fn is_empty_string(expr: &AnyRExpression) -> bool {
+    expr.as_any_r_value()
+        .and_then(|value| value.as_r_string_value().cloned())
+        .is_some_and(|string| matches!(string.to_trimmed_string().as_str(), "\"\"" | "''"))
+}
+
+fn is_named(arg: &RArgument, name: &str) -> bool {
+    arg.name_clause()
+        .and_then(|clause| clause.name().ok())
+        .is_some_and(|arg_name| arg_name.to_trimmed_string() == name)
+}
@@ -0,0 +1,118 @@
+pub(crate) mod pipe_consistency;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_pipe_consistency() {
+        expect_no_lint("x %>% f()", "pipe_consistency", None);
+        expect_no_lint("x |> f()", "pipe_consistency", None);
+        expect_no_lint("x %>% f() %>% g(., y)", "pipe_consistency", None);
+        expect_no_lint("x |> f() |> g()", "pipe_consistency", None);
+        expect_no_lint("x %in% y |> f()", "pipe_consistency", None);
+        expect_no_lint("x %>% f()\ny %T>% g()", "pipe_consistency", None);
+        expect_no_lint(
+            "x |> f()\n# nolint: pipe_consistency\ny %>% g()",
+            "pipe_consistency",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_pipe_consistency() {
+        use insta::assert_snapshot;
+
+        expect_lint(
+            "x |> f()\ny %>% g()",
+            "Use the native pipe `|>` instead of `%>%`",
+            "pipe_consistency",
+            None,
+        );
+        expect_lint(
+            "x %>% f()\ny %>% g()\nz |> h()",
+            "Use the magrittr pipe `%>%` instead of `|>`",
+            "pipe_consistency",
+            None,
+        );
+        expect_lint(
+            "x |> f()\ny %T>% g()",
+            "Use the native pipe `|>` instead of `%T>%`",
+            "pipe_consistency",
+            None,
+        );
+
+        assert_eq!(
+            check_code(
+                "x |> f() |> g()\ny %>% h() %>% k()",
+                "pipe_consistency",
+                None
+            )
+            .len(),
+            2
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "x |> f()\ny %>% g()",
+                    "x |> f()\ny %>% g",
+                    "x |> f()\ny %>% pkg::g",
+                    "x |> f()\ny %>% g(., z)",
+                    "x |> f()\ny %>% g(.)",
+                    "x |> f()\ny %>%\n  g(., z)",
+                ],
+                "pipe_consistency",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_pipe_consistency_magrittr_features_no_fix() {
+        use insta::assert_snapshot;
+
+        assert_snapshot!(
+            "no_fix_magrittr_features",
+            get_fixed_text(
+                vec![
+                    "x |> f()\ny %>% g(z, .)",
+                    "x |> f()\ny %>% g(h(.))",
+                    "x |> f()\ny %>% g(., h(.))",
+                    "x |> f()\ny %>% { g(.) }",
+                    "x |> f()\ny %T>% g()",
+                    "x |> f()\ny %>% g(.,\n  # comment\n  z)",
+                    "x %>% f()\ny %>% g()\nz |> h(a = _)",
+                ],
+                "pipe_consistency",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_pipe_consistency_magrittr_fix_is_unsafe() {
+        use insta::assert_snapshot;
+
+        assert_snapshot!(
+            "no_safe_fix_magrittr",
+            get_fixed_text(
+                vec!["x %>% f()\ny %>% g()\nz |> h()"],
+                "pipe_consistency",
+                None
+            )
+        );
+        assert_snapshot!(
+            "unsafe_fix_magrittr",
+            get_unsafe_fixed_text(
+                vec![
+                    "x %>% f()\ny %>% g()\nz |> h()",
+                    "x %>% f()\ny %>% g()\nz |> h(y = .)",
+                    "x %>% f()\ny %>% g()\nz |> h(\\(x) x + .)",
+                ],
+                "pipe_consistency"
+            )
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use crate::config::PipeStyle;
+use crate::diagnostic::*;
+use crate::utils_ast::{enclosing_statement, pipe_parts};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList, Direction};
+
+/// ## What it does
+///
+/// Checks that a single pipe operator is used, either the native pipe `|>` or
+/// the magrittr pipe `%>%`.
+///
+/// By default, only files that mix both pipes are reported, and the pipe that
+/// is used the least in the file is reported. This can be changed with the
+/// option `style` in `[lint.pipe_consistency]`, which can be `"native"` or
+/// `"magrittr"` to report every use of the other pipe.
+///
+/// ## Why is this bad?
+///
+/// Mixing both pipes makes code harder to read since they don't behave
+/// exactly in the same way.
+///
+/// This rule is disabled by default. Its fix replaces one pipe by the other.
+/// `%>%` is only replaced by `|>` when this doesn't change the behavior of the
+/// code. Uses of `%>%` that rely on features specific to magrittr, such as `.`
+/// in another position than the first argument, `{ }` blocks, or the tee pipe
+/// `%T>%`, are reported without a fix.
+///
+/// Replacing `|>` by `%>%` is an unsafe fix, and isn't done when the right side
+/// uses `_`, which `%>%` doesn't support, or `.`, which `%>%` would replace by
+/// the left side.
+///
+/// ## Example
+///
+/// ```r
+/// x |>
+///   filter(y > 1) %>%
+///   select(., z)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x |>
+///   filter(y > 1) |>
+///   select(z)
+/// ```
+pub fn pipe_consistency(
+    root: &RSyntaxNode,
+    style: PipeStyle,
+) -> anyhow::Result<Vec<(RSyntaxNode, Diagnostic)>> {
+    let source = root.text().to_string();
+
+    let pipes: Vec<(RBinaryExpression, RSyntaxToken, AnyRExpression)> = root
+        .descendants()
+        .filter_map(RBinaryExpression::cast)
+        .filter_map(|ast| {
+            let (operator, _, right) = pipe_parts(&ast)?;
+            Some((ast, operator, right))
+        })
+        .collect();
+
+    let n_native = pipes
+        .iter()
+        .filter(|(_, operator, _)| operator.kind() == RSyntaxKind::PIPE)
+        .count();
+    let n_magrittr = pipes.len() - n_native;
+
+    let preferred = match style {
+        PipeStyle::Native => PipeStyle::Native,
+        PipeStyle::Magrittr => PipeStyle::Magrittr,
+        PipeStyle::Auto if n_native == 0 || n_magrittr == 0 => return Ok(vec![]),
+        PipeStyle::Auto if n_magrittr > n_native => PipeStyle::Magrittr,
+        PipeStyle::Auto => PipeStyle::Native,
+    };
+
+    let mut diagnostics = vec![];
+    for (ast, operator, right) in pipes {
+        let is_native = operator.kind() == RSyntaxKind::PIPE;
+        let diagnostic = match preferred {
+            PipeStyle::Native if !is_native => to_native(&operator, &right, &source),
+            PipeStyle::Magrittr if is_native => to_magrittr(&operator, &right),
+            _ => continue,
+        };
+        diagnostics.push((enclosing_statement(ast.syntax()), diagnostic));
+    }
+
+    Ok(diagnostics)
+}
+
+/// Report a magrittr pipe, with a fix replacing it by `|>` if possible.
+fn to_native(operator: &RSyntaxToken, right: &AnyRExpression, source: &str) -> Diagnostic {
+    let operator_text = operator.text_trimmed();
+    let (suggestion, fix) = if operator_text == "%T>%" {
+        (
+            Some(
+                "`%T>%` has no equivalent with `|>`, this must be rewritten manually.".to_string(),
+            ),
+            Fix::empty(),
+        )
+    } else {
+        match native_replacement(operator, right, source) {
+            Some(fix) => (None, fix),
+            None => (
+                Some(
+                    "This relies on features specific to magrittr, it must be rewritten manually."
+                        .to_string(),
+                ),
+                Fix::empty(),
+            ),
+        }
+    };
+
+    Diagnostic::new(
+        ViolationData::new(
+            "pipe_consistency".to_string(),
+            format!("Use the native pipe `|>` instead of `{operator_text}`."),
+            suggestion,
+        ),
+        operator.text_trimmed_range(),
+        fix,
+    )
+}
+
+/// The fix replacing `%>%` by `|>`, covering the operator and the right side
+/// of the pipe. Return `None` if the right side uses `.` in a way that `|>`
+/// doesn't support, or isn't a call or a function name.
+fn native_replacement(
+    operator: &RSyntaxToken,
+    right: &AnyRExpression,
+    source: &str,
+) -> Option<Fix> {
+    let start: usize = operator.text_trimmed_range().start().into();
+    let right_range = right.syntax().text_trimmed_range();
+    let right_start: usize = right_range.start().into();
+    let right_end: usize = right_range.end().into();
+    let between = &source[usize::from(operator.text_trimmed_range().end())..right_start];
+
+    let dots: Vec<RSyntaxToken> = right
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .filter(|token| token.text_trimmed() == ".")
+        .collect();
+
+    let new_right = match right {
+        // `x %>% f` is `f(x)`.
+        AnyRExpression::RIdentifier(_) | AnyRExpression::RNamespaceExpression(_)
+            if dots.is_empty() =>
+        {
+            format!("{}()", &source[right_start..right_end])
+        }
+        AnyRExpression::RCall(_) if dots.is_empty() => source[right_start..right_end].to_string(),
+        // `x %>% f(., y)` is `f(x, y)`.
+        AnyRExpression::RCall(call) if dots.len() == 1 => {
+            let arguments: Vec<RArgument> = call
+                .arguments()
+                .ok()?
+                .items()
+                .iter()
+                .collect::<SyntaxResult<_>>()
+                .ok()?;
+            let first = arguments.first()?;
+            let is_dot = first.name_clause().is_none()
+                && first
+                    .value()
+                    .is_some_and(|value| value.to_trimmed_string() == ".");
+            if !is_dot {
+                return None;
+            }
+
+            let dot_start: usize = first.syntax().text_trimmed_range().start().into();
+            let dot_end: usize = match arguments.get(1) {
+                Some(second) => second.syntax().text_trimmed_range().start().into(),
+                None => first.syntax().text_trimmed_range().end().into(),
+            };
+            // Don't remove comments between `.` and the next argument.
+            if source[dot_start..dot_end].contains('#') {
+                return None;
+            }
+            format!(
+                "{}{}",
+                &source[right_start..dot_start],
+                &source[dot_end..right_end]
+            )
+        }
+        _ => return None,
+    };
+
+    Some(Fix {
+        content: format!("|>{between}{new_right}"),
+        start,
+        end: right_end,
+        to_skip: false,
+    })
+}
+
+/// Report a native pipe, with a fix replacing it by `%>%` unless the
+/// placeholder `_` or `.` is used. `%>%` also passes the left side to the `.`
+/// of the right side, so `x |> f(y = .)` would become `f(y = x)`. The fix is
+/// unsafe since `%>%` is a function call, unlike `|>` which is resolved when
+/// the code is parsed.
+fn to_magrittr(operator: &RSyntaxToken, right: &AnyRExpression) -> Diagnostic {
+    let placeholder = right
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .map(|token| token.text_trimmed().to_string())
+        .find(|text| text == "_" || text == ".");
+
+    let range = operator.text_trimmed_range();
+    let (suggestion, fix) = match placeholder.as_deref() {
+        Some("_") => (
+            Some("The placeholder `_` has no equivalent with `%>%`, this must be rewritten manually.".to_string()),
+            Fix::empty(),
+        ),
+        Some(_) => (
+            Some("`.` would be replaced by the left side with `%>%`, this must be rewritten manually.".to_string()),
+            Fix::empty(),
+        ),
+        None => (
+            None,
+            Fix {
+                content: "%>%".to_string(),
+                start: range.start().into(),
+                end: range.end().into(),
+                to_skip: false,
+            },
+        ),
+    };
+
+    Diagnostic::new(
+        ViolationData::new(
+            "pipe_consistency".to_string(),
+            "Use the magrittr pipe `%>%` instead of `|>`.".to_string(),
+            suggestion,
+        ),
+        range,
+        fix,
+    )
+    .with_applicability(Applicability::MaybeIncorrect)
+}
@@ -0,0 +1,53 @@
+pub(crate) mod quotes;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_quotes() {
+        expect_no_lint("x <- \"a\"", "quotes", None);
+        expect_no_lint("x <- \"it's\"", "quotes", None);
+        expect_no_lint("x <- r'(a)'", "quotes", None);
+        expect_no_lint("x <- R'[it's]'", "quotes", None);
+        expect_no_lint("x <- r\"-(a)-\"", "quotes", None);
+        expect_no_lint("# x <- 'a'", "quotes", None);
+        expect_no_lint("`a b` <- 1", "quotes", None);
+        // Using double quotes would require escaping the inner quotes.
+        expect_no_lint("x <- 'He said \"hi\"'", "quotes", None);
+        expect_no_lint("x <- '\"'", "quotes", None);
+        expect_no_lint("x <- 'it\\'s \"a\" and \"b\"'", "quotes", None);
+    }
+
+    #[test]
+    fn test_lint_quotes() {
+        use insta::assert_snapshot;
+
+        let expected_message = "Only use double quotes for strings";
+        expect_lint("x <- 'a'", expected_message, "quotes", None);
+        expect_lint("x <- ''", expected_message, "quotes", None);
+        expect_lint("f('a', b = 'c')", expected_message, "quotes", None);
+        expect_lint("x <- 'it\\'s'", expected_message, "quotes", None);
+        expect_lint("x <- 'it\\'s \"a'", expected_message, "quotes", None);
+        expect_lint("x <- 'a\nb'", expected_message, "quotes", None);
+
+        assert_eq!(check_code("c('a', 'b', \"c\")", "quotes", None).len(), 2);
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "x <- 'a'",
+                    "x <- ''",
+                    "f('a', b = 'c')",
+                    "x <- 'it\\'s'",
+                    "x <- 'it\\'s \"a'",
+                    "x <- 'a\\\\'",
+                    "x <- 'a\\nb\\t\\\\\\''",
+                ],
+                "quotes",
+                None
+            )
+        );
+    }
+}
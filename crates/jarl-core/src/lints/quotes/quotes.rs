@@ -0,0 +1,122 @@
+use crate::diagnostic::*;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// ## What it does
+///
+/// Checks for strings that are not delimited by the preferred quote. By
+/// default, this is the double quote `"`, as recommended by the tidyverse
+/// style guide. This can be changed with the option `style` of
+/// `[lint.quotes]` in `jarl.toml`:
+///
+/// ```toml
+/// [lint.quotes]
+/// style = "single"
+/// ```
+///
+/// ## Why is this bad?
+///
+/// Single and double quotes are equivalent in R, but mixing them makes the
+/// code harder to read.
+///
+/// Strings that contain the preferred quote are not reported if changing
+/// their delimiters would require more escaped quotes than they currently
+/// have, e.g. `'He said "hi"'`. Raw strings, such as `r"(...)"`, are never
+/// reported.
+///
+/// This rule is disabled by default.
+///
+/// ## Example
+///
+/// ```r
+/// x <- 'hello'
+/// y <- 'it\'s'
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- "hello"
+/// y <- "it's"
+/// ```
+pub fn quotes(ast: &AnyRValue, preferred_quote: char) -> anyhow::Result<Option<Diagnostic>> {
+    let string = unwrap_or_return_none!(ast.as_r_string_value());
+    let token = string.value_token()?;
+    let text = token.text_trimmed();
+
+    let other_quote = if preferred_quote == '"' { '\'' } else { '"' };
+
+    // Raw strings start with `r` or `R`, so they are skipped here.
+    if !text.starts_with(other_quote) || text.len() < 2 {
+        return Ok(None);
+    }
+
+    let content = unwrap_or_return_none!(requote(
+        &text[1..text.len() - 1],
+        other_quote,
+        preferred_quote
+    ));
+
+    let (body, suggestion) = if preferred_quote == '"' {
+        ("Only use double quotes for strings.", "Use `\"` instead.")
+    } else {
+        ("Only use single quotes for strings.", "Use `'` instead.")
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "quotes".to_string(),
+            body.to_string(),
+            Some(suggestion.to_string()),
+        ),
+        range,
+        Fix {
+            content: format!("{preferred_quote}{content}{preferred_quote}"),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: false,
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Rewrite the content of a string delimited by `from` so that it can be
+/// delimited by `to`: `\from` doesn't need to be escaped anymore and `to`
+/// must be escaped. Other escape sequences are kept as is.
+///
+/// Return `None` if this requires more escaped quotes than the original
+/// content has.
+fn requote(content: &str, from: char, to: char) -> Option<String> {
+    let mut out = String::with_capacity(content.len());
+    let mut removed = 0;
+    let mut added = 0;
+
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some(next) if next == from => {
+                    out.push(from);
+                    removed += 1;
+                }
+                Some(next) => {
+                    out.push('\\');
+                    out.push(next);
+                }
+                None => out.push('\\'),
+            }
+        } else if c == to {
+            out.push('\\');
+            out.push(to);
+            added += 1;
+        } else {
+            out.push(c);
+        }
+    }
+
+    if added > removed {
+        return None;
+    }
+    Some(out)
+}
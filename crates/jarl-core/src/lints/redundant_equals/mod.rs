@@ -38,6 +38,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_redundant_equals_fix_content() {
+        let fix = |text: &str| {
+            let diagnostics = check_code(text, "redundant_equals", None);
+            assert_eq!(diagnostics.len(), 1);
+            diagnostics[0].fix.content.clone()
+        };
+
+        // Comparing to `TRUE` with `==`, or to `FALSE` with `!=`, keeps the
+        // value as is.
+        assert_eq!(fix("a == TRUE"), "a");
+        assert_eq!(fix("TRUE == a"), "a");
+        assert_eq!(fix("a != FALSE"), "a");
+        assert_eq!(fix("FALSE != a"), "a");
+
+        // The other comparisons negate the value.
+        assert_eq!(fix("a == FALSE"), "!a");
+        assert_eq!(fix("FALSE == a"), "!a");
+        assert_eq!(fix("a != TRUE"), "!a");
+        assert_eq!(fix("TRUE != a"), "!a");
+
+        // `!` has a lower precedence than arithmetic and comparison
+        // operators, so this negates the whole value.
+        assert_eq!(fix("x + 1 == FALSE"), "!x + 1");
+        assert_eq!(fix("(a | b) == FALSE"), "!(a | b)");
+    }
+
     #[test]
     fn test_no_lint_redundant_equals() {
         expect_no_lint("x == 1", "redundant_equals", None);
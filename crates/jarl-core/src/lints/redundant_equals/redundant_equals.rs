@@ -20,6 +20,9 @@ pub struct RedundantEquals;
 /// their logical nature, while `child`, `parent_supervision`,
 /// `watch_horror_movie` don't.
 ///
+/// Comparisons that negate the value, such as `x == FALSE` or `x != TRUE`,
+/// are replaced by `!x`.
+///
 /// ## Example
 ///
 /// ```r
@@ -7,18 +7,21 @@ mod tests {
     #[test]
     fn test_no_lint_redundant_ifelse() {
         // Normal ifelse calls with non-boolean constants
-        expect_no_lint("ifelse(x > 0, 1, 0)", "redundant_ifelse", None);
+        expect_no_lint("ifelse(x > 0, 2, 0)", "redundant_ifelse", None);
+        expect_no_lint("ifelse(x > 0, 1, 1)", "redundant_ifelse", None);
+        expect_no_lint("ifelse(x > 0, 1L, 0)", "redundant_ifelse", None);
+        expect_no_lint("ifelse(x > 0, 1, -1)", "redundant_ifelse", None);
         expect_no_lint("ifelse(x > 0, 'yes', 'no')", "redundant_ifelse", None);
         expect_no_lint("ifelse(x > 0, x, y)", "redundant_ifelse", None);
         expect_no_lint("ifelse(x > 0, TRUE, 0)", "redundant_ifelse", None);
         expect_no_lint("ifelse(x > 0, 1, FALSE)", "redundant_ifelse", None);
 
         // if_else with non-boolean constants
-        expect_no_lint("dplyr::if_else(x > 0, 1, 0)", "redundant_ifelse", None);
+        expect_no_lint("dplyr::if_else(x > 0, 2, 0)", "redundant_ifelse", None);
         expect_no_lint("if_else(x > 0, 'yes', 'no')", "redundant_ifelse", None);
 
         // fifelse with non-boolean constants
-        expect_no_lint("data.table::fifelse(x > 0, 1, 0)", "redundant_ifelse", None);
+        expect_no_lint("data.table::fifelse(x > 0, 0, 0)", "redundant_ifelse", None);
         expect_no_lint("fifelse(x > 0, x, y)", "redundant_ifelse", None);
 
         // Calls with more than 3 arguments (shouldn't be handled)
@@ -122,4 +125,46 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_redundant_ifelse_numeric() {
+        use insta::assert_snapshot;
+        let expected_message = "This `ifelse()` is redundant";
+
+        expect_lint(
+            "ifelse(x > 0, 1, 0)",
+            expected_message,
+            "redundant_ifelse",
+            None,
+        );
+        expect_lint(
+            "ifelse(x > 0, 0L, 1L)",
+            expected_message,
+            "redundant_ifelse",
+            None,
+        );
+        expect_lint(
+            "ifelse(no = 0, yes = 1, test = x > 0)",
+            expected_message,
+            "redundant_ifelse",
+            None,
+        );
+
+        assert_snapshot!(
+            "numeric",
+            get_fixed_text(
+                vec![
+                    "ifelse(x > 0, 1, 0)",
+                    "ifelse(x > 0, 0, 1)",
+                    "ifelse(x > 0, 1L, 0L)",
+                    "ifelse(x > 0, 0L, 1L)",
+                    "ifelse(no = 0, yes = 1, test = x > 0)",
+                    "dplyr::if_else(x > 0, 1, 0)",
+                    "data.table::fifelse(x > 0, 0L, 1L)",
+                ],
+                "redundant_ifelse",
+                None
+            )
+        );
+    }
 }
@@ -11,31 +11,40 @@ use biome_rowan::{AstNode, AstSeparatedList};
 ///
 /// ## Why is this bad?
 ///
-/// This rule looks for 4 cases:
+/// This rule looks for 6 cases:
 ///
 /// - `ifelse(condition, TRUE, FALSE)`
 /// - `ifelse(condition, FALSE, TRUE)`
 /// - `ifelse(condition, TRUE, TRUE)`
 /// - `ifelse(condition, FALSE, FALSE)`
+/// - `ifelse(condition, 1, 0)` (or `1L` and `0L`)
+/// - `ifelse(condition, 0, 1)` (or `0L` and `1L`)
 ///
 /// The first two cases can be simplified to `condition` and `!condition`
-/// respectively. The last two cases are very likely to be mistakes since the
-/// output is always the same.
+/// respectively. The next two cases are very likely to be mistakes since the
+/// output is always the same. The last two cases can be simplified to
+/// `as.numeric(condition)` and `as.numeric(!condition)` respectively (or
+/// `as.integer()` if the branches are integers, so that the type of the output
+/// doesn't change).
 ///
 /// This rule has a safe fix and doesn't handle calls to `dplyr::if_else()` and
-/// `data.table::fifelse()` when they have additional arguments.
+/// `data.table::fifelse()` when they have additional arguments. The fix is safe
+/// even if `condition` contains `NA`: `ifelse()` returns `NA` for those
+/// elements, and so do `!`, `as.numeric()`, and `as.integer()`.
 ///
 /// ## Example
 ///
 /// ```r
 /// ifelse(x %in% letters, TRUE, FALSE)
 /// dplyr::if_else(x > 1, FALSE, TRUE)
+/// ifelse(x > 1, 1L, 0L)
 /// ```
 ///
 /// Use instead:
 /// ```r
 /// x %in% letters
 /// !(x > 1) # (or `x <= 1`)
+/// as.integer(x > 1)
 /// ```
 pub fn redundant_ifelse(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
     let function = ast.function()?;
@@ -76,54 +85,51 @@ pub fn redundant_ifelse(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
     let arg_true = unwrap_or_return_none!(arg_true.value());
     let arg_false = unwrap_or_return_none!(arg_false.value());
 
-    let arg_true_is_true = arg_true.as_r_true_expression().is_some();
-    let arg_true_is_false = arg_true.as_r_false_expression().is_some();
-    let arg_false_is_true = arg_false.as_r_true_expression().is_some();
-    let arg_false_is_false = arg_false.as_r_false_expression().is_some();
-
-    if (!arg_true_is_true && !arg_true_is_false) || (!arg_false_is_true && !arg_false_is_false) {
-        return Ok(None);
-    }
+    let branch_true = unwrap_or_return_none!(Branch::from_expression(&arg_true));
+    let branch_false = unwrap_or_return_none!(Branch::from_expression(&arg_false));
 
     let range = ast.syntax().text_trimmed_range();
+    let fix_with = |content: String| Fix {
+        content,
+        start: range.start().into(),
+        end: range.end().into(),
+        to_skip: node_contains_comments(ast.syntax()),
+    };
+    let cond = arg_cond.to_trimmed_string();
+    let redundant_msg = format!("This `{}()` is redundant.", fn_name);
 
-    let (msg, suggestion, fix) = if arg_true_is_true && arg_false_is_false {
-        (
-            format!("This `{}()` is redundant.", fn_name),
+    let (msg, suggestion, fix) = match (branch_true, branch_false) {
+        (Branch::True, Branch::False) => (
+            redundant_msg,
             "Use `condition` directly.".to_string(),
-            Fix {
-                content: arg_cond.to_string(),
-                start: range.start().into(),
-                end: range.end().into(),
-                to_skip: node_contains_comments(ast.syntax()),
-            },
-        )
-    } else if arg_true_is_false && arg_false_is_true {
-        (
-            format!("This `{}()` is redundant.", fn_name),
+            fix_with(cond),
+        ),
+        (Branch::False, Branch::True) => (
+            redundant_msg,
             "Use `!condition` directly.".to_string(),
-            Fix {
-                content: format!("!({})", arg_cond),
-
-                start: range.start().into(),
-                end: range.end().into(),
-                to_skip: node_contains_comments(ast.syntax()),
-            },
-        )
-    } else if arg_true_is_true && arg_false_is_true {
-        (
+            fix_with(format!("!({cond})")),
+        ),
+        (Branch::True, Branch::True) => (
             format!("This `{}()` always evaluates to `TRUE`.", fn_name),
             "This is likely wrong.".to_string(),
             Fix::empty(),
-        )
-    } else if arg_true_is_false && arg_false_is_false {
-        (
+        ),
+        (Branch::False, Branch::False) => (
             format!("This `{}()` always evaluates to `FALSE`.", fn_name),
             "This is likely wrong.".to_string(),
             Fix::empty(),
-        )
-    } else {
-        unreachable!()
+        ),
+        (Branch::One(coercer), Branch::Zero(other)) if coercer == other => (
+            redundant_msg,
+            format!("Use `{coercer}(condition)` instead."),
+            fix_with(format!("{coercer}({cond})")),
+        ),
+        (Branch::Zero(coercer), Branch::One(other)) if coercer == other => (
+            redundant_msg,
+            format!("Use `{coercer}(!condition)` instead."),
+            fix_with(format!("{coercer}(!({cond}))")),
+        ),
+        _ => return Ok(None),
     };
 
     let diagnostic = Diagnostic::new(
@@ -134,3 +140,42 @@ pub fn redundant_ifelse(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
 
     Ok(Some(diagnostic))
 }
+
+/// Constant values of `yes` and `no` for which `ifelse()` is redundant.
+#[derive(Clone, Copy, PartialEq)]
+enum Branch {
+    True,
+    False,
+    /// `1` or `1L`, with the function that converts the condition to the same
+    /// type.
+    One(&'static str),
+    /// `0` or `0L`, with the function that converts the condition to the same
+    /// type.
+    Zero(&'static str),
+}
+
+impl Branch {
+    fn from_expression(expr: &AnyRExpression) -> Option<Self> {
+        if expr.as_r_true_expression().is_some() {
+            return Some(Branch::True);
+        }
+        if expr.as_r_false_expression().is_some() {
+            return Some(Branch::False);
+        }
+
+        let value = expr.as_any_r_value()?;
+        let (text, coercer) = if let Some(double) = value.as_r_double_value() {
+            (double.value_token().ok()?, "as.numeric")
+        } else if let Some(integer) = value.as_r_integer_value() {
+            (integer.value_token().ok()?, "as.integer")
+        } else {
+            return None;
+        };
+
+        match text.text_trimmed() {
+            "1" | "1L" => Some(Branch::One(coercer)),
+            "0" | "0L" => Some(Branch::Zero(coercer)),
+            _ => None,
+        }
+    }
+}
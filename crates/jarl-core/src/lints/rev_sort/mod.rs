@@ -0,0 +1,74 @@
+pub(crate) mod rev_sort;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_rev_sort() {
+        expect_no_lint("rev(x)", "rev_sort", None);
+        expect_no_lint("sort(x)", "rev_sort", None);
+        expect_no_lint("sort(rev(x))", "rev_sort", None);
+        expect_no_lint("rev(order(x))", "rev_sort", None);
+        expect_no_lint("rev(foo(sort(x)))", "rev_sort", None);
+        expect_no_lint("sort(x, decreasing = TRUE)", "rev_sort", None);
+        expect_no_lint("rev(sort())", "rev_sort", None);
+        expect_no_lint("rev()", "rev_sort", None);
+    }
+
+    #[test]
+    fn test_lint_rev_sort() {
+        use insta::assert_snapshot;
+
+        let expected_message = "`rev(sort(x))` is less readable";
+        expect_lint("rev(sort(x))", expected_message, "rev_sort", None);
+        expect_lint("rev(sort(foo(x)))", expected_message, "rev_sort", None);
+        expect_lint("rev(base::sort(x))", expected_message, "rev_sort", None);
+        expect_lint(
+            "rev(sort(x, na.last = TRUE))",
+            expected_message,
+            "rev_sort",
+            None,
+        );
+        expect_lint(
+            "rev(sort(x, decreasing = FALSE))",
+            expected_message,
+            "rev_sort",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "rev(sort(x))",
+                    "rev(sort(foo(x)))",
+                    "rev(base::sort(x))",
+                    "rev(sort(x, na.last = TRUE))",
+                    "rev(sort(x, method = \"radix\"))",
+                ],
+                "rev_sort",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_rev_sort_no_fix() {
+        use insta::assert_snapshot;
+        assert_snapshot!(
+            "no_fix",
+            get_fixed_text(
+                vec![
+                    "rev(sort(x, decreasing = FALSE))",
+                    "rev(sort(x, FALSE))",
+                    "rev(sort(x, method = \"quick\"))",
+                    "rev(sort(x, method = m))",
+                    "rev(# comment\n  sort(x))",
+                ],
+                "rev_sort",
+                None
+            )
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::diagnostic::*;
+use crate::utils::{
+    get_arg_by_name, get_arg_by_name_then_position, get_function_namespace_prefix,
+    get_nested_function_call, node_contains_comments,
+};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// ## What it does
+///
+/// Checks for usage of `rev(sort(x))`.
+///
+/// ## Why is this bad?
+///
+/// `sort()` has an argument `decreasing` to sort values in decreasing order.
+/// Using it is more readable than reversing the output of `sort()`, and it
+/// avoids creating an intermediate vector.
+///
+/// This rule has a safe fix. There is no fix if `sort()` already has an
+/// argument `decreasing` or uses `method = "quick"`, or if there are comments
+/// between the two calls.
+///
+/// ## Example
+///
+/// ```r
+/// x <- c(3, 2, 5, 1, 5, 6)
+/// rev(sort(x))
+/// rev(sort(x, na.last = TRUE))
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- c(3, 2, 5, 1, 5, 6)
+/// sort(x, decreasing = TRUE)
+/// sort(x, na.last = TRUE, decreasing = TRUE)
+/// ```
+///
+/// ## References
+///
+/// See `?sort`
+pub fn rev_sort(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    let inner_call = unwrap_or_return_none!(get_nested_function_call(ast, "rev", "sort")?);
+    let inner_args = inner_call.arguments()?.items();
+
+    // `rev(sort())` is an error anyway.
+    if inner_args.is_empty() {
+        return Ok(None);
+    }
+
+    let has_decreasing = get_arg_by_name_then_position(&inner_args, "decreasing", 2).is_some();
+    let has_unusual_method = get_arg_by_name(&inner_args, "method").is_some_and(|method| {
+        let method = method
+            .value()
+            .map(|value| value.syntax().text_trimmed().to_string())
+            .unwrap_or_default();
+        let method = method.replace(&['\'', '"'][..], "");
+        !["auto", "radix", "shell"].contains(&method.as_str())
+    });
+
+    let range = ast.syntax().text_trimmed_range();
+    let fix = if has_decreasing || has_unusual_method {
+        Fix::empty()
+    } else {
+        let namespace = get_function_namespace_prefix(inner_call.function()?).unwrap_or_default();
+        let inner_content = inner_args.clone().into_syntax().text_trimmed();
+        Fix {
+            content: format!("{namespace}sort({inner_content}, decreasing = TRUE)"),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        }
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "rev_sort".to_string(),
+            "`rev(sort(x))` is less readable than sorting in decreasing order.".to_string(),
+            Some("Use `sort(x, decreasing = TRUE)` instead.".to_string()),
+        ),
+        range,
+        fix,
+    );
+
+    Ok(Some(diagnostic))
+}
@@ -0,0 +1,75 @@
+pub(crate) mod sapply_type;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_sapply_type() {
+        expect_no_lint("vapply(x, f, numeric(1))", "sapply_type", None);
+        expect_no_lint("lapply(x, f)", "sapply_type", None);
+        expect_no_lint("sapply(x, f, simplify = FALSE)", "sapply_type", None);
+        expect_no_lint("sapply(x, f, USE.NAMES = FALSE)", "sapply_type", None);
+        expect_no_lint("sapply(x, length)", "sapply_type", None);
+        expect_no_lint("sapply(x, FUN = length)", "sapply_type", None);
+        expect_no_lint("sapply(x, list)", "sapply_type", None);
+        expect_no_lint("sapply(x, as.list)", "sapply_type", None);
+        expect_no_lint("sapply(x, function(i) list(i))", "sapply_type", None);
+        expect_no_lint(
+            "sapply(x, function(i) {\n  y <- i + 1\n  list(y)\n})",
+            "sapply_type",
+            None,
+        );
+        expect_no_lint("sapply(x, \\(i) base::list(i))", "sapply_type", None);
+        expect_no_lint("x$sapply(y, f)", "sapply_type", None);
+        expect_no_lint("sapply(x, f) # nolint: sapply_type", "sapply_type", None);
+    }
+
+    #[test]
+    fn test_lint_sapply_type() {
+        let expected_message = "The type of the output of `sapply()` depends on its input";
+        expect_lint("sapply(x, mean)", expected_message, "sapply_type", None);
+        expect_lint(
+            "base::sapply(x, mean)",
+            expected_message,
+            "sapply_type",
+            None,
+        );
+        expect_lint(
+            "sapply(x, FUN = f, y = 1)",
+            expected_message,
+            "sapply_type",
+            None,
+        );
+        expect_lint(
+            "sapply(x, function(i) i + 1)",
+            expected_message,
+            "sapply_type",
+            None,
+        );
+        expect_lint(
+            "sapply(x, \\(i) c(i, 1))",
+            "Use `vapply()` with an explicit `FUN.VALUE` instead",
+            "sapply_type",
+            None,
+        );
+        expect_lint(
+            "sapply(sapply(x, length), f)",
+            expected_message,
+            "sapply_type",
+            None,
+        );
+        assert_eq!(
+            check_code("sapply(sapply(x, length), f)", "sapply_type", None).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_sapply_type_and_lengths_no_double_report() {
+        assert_eq!(
+            check_code("sapply(x, length)", "sapply_type,lengths", None).len(),
+            1
+        );
+    }
+}
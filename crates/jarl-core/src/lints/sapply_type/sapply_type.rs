@@ -0,0 +1,102 @@
+use crate::diagnostic::*;
+use crate::lints::lengths::lengths::applies_length;
+use crate::utils::{get_arg_by_name, get_arg_by_name_then_position, get_function_name};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+pub struct SapplyType;
+
+/// ## What it does
+///
+/// Checks for calls to `sapply()` and recommends `vapply()` instead.
+///
+/// Calls that set `simplify` or `USE.NAMES` are not reported since they
+/// already state what they expect from `sapply()`. Calls whose function
+/// obviously returns a list, such as `sapply(x, function(i) list(i))`, are not
+/// reported either since `lapply()` is the better replacement there.
+/// `sapply(x, length)` is reported by the rule `lengths` instead.
+///
+/// This rule is disabled by default and doesn't have an automatic fix since
+/// the type of the output can't be known from the code.
+///
+/// ## Why is this bad?
+///
+/// The type of the output of `sapply()` depends on its input: it can be a
+/// vector, a matrix, or a list, and it is an empty list when `x` is empty.
+/// Code relying on it can therefore break on unusual inputs. `vapply()`
+/// checks that each result matches `FUN.VALUE` and always returns the same
+/// type.
+///
+/// ## Example
+///
+/// ```r
+/// sapply(x, mean)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// vapply(x, mean, FUN.VALUE = numeric(1))
+/// ```
+///
+/// ## References
+///
+/// See `?vapply`
+impl Violation for SapplyType {
+    fn name(&self) -> String {
+        "sapply_type".to_string()
+    }
+    fn body(&self) -> String {
+        "The type of the output of `sapply()` depends on its input.".to_string()
+    }
+    fn suggestion(&self) -> Option<String> {
+        Some("Use `vapply()` with an explicit `FUN.VALUE` instead.".to_string())
+    }
+}
+
+pub fn sapply_type(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    if get_function_name(ast.function()?) != "sapply" || applies_length(ast) {
+        return Ok(None);
+    }
+
+    let arguments = ast.arguments()?.items();
+    if get_arg_by_name(&arguments, "simplify").is_some()
+        || get_arg_by_name(&arguments, "USE.NAMES").is_some()
+    {
+        return Ok(None);
+    }
+
+    let arg_fun = unwrap_or_return_none!(get_arg_by_name_then_position(&arguments, "FUN", 2));
+    let arg_fun = unwrap_or_return_none!(arg_fun.value());
+    if returns_list(&arg_fun) {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(SapplyType, range, Fix::empty());
+
+    Ok(Some(diagnostic))
+}
+
+/// Does the function passed to `sapply()` obviously return a list, e.g. `list`
+/// or `function(i) list(i)`?
+fn returns_list(fun: &AnyRExpression) -> bool {
+    let list_functions = ["list", "as.list", "lapply", "Map"];
+
+    let body = match fun {
+        AnyRExpression::RIdentifier(_) | AnyRExpression::RNamespaceExpression(_) => {
+            return list_functions.contains(&get_function_name(fun.clone()).as_str());
+        }
+        AnyRExpression::RFunctionDefinition(definition) => definition.body(),
+        _ => return false,
+    };
+
+    // For `function(i) { ...; list(i) }`, look at the last expression.
+    let last = match body {
+        Ok(AnyRExpression::RBracedExpressions(braced)) => braced.expressions().into_iter().last(),
+        Ok(body) => Some(body),
+        Err(_) => None,
+    };
+    last.and_then(|last| last.as_r_call().cloned())
+        .and_then(|call| call.function().ok())
+        .is_some_and(|function| list_functions.contains(&get_function_name(function).as_str()))
+}
@@ -0,0 +1,93 @@
+pub(crate) mod scalar_in;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_scalar_in() {
+        expect_no_lint("x %in% y", "scalar_in", None);
+        expect_no_lint("x %in% c(1, 2)", "scalar_in", None);
+        expect_no_lint("x %in% c('a')", "scalar_in", None);
+        expect_no_lint("x %in% 1:2", "scalar_in", None);
+        expect_no_lint("x %in% foo(1)", "scalar_in", None);
+        expect_no_lint("x %in% NULL", "scalar_in", None);
+        expect_no_lint("x %in% NA", "scalar_in", None);
+        expect_no_lint("x %in% -1", "scalar_in", None);
+        expect_no_lint("1 %in% x", "scalar_in", None);
+        expect_no_lint("x %notin% 1", "scalar_in", None);
+        expect_no_lint("x == 1", "scalar_in", None);
+    }
+
+    #[test]
+    fn test_lint_scalar_in() {
+        use insta::assert_snapshot;
+
+        let expected_message = "Using `%in%` to compare with a single value";
+        expect_lint("x %in% 1", expected_message, "scalar_in", None);
+        expect_lint("x %in% 1L", expected_message, "scalar_in", None);
+        expect_lint("x %in% 'a'", expected_message, "scalar_in", None);
+        expect_lint("x %in% \"a\"", expected_message, "scalar_in", None);
+        expect_lint("x %in% TRUE", expected_message, "scalar_in", None);
+        expect_lint("foo(x) %in% 1", expected_message, "scalar_in", None);
+
+        assert_snapshot!(
+            "fix_output",
+            get_unsafe_fixed_text(
+                vec![
+                    "x %in% 1",
+                    "x%in%'a'",
+                    "foo(x)   %in%   1L",
+                    "y <- x %in% FALSE",
+                ],
+                "scalar_in",
+            )
+        );
+    }
+
+    #[test]
+    fn test_scalar_in_no_safe_fix() {
+        use insta::assert_snapshot;
+        // `NA %in% 1` is `FALSE` but `NA == 1` is `NA`.
+        assert_snapshot!(
+            "no_safe_fix",
+            get_fixed_text(vec!["x %in% 1", "NA %in% 1"], "scalar_in", None)
+        );
+    }
+
+    #[test]
+    fn test_scalar_in_operand_no_fix() {
+        use insta::assert_snapshot;
+
+        let expected_message = "Using `%in%` to compare with a single value";
+        expect_lint("a * x %in% 1", expected_message, "scalar_in", None);
+        expect_lint("x %in% 1 + 1", expected_message, "scalar_in", None);
+
+        // `==` has a lower precedence than `%in%`, so these would become
+        // `(a * x) == 1` and `x == (1 + 1)`.
+        assert_snapshot!(
+            "operand_no_fix",
+            get_unsafe_fixed_text(
+                vec![
+                    "a * x %in% 1",
+                    "x %in% 1 + 1",
+                    "x %in% 1 |> f()",
+                    "!x %in% 1",
+                ],
+                "scalar_in",
+            )
+        );
+    }
+
+    #[test]
+    fn test_scalar_in_with_comments_no_fix() {
+        use insta::assert_snapshot;
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_unsafe_fixed_text(
+                vec!["x %in% # a comment\n  1", "x %in% 1 # trailing comment",],
+                "scalar_in",
+            )
+        );
+    }
+}
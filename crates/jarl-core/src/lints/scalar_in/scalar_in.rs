@@ -0,0 +1,102 @@
+use crate::diagnostic::*;
+use crate::utils::node_contains_comments;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+pub struct ScalarIn;
+
+/// ## What it does
+///
+/// Checks for `x %in% y` where `y` is a single literal value, such as
+/// `x %in% 1` or `x %in% "a"`, and replaces `%in%` with `==`.
+///
+/// ## Why is this bad?
+///
+/// `%in%` is meant to check whether values belong to a set of several
+/// values. When there is a single value on the right side, `==` is clearer.
+///
+/// This rule has an unsafe fix because `NA %in% 1` is `FALSE` while
+/// `NA == 1` is `NA`. The fix is not applied when the expression is an
+/// operand of another operator, e.g. `a * x %in% 1`, since `==` has a lower
+/// precedence than `%in%` and the result would be `(a * x) == 1`.
+///
+/// ## Example
+///
+/// ```r
+/// x %in% 1
+/// x %in% "a"
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x == 1
+/// x == "a"
+/// ```
+impl Violation for ScalarIn {
+    fn name(&self) -> String {
+        "scalar_in".to_string()
+    }
+    fn body(&self) -> String {
+        "Using `%in%` to compare with a single value is unnecessary.".to_string()
+    }
+    fn suggestion(&self) -> Option<String> {
+        Some("Use `==` instead.".to_string())
+    }
+}
+
+pub fn scalar_in(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
+    let operator = ast.operator()?;
+    let right = ast.right()?;
+
+    if operator.kind() != RSyntaxKind::SPECIAL || operator.text_trimmed() != "%in%" {
+        return Ok(None);
+    }
+
+    // Only literal values: `c(...)`, other calls, and `NULL` (reported by
+    // `equals_null`) are skipped. `NA` is skipped too since `x == NA` is
+    // reported by `equals_na`.
+    let is_scalar = right.as_any_r_value().is_some()
+        || right.as_r_true_expression().is_some()
+        || right.as_r_false_expression().is_some();
+    if !is_scalar {
+        return Ok(None);
+    }
+
+    // Only replace the operator to keep the spacing around it.
+    let operator_range = operator.text_trimmed_range();
+    let is_operand = ast
+        .syntax()
+        .parent()
+        .is_some_and(|parent| is_operator(&parent));
+    let diagnostic = Diagnostic::new(
+        ScalarIn,
+        ast.syntax().text_trimmed_range(),
+        Fix {
+            content: "==".to_string(),
+            start: operator_range.start().into(),
+            end: operator_range.end().into(),
+            to_skip: is_operand || node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `node` is an operator other than an assignment. `==` has a lower
+/// precedence than `%in%` and than most operators, so replacing `%in%` in
+/// one of their operands would change how the code is parsed.
+fn is_operator(node: &RSyntaxNode) -> bool {
+    if let Some(binary) = RBinaryExpression::cast(node.clone()) {
+        return binary.operator().is_ok_and(|operator| {
+            !matches!(
+                operator.kind(),
+                RSyntaxKind::ASSIGN
+                    | RSyntaxKind::SUPER_ASSIGN
+                    | RSyntaxKind::ASSIGN_RIGHT
+                    | RSyntaxKind::SUPER_ASSIGN_RIGHT
+                    | RSyntaxKind::EQUAL
+            )
+        });
+    }
+    RUnaryExpression::can_cast(node.kind())
+}
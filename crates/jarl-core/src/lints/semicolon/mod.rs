@@ -0,0 +1,73 @@
+pub(crate) mod semicolon;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_semicolon() {
+        expect_no_lint("x <- 1\ny <- 2", "semicolon", None);
+        expect_no_lint("x <- ';'", "semicolon", None);
+        expect_no_lint("f('a;b')", "semicolon", None);
+        expect_no_lint("# x <- 1; y <- 2", "semicolon", None);
+        expect_no_lint("x <- 1 # y <- 2;", "semicolon", None);
+        expect_no_lint("# nolint: semicolon\nx <- 1;", "semicolon", None);
+    }
+
+    #[test]
+    fn test_lint_semicolon() {
+        use insta::assert_snapshot;
+
+        let separator_message = "Statements are separated by `;`";
+        let trailing_message = "Trailing `;` is not needed";
+        expect_lint("x <- 1; y <- 2", separator_message, "semicolon", None);
+        expect_lint(
+            "f <- function() { x; y }",
+            separator_message,
+            "semicolon",
+            None,
+        );
+        expect_lint("x <- ';'; y", separator_message, "semicolon", None);
+        expect_lint("x <- 1;", trailing_message, "semicolon", None);
+        expect_lint("x <- 1 ;\ny <- 2", trailing_message, "semicolon", None);
+        expect_lint("x <- 1; # comment", trailing_message, "semicolon", None);
+
+        assert_eq!(check_code("a; b; c;", "semicolon", None).len(), 3);
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "x <- 1; y <- 2",
+                    "x <- 1;",
+                    "x <- 1 ;\ny <- 2",
+                    "f <- function() {\n  x <- 1; y <- 2\n}",
+                    "a; b; c",
+                    "x <- ';'; y",
+                    "x <- 1; # comment",
+                ],
+                "semicolon",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_semicolon_no_fix_with_comment() {
+        // The comment could refer to any of the statements.
+        let code = "x <- 1; y <- 2 # comment";
+        let diagnostics = check_code(code, "semicolon", None);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].fix.to_skip);
+        assert_eq!(apply_fixes(code, "semicolon", false, None), code);
+    }
+
+    #[test]
+    fn test_semicolon_trailing_fix_is_safe() {
+        // Removing a trailing `;` is a fix even if it doesn't insert anything.
+        let diagnostics = check_code("x <- 1;", "semicolon", None);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].has_safe_fix());
+        assert!(!diagnostics[0].has_no_fix());
+    }
+}
@@ -0,0 +1,142 @@
+use crate::diagnostic::*;
+use crate::utils_ast::enclosing_statement;
+use air_r_syntax::*;
+use biome_rowan::{Direction, TextRange, TextSize};
+
+/// ## What it does
+///
+/// Checks for `;` used to separate statements on the same line or to
+/// terminate a statement.
+///
+/// ## Why is this bad?
+///
+/// Putting several statements on the same line makes the code harder to read
+/// and to debug, and a `;` at the end of a line is never needed in R.
+///
+/// This rule is disabled by default. Its fix puts each statement on its own
+/// line and removes the trailing `;`. The fix is not available when the line
+/// ends with a comment, since it isn't possible to know to which statement
+/// the comment refers.
+///
+/// ## Example
+///
+/// ```r
+/// x <- 1; y <- 2
+/// z <- 3;
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- 1
+/// y <- 2
+/// z <- 3
+/// ```
+pub fn semicolon(root: &RSyntaxNode) -> anyhow::Result<Vec<(RSyntaxNode, Diagnostic)>> {
+    let source = root.text().to_string();
+    let newline = if source.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+
+    // Semicolons can be tokens or trivia depending on where they are. Strings
+    // are single tokens and comments are skipped, so a `;` in them is never
+    // reported.
+    let mut semicolons: Vec<(usize, RSyntaxNode)> = vec![];
+    let mut comments: Vec<usize> = vec![];
+    for token in root.descendants_tokens(Direction::Next) {
+        let Some(parent) = token.parent() else {
+            continue;
+        };
+        if token.text_trimmed() == ";" {
+            semicolons.push((token.text_trimmed_range().start().into(), parent.clone()));
+        }
+        for piece in token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces())
+        {
+            let start: usize = piece.text_range().start().into();
+            if piece.is_comments() {
+                comments.push(start);
+                continue;
+            }
+            for (i, _) in piece.text().match_indices(';') {
+                semicolons.push((start + i, parent.clone()));
+            }
+        }
+    }
+    semicolons.sort_by_key(|(offset, _)| *offset);
+    semicolons.dedup_by_key(|(offset, _)| *offset);
+
+    let mut diagnostics = vec![];
+    for (offset, node) in semicolons {
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+        let before = &source[line_start..offset];
+        let after = &source[offset + 1..line_end];
+
+        let space_before = before.len() - before.trim_end_matches([' ', '\t']).len();
+        let space_after = after.len() - after.trim_start_matches([' ', '\t']).len();
+        let rest = after.trim();
+        let has_comment_after = comments.iter().any(|c| *c > offset && *c < line_end);
+
+        let leading = before.trim().is_empty();
+        let trailing = leading || rest.is_empty() || rest.starts_with('#');
+
+        let fix = if trailing {
+            // Remove the `;` and the spaces around it, but keep the
+            // indentation and the space before a comment.
+            let (start, end) = if leading {
+                (offset, offset + 1 + space_after)
+            } else if rest.is_empty() {
+                (offset - space_before, offset + 1 + space_after)
+            } else {
+                (offset - space_before, offset + 1)
+            };
+            Fix {
+                content: "".to_string(),
+                start,
+                end,
+                to_skip: false,
+            }
+        } else if has_comment_after {
+            Fix::empty()
+        } else {
+            let indentation = &before[..before.len() - before.trim_start().len()];
+            Fix {
+                content: format!("{newline}{indentation}"),
+                start: offset - space_before,
+                end: offset + 1 + space_after,
+                to_skip: false,
+            }
+        };
+
+        let (body, suggestion) = if trailing {
+            ("Trailing `;` is not needed.", "Remove it.")
+        } else {
+            (
+                "Statements are separated by `;`.",
+                "Put each statement on its own line.",
+            )
+        };
+
+        let range = TextRange::at(TextSize::from(offset as u32), TextSize::from(1));
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "semicolon".to_string(),
+                body.to_string(),
+                Some(suggestion.to_string()),
+            ),
+            range,
+            fix,
+        );
+
+        // Suppression comments apply to the whole statement.
+        diagnostics.push((enclosing_statement(&node), diagnostic));
+    }
+
+    Ok(diagnostics)
+}
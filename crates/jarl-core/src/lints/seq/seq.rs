@@ -1,7 +1,5 @@
-use crate::{
-    diagnostic::*,
-    utils::{get_function_name, node_contains_comments},
-};
+use crate::diagnostic::*;
+use crate::utils_ast::{LengthLikeCall, fix_replacing_node};
 use air_r_syntax::*;
 use biome_rowan::AstNode;
 
@@ -50,67 +48,25 @@ pub fn seq(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
     let left = ast.left()?;
     let right = ast.right()?;
 
-    let right_call = unwrap_or_return_none!(right.as_r_call());
-
     let left_is_literal_one = left.to_trimmed_text() == "1" || left.to_trimmed_text() == "1L";
 
     if !left_is_literal_one {
         return Ok(None);
     }
 
-    let right_fun = right_call.function()?;
-    let right_fun_name = get_function_name(right_fun);
-    if !["length", "nrow", "ncol", "NROW", "NCOL"].contains(&right_fun_name.as_str()) {
-        return Ok(None);
-    }
-
-    let right_fun_content = right_call
-        .arguments()?
-        .items()
-        .into_iter()
-        .map(|x| x.unwrap().to_trimmed_string())
-        .collect::<Vec<String>>()
-        .join(", ");
-
-    let (suggestion, replacement) = match right_fun_name.as_str() {
-        "length" => (
-            "seq_along(...)",
-            format!("seq_along({})", right_fun_content),
-        ),
-        "nrow" => (
-            "seq_len(nrow((...))",
-            format!("seq_len(nrow({}))", right_fun_content),
-        ),
-        "ncol" => (
-            "seq_len(ncol(...))",
-            format!("seq_len(ncol({}))", right_fun_content),
-        ),
-        "NROW" => (
-            "seq_len(NROW(...))",
-            format!("seq_len(NROW({}))", right_fun_content),
-        ),
-        "NCOL" => (
-            "seq_len(NCOL(...))",
-            format!("seq_len(NCOL({}))", right_fun_content),
-        ),
-        // We checked the choices of right_fun_name above.
-        _ => unreachable!(),
-    };
+    let length_call = unwrap_or_return_none!(LengthLikeCall::from_expression(&right));
 
-    let range = ast.syntax().text_trimmed_range();
     let diagnostic = Diagnostic::new(
         ViolationData::new(
             "seq".to_string(),
-            format!("`1:{right_fun_name}(...)` can be wrong if the RHS is 0.").to_string(),
-            Some(format!("Use `{suggestion}` instead.").to_string()),
+            format!(
+                "`1:{}(...)` can be wrong if the RHS is 0.",
+                length_call.function
+            ),
+            Some(format!("Use `{}` instead.", length_call.suggestion())),
         ),
-        range,
-        Fix {
-            content: replacement,
-            start: range.start().into(),
-            end: range.end().into(),
-            to_skip: node_contains_comments(ast.syntax()),
-        },
+        ast.syntax().text_trimmed_range(),
+        fix_replacing_node(ast.syntax(), length_call.replacement()),
     );
 
     Ok(Some(diagnostic))
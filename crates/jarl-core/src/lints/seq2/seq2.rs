@@ -1,7 +1,6 @@
-use crate::{
-    diagnostic::*,
-    utils::{get_function_name, node_contains_comments},
-};
+use crate::diagnostic::*;
+use crate::utils::get_function_name;
+use crate::utils_ast::{LengthLikeCall, fix_replacing_node};
 use air_r_syntax::*;
 use biome_rowan::{AstNode, AstSeparatedList};
 
@@ -61,63 +60,21 @@ pub fn seq2(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
             .find(|x| x.clone().unwrap().name_clause().is_none())
     );
 
-    let value = unnamed_arg?.value();
+    let value = unwrap_or_return_none!(unnamed_arg?.value());
+    let length_call = unwrap_or_return_none!(LengthLikeCall::from_expression(&value));
 
-    if let Some(inner) = value
-        && let Some(inner_call) = inner.as_r_call()
-    {
-        let RCallFields { function, arguments } = inner_call.as_fields();
-
-        let function = function?;
-        let inner_fn_name = get_function_name(function);
-
-        if !["length", "nrow", "ncol", "NROW", "NCOL"].contains(&inner_fn_name.as_str()) {
-            return Ok(None);
-        }
-
-        let inner_fun_content = arguments?.items().into_syntax().to_string();
-
-        let (suggestion, replacement) = match inner_fn_name.as_str() {
-            "length" => ("seq_along(...)", format!("seq_along({inner_fun_content})")),
-            "nrow" => (
-                "seq_len(nrow(...))",
-                format!("seq_len(nrow({inner_fun_content}))"),
-            ),
-            "ncol" => (
-                "seq_len(ncol(...))",
-                format!("seq_len(ncol({inner_fun_content}))"),
-            ),
-            "NROW" => (
-                "seq_len(NROW(...))",
-                format!("seq_len(NROW({inner_fun_content}))"),
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "seq2".to_string(),
+            format!(
+                "`seq({}(...))` can be wrong if the argument has length 0.",
+                length_call.function
             ),
-            "NCOL" => (
-                "seq_len(NCOL(...))",
-                format!("seq_len(NCOL({inner_fun_content}))"),
-            ),
-            // We checked the choices of inner_fn_name above.
-            _ => unreachable!(),
-        };
-
-        let range = ast.syntax().text_trimmed_range();
-        let diagnostic = Diagnostic::new(
-            ViolationData::new(
-                "seq2".to_string(),
-                format!("`seq({inner_fn_name}(...))` can be wrong if the argument has length 0.")
-                    .to_string(),
-                Some(format!("Use `{suggestion}` instead.").to_string()),
-            ),
-            range,
-            Fix {
-                content: replacement,
-                start: range.start().into(),
-                end: range.end().into(),
-                to_skip: node_contains_comments(ast.syntax()),
-            },
-        );
+            Some(format!("Use `{}` instead.", length_call.suggestion())),
+        ),
+        ast.syntax().text_trimmed_range(),
+        fix_replacing_node(ast.syntax(), length_call.replacement()),
+    );
 
-        Ok(Some(diagnostic))
-    } else {
-        Ok(None)
-    }
+    Ok(Some(diagnostic))
 }
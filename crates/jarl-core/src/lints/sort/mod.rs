@@ -16,6 +16,8 @@ mod tests {
         expect_no_lint("x[order(y)]", "sort", None);
         expect_no_lint("x[order(x, y)]", "sort", None);
         expect_no_lint("x[c(order(x))]", "sort", None);
+        expect_no_lint("x[order(x), drop = FALSE]", "sort", None);
+        expect_no_lint("df$x[order(df$y)]", "sort", None);
     }
 
     #[test]
@@ -54,6 +56,7 @@ mod tests {
             "sort",
             None,
         );
+        expect_lint("df$x[order(df$x)]", expected_message, "sort", None);
         assert_snapshot!(
             "fix_output",
             get_fixed_text(
@@ -64,6 +67,7 @@ mod tests {
                     "x[order(x, method = \"radix\")]",
                     "x[order(x, method = \"radix\", na.last = TRUE)]",
                     "x[order(method = \"radix\", na.last = TRUE, x)]",
+                    "df$x[order(df$x)]",
                 ],
                 "sort",
                 None
@@ -144,6 +144,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_no_lint_string_boundary_grepl() {
+        expect_no_lint("grepl('abc', x)", "string_boundary", None);
+        expect_no_lint("grepl('a^bc', x)", "string_boundary", None);
+        // regex pattern after the anchor
+        expect_no_lint("grepl('^a.c', x)", "string_boundary", None);
+        expect_no_lint("grepl('[a-z]$', x)", "string_boundary", None);
+        expect_no_lint("grepl('^a\\\\.b', x)", "string_boundary", None);
+        // this checks for equality
+        expect_no_lint("grepl('^abc$', x)", "string_boundary", None);
+        expect_no_lint("grepl('^', x)", "string_boundary", None);
+        // `^` and `$` are not anchors with `fixed = TRUE`
+        expect_no_lint("grepl('^abc', x, fixed = TRUE)", "string_boundary", None);
+        expect_no_lint("grepl('^abc', x, fixed = fixed)", "string_boundary", None);
+        // pattern is not a string literal
+        expect_no_lint("grepl(pattern, x)", "string_boundary", None);
+        expect_no_lint("grepl(paste0('^', y), x)", "string_boundary", None);
+        // missing `x`
+        expect_no_lint("grepl('^abc')", "string_boundary", None);
+        // other functions
+        expect_no_lint("grep('^abc', x)", "string_boundary", None);
+        expect_no_lint("sub('^abc', '', x)", "string_boundary", None);
+    }
+
+    #[test]
+    fn test_lint_string_boundary_grepl() {
+        use insta::assert_snapshot;
+
+        expect_lint(
+            "grepl('^abc', x)",
+            "Using `grepl()` to detect an initial substring",
+            "string_boundary",
+            None,
+        );
+        expect_lint(
+            "grepl('abc$', x)",
+            "Using `grepl()` to detect a terminal substring",
+            "string_boundary",
+            None,
+        );
+        expect_lint(
+            "grepl(x = x, pattern = '^abc')",
+            "Using `grepl()` to detect an initial substring",
+            "string_boundary",
+            None,
+        );
+        expect_lint(
+            "grepl('^abc', x, fixed = FALSE, perl = TRUE)",
+            "Using `grepl()` to detect an initial substring",
+            "string_boundary",
+            None,
+        );
+        // no fix since `startsWith()` is case-sensitive
+        expect_lint(
+            "grepl('^abc', x, ignore.case = TRUE)",
+            "Using `grepl()` to detect an initial substring",
+            "string_boundary",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output_grepl",
+            get_fixed_text(
+                vec![
+                    "grepl('^abc', x)",
+                    "grepl(\"abc$\", x)",
+                    "grepl(x = names(y), pattern = '^abc')",
+                    "grepl('^abc', x, fixed = FALSE, perl = TRUE)",
+                    "base::grepl('abc$', x)",
+                    "grepl(r\"(^a'b)\", x)",
+                    "grepl('^abc', x, ignore.case = TRUE)",
+                    "grepl('^abc', x, TRUE)",
+                ],
+                "string_boundary",
+                None
+            )
+        );
+    }
+
     #[test]
     fn test_string_boundary_with_comments_no_fix() {
         use insta::assert_snapshot;
@@ -161,6 +240,7 @@ mod tests {
                     "# leading comment\nsubstr(x, 1, 2) == 'ab'",
                     "substr(x, \n # a comment \n1, 2) == 'ab'",
                     "substr(x, 1, 2) == 'ab' # trailing comment",
+                    "grepl(\n # a comment \n'^abc', x)",
                 ],
                 "string_boundary",
                 None
@@ -1,5 +1,10 @@
 use crate::diagnostic::*;
-use crate::utils::{get_function_name, node_contains_comments};
+use crate::lints::fixed_regex::fixed_regex::is_fixed_pattern;
+use crate::utils::{
+    get_arg_by_name_then_position, get_function_name, get_function_namespace_prefix,
+    node_contains_comments,
+};
+use crate::utils_ast::string_literal_content;
 use air_r_syntax::*;
 use biome_rowan::AstNode;
 
@@ -8,28 +13,40 @@ use biome_rowan::AstNode;
 /// Checks for `substr()` and `substring()` calls that can be replaced with
 /// `startsWith()` or `endsWith()`.
 ///
+/// It also checks for `grepl()` calls whose pattern is a plain string anchored
+/// to the start (`^abc`) or to the end (`abc$`) of the string.
+///
 /// ## Why is this bad?
 ///
 /// Using `startsWith()` and `endsWith()` is both more readable and more efficient
 /// than extracting substrings and comparing them.
 ///
-/// This rule has a safe fix.
+/// This rule has a safe fix. There is no fix for `grepl()` calls with
+/// `ignore.case = TRUE` since `startsWith()` and `endsWith()` are
+/// case-sensitive.
+///
+/// Note that `grepl()` returns `FALSE` for missing values, while
+/// `startsWith()` and `endsWith()` return `NA`.
 ///
 /// ## Example
 ///
 /// ```r
 /// substr(x, 1L, 3L) == "abc"
 /// substring(x, nchar(x) - 2L, nchar(x)) == "xyz"
+/// grepl("^abc", x)
+/// grepl("xyz$", x)
 /// ```
 /// Use instead:
 /// ```r
 /// startsWith(x, "abc")
 /// endsWith(x, "xyz")
+/// startsWith(x, "abc")
+/// endsWith(x, "xyz")
 /// ```
 ///
 /// ## References
 ///
-/// See `?startsWith` and `?substr`
+/// See `?startsWith`, `?substr`, and `?grepl`
 pub fn string_boundary(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
     let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
 
@@ -146,6 +163,91 @@ pub fn string_boundary(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnos
     Ok(None)
 }
 
+/// Check for `grepl()` calls with a pattern like `"^abc"` or `"abc$"`.
+pub fn string_boundary_grepl(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    let function = ast.function()?;
+    let namespace = get_function_namespace_prefix(function.clone()).unwrap_or_default();
+    if get_function_name(function) != "grepl" {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+
+    // With `fixed = TRUE`, `^` and `$` are matched literally so the pattern
+    // is not anchored. If the value of `fixed` is unknown, we can't tell.
+    if let Some(fixed) = get_arg_by_name_then_position(&args, "fixed", 5)
+        && unwrap_or_return_none!(fixed.value())
+            .syntax()
+            .text_trimmed()
+            != "FALSE"
+    {
+        return Ok(None);
+    }
+
+    let pattern = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "pattern", 1));
+    let pattern = unwrap_or_return_none!(pattern.value());
+    let x = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "x", 2));
+    let x = unwrap_or_return_none!(x.value());
+
+    let pattern = unwrap_or_return_none!(pattern.as_any_r_value());
+    let pattern = unwrap_or_return_none!(pattern.as_r_string_value());
+    let pattern_token = pattern.value_token()?;
+    let content = unwrap_or_return_none!(string_literal_content(pattern));
+
+    let (replacement_fn, literal, msg) = if let Some(literal) = content.strip_prefix('^') {
+        ("startsWith", literal, "an initial")
+    } else if let Some(literal) = content.strip_suffix('$') {
+        ("endsWith", literal, "a terminal")
+    } else {
+        return Ok(None);
+    };
+
+    // `"^abc$"` checks for equality, and other patterns need a regex.
+    if literal.is_empty() || !is_fixed_pattern(literal) {
+        return Ok(None);
+    }
+
+    // Keep the quotes of the original string, except for raw strings.
+    let quote = match pattern_token.text_trimmed().chars().next() {
+        Some(q @ ('"' | '\'')) => q,
+        _ if !literal.contains('"') => '"',
+        _ if !literal.contains('\'') => '\'',
+        _ => return Ok(None),
+    };
+
+    // `startsWith()` and `endsWith()` are case-sensitive.
+    let ignore_case = get_arg_by_name_then_position(&args, "ignore.case", 3)
+        .and_then(|arg| arg.value())
+        .is_some_and(|value| value.syntax().text_trimmed() != "FALSE");
+
+    let range = ast.syntax().text_trimmed_range();
+    let fix = if ignore_case {
+        Fix::empty()
+    } else {
+        Fix {
+            content: format!(
+                "{namespace}{replacement_fn}({}, {quote}{literal}{quote})",
+                x.syntax().text_trimmed()
+            ),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        }
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "string_boundary".to_string(),
+            format!("Using `grepl()` to detect {msg} substring is hard to read and inefficient."),
+            Some(format!("Use `{replacement_fn}()` instead.")),
+        ),
+        range,
+        fix,
+    );
+
+    Ok(Some(diagnostic))
+}
+
 /// Check if an expression is the literal value 1 or 1L
 fn is_literal_one(expr: &AnyRExpression) -> bool {
     // Check if it's an AnyRValue (numeric literal)
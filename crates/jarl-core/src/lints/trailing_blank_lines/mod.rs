@@ -0,0 +1,76 @@
+pub(crate) mod trailing_blank_lines;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_trailing_blank_lines() {
+        expect_no_lint("x <- 1\n", "trailing_blank_lines", None);
+        expect_no_lint("x <- 1\r\n", "trailing_blank_lines", None);
+        expect_no_lint("x <- 1\n\ny <- 2\n", "trailing_blank_lines", None);
+        expect_no_lint("x <- 'a\n\n'\n", "trailing_blank_lines", None);
+        expect_no_lint("", "trailing_blank_lines", None);
+        expect_no_lint("\n", "trailing_blank_lines", None);
+    }
+
+    #[test]
+    fn test_lint_trailing_blank_lines() {
+        let blank_lines_message = "The file ends with blank lines";
+        let no_newline_message = "The file doesn't end with a newline";
+        expect_lint(
+            "x <- 1\n\n",
+            blank_lines_message,
+            "trailing_blank_lines",
+            None,
+        );
+        expect_lint(
+            "x <- 1\n\n\n",
+            blank_lines_message,
+            "trailing_blank_lines",
+            None,
+        );
+        expect_lint(
+            "x <- 1\r\n\r\n",
+            blank_lines_message,
+            "trailing_blank_lines",
+            None,
+        );
+        expect_lint(
+            "x <- 1\n  \n",
+            blank_lines_message,
+            "trailing_blank_lines",
+            None,
+        );
+        expect_lint("x <- 1", no_newline_message, "trailing_blank_lines", None);
+        expect_lint(
+            "x <- 1\n# comment",
+            no_newline_message,
+            "trailing_blank_lines",
+            None,
+        );
+        expect_lint(
+            "x <- 1\n\n  ",
+            blank_lines_message,
+            "trailing_blank_lines",
+            None,
+        );
+
+        assert_eq!(
+            check_code("x <- 1\n\n\n\n", "trailing_blank_lines", None).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_fix_trailing_blank_lines() {
+        let fix = |code: &str| apply_fixes(code, "trailing_blank_lines", false, None);
+        assert_eq!(fix("x <- 1\n\n\n"), "x <- 1\n");
+        assert_eq!(fix("x <- 1\r\n\r\n\r\n"), "x <- 1\r\n");
+        assert_eq!(fix("x <- 1\n \t\n\n"), "x <- 1\n");
+        assert_eq!(fix("x <- 1"), "x <- 1\n");
+        assert_eq!(fix("x <- 1\r\ny <- 2"), "x <- 1\r\ny <- 2\r\n");
+        assert_eq!(fix("x <- 1\n\n  "), "x <- 1\n");
+        assert_eq!(fix("x <- 1 # comment  \n\n"), "x <- 1 # comment  \n");
+    }
+}
@@ -0,0 +1,87 @@
+use crate::diagnostic::*;
+use crate::utils_ast::statement_at_offset;
+use air_r_syntax::*;
+use biome_rowan::{TextRange, TextSize};
+
+/// ## What it does
+///
+/// Checks that files end with exactly one newline, i.e. that they have no
+/// blank lines at the end and that their last line ends with a newline.
+///
+/// ## Why is this bad?
+///
+/// Blank lines at the end of a file are useless. A missing newline at the end
+/// of the last line is reported by many tools, such as `git diff`, and makes
+/// the last line change when code is added after it.
+///
+/// This rule is disabled by default. Its fix ensures that the file ends with a
+/// single newline.
+///
+/// ## Example
+///
+/// ```r
+/// x <- 1
+///
+///
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- 1
+/// ```
+pub fn trailing_blank_lines(root: &RSyntaxNode) -> anyhow::Result<Vec<(RSyntaxNode, Diagnostic)>> {
+    let source = root.text().to_string();
+    if source.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    let newline = if source.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+
+    let end = TextSize::from(source.len() as u32);
+    let content_end = source.trim_end().len();
+    let (body, range, fix) = match source[content_end..].find('\n') {
+        None => {
+            // Point at the last line.
+            let last_line = source.rfind('\n').map_or(0, |i| i + 1);
+            let range = TextRange::new(TextSize::from(last_line as u32), end);
+            let fix = Fix {
+                content: newline.to_string(),
+                start: source.len(),
+                end: source.len(),
+                to_skip: false,
+            };
+            ("The file doesn't end with a newline.", range, fix)
+        }
+        Some(i) => {
+            // The blank lines start after the line break of the last line
+            // that isn't blank.
+            let blank_start = content_end + i + 1;
+            if blank_start == source.len() {
+                return Ok(vec![]);
+            }
+            let range = TextRange::new(TextSize::from(blank_start as u32), end);
+            let fix = Fix {
+                content: "".to_string(),
+                start: blank_start,
+                end: source.len(),
+                to_skip: false,
+            };
+            ("The file ends with blank lines.", range, fix)
+        }
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "trailing_blank_lines".to_string(),
+            body.to_string(),
+            Some("End the file with a single newline.".to_string()),
+        ),
+        range,
+        fix,
+    );
+
+    Ok(vec![(statement_at_offset(root, range.start()), diagnostic)])
+}
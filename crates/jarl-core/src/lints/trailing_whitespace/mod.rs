@@ -0,0 +1,71 @@
+pub(crate) mod trailing_whitespace;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_trailing_whitespace() {
+        expect_no_lint("x <- 1", "trailing_whitespace", None);
+        expect_no_lint("x <- 1\n\ny <- 2\n", "trailing_whitespace", None);
+        expect_no_lint("x <- 1\r\ny <- 2\r\n", "trailing_whitespace", None);
+        expect_no_lint("  x <- 1", "trailing_whitespace", None);
+        expect_no_lint("x <- 'a  \nb'", "trailing_whitespace", None);
+        expect_no_lint("x <- \"a\t\r\nb\"\r\n", "trailing_whitespace", None);
+        expect_no_lint(
+            "# nolint: trailing_whitespace\nx <- 1  ",
+            "trailing_whitespace",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_trailing_whitespace() {
+        let expected_message = "Trailing whitespace is superfluous";
+        expect_lint("x <- 1 ", expected_message, "trailing_whitespace", None);
+        expect_lint("x <- 1\t\n", expected_message, "trailing_whitespace", None);
+        expect_lint(
+            "x <- 1  \r\n",
+            expected_message,
+            "trailing_whitespace",
+            None,
+        );
+        expect_lint(
+            "x <- 1\n  \ny <- 2",
+            expected_message,
+            "trailing_whitespace",
+            None,
+        );
+        expect_lint(
+            "x <- 1 # comment ",
+            expected_message,
+            "trailing_whitespace",
+            None,
+        );
+        expect_lint(
+            "x <- 'a\nb' ",
+            expected_message,
+            "trailing_whitespace",
+            None,
+        );
+
+        assert_eq!(
+            check_code("a \nb\t\nc", "trailing_whitespace", None).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_fix_trailing_whitespace() {
+        let fix = |code: &str| apply_fixes(code, "trailing_whitespace", false, None);
+        assert_eq!(fix("x <- 1  "), "x <- 1");
+        assert_eq!(fix("x <- 1 \t\ny <- 2\t\n"), "x <- 1\ny <- 2\n");
+        assert_eq!(fix("x <- 1  \r\ny <- 2 \r\n"), "x <- 1\r\ny <- 2\r\n");
+        assert_eq!(
+            fix("f <- function() {\n  \n  1 \n}"),
+            "f <- function() {\n\n  1\n}"
+        );
+        assert_eq!(fix("x <- 'a  \nb'  \n"), "x <- 'a  \nb'\n");
+        assert_eq!(fix("x <- 'é' \ny <- 'ü' \n"), "x <- 'é'\ny <- 'ü'\n");
+    }
+}
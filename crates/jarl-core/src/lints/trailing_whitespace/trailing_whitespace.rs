@@ -0,0 +1,88 @@
+use crate::diagnostic::*;
+use crate::utils_ast::statement_at_offset;
+use air_r_syntax::*;
+use biome_rowan::{AstNode, TextRange, TextSize};
+
+/// ## What it does
+///
+/// Checks for spaces and tabs at the end of lines.
+///
+/// ## Why is this bad?
+///
+/// Trailing whitespace is invisible in most editors and adds noise to diffs
+/// when it is removed or added by mistake.
+///
+/// Whitespace at the end of a line inside a multi-line string is part of the
+/// string and is never reported.
+///
+/// This rule is disabled by default. Its fix removes the trailing
+/// whitespace.
+///
+/// ## Example
+///
+/// Where `␣` is a space:
+///
+/// ```r
+/// x <- 1␣␣
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- 1
+/// ```
+pub fn trailing_whitespace(root: &RSyntaxNode) -> anyhow::Result<Vec<(RSyntaxNode, Diagnostic)>> {
+    let source = root.text().to_string();
+
+    // Ranges of strings spanning several lines, whose content must not be
+    // changed.
+    let multiline_strings: Vec<TextRange> = root
+        .descendants()
+        .filter_map(RStringValue::cast)
+        .map(|string| string.syntax().text_trimmed_range())
+        .filter(|range| source[usize::from(range.start())..usize::from(range.end())].contains('\n'))
+        .collect();
+
+    let mut diagnostics = vec![];
+    let mut line_start = 0;
+    for line in source.split_inclusive('\n') {
+        let start = line_start;
+        line_start += line.len();
+
+        // Keep the `\r` of CRLF line endings.
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let trimmed = line.trim_end_matches([' ', '\t']);
+        if trimmed.len() == line.len() {
+            continue;
+        }
+
+        let range = TextRange::new(
+            TextSize::from((start + trimmed.len()) as u32),
+            TextSize::from((start + line.len()) as u32),
+        );
+        if multiline_strings
+            .iter()
+            .any(|string| string.contains_range(range))
+        {
+            continue;
+        }
+
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "trailing_whitespace".to_string(),
+                "Trailing whitespace is superfluous.".to_string(),
+                Some("Remove it.".to_string()),
+            ),
+            range,
+            Fix {
+                content: "".to_string(),
+                start: range.start().into(),
+                end: range.end().into(),
+                to_skip: false,
+            },
+        );
+        diagnostics.push((statement_at_offset(root, range.start()), diagnostic));
+    }
+
+    Ok(diagnostics)
+}
@@ -4,23 +4,66 @@ pub(crate) mod true_false_symbol;
 mod tests {
     use crate::utils_test::*;
 
-    // TODO: I guess this should only be linted if --unsafe-fixes is passed?
-    // #[test]
-    // fn test_lint_true_false_symbol() {
-    //     let expected_message = "can be confused with variable names";
-    //     expect_lint("T", expected_message, "true_false_symbol", None);
-    //     expect_lint("F", expected_message, "true_false_symbol", None);
-    //     expect_lint("T = 42", expected_message, "true_false_symbol", None);
-    //     expect_lint("F = 42", expected_message, "true_false_symbol", None);
-    //     expect_lint(
-    //         "for (i in 1:10) {x <- c(T, TRUE, F, FALSE)}",
-    //         expected_message,
-    //         "true_false_symbol", None,
-    //     );
-    //     expect_lint("DF$bool <- T", expected_message, "true_false_symbol", None);
-    //     expect_lint("S4@bool <- T", expected_message, "true_false_symbol", None);
-    //     expect_lint("sum(x, na.rm = T)", expected_message, "true_false_symbol", None);
-    // }
+    #[test]
+    fn test_lint_true_false_symbol() {
+        use insta::assert_snapshot;
+
+        let expected_message = "can be confused with variable names";
+        expect_lint("T", expected_message, "true_false_symbol", None);
+        expect_lint("F", expected_message, "true_false_symbol", None);
+        expect_lint("T = 42", expected_message, "true_false_symbol", None);
+        expect_lint("F <- 42", expected_message, "true_false_symbol", None);
+        expect_lint(
+            "for (i in 1:10) {x <- c(T, TRUE, F, FALSE)}",
+            expected_message,
+            "true_false_symbol",
+            None,
+        );
+        expect_lint("DF$bool <- T", expected_message, "true_false_symbol", None);
+        expect_lint("S4@bool <- T", expected_message, "true_false_symbol", None);
+        expect_lint(
+            "sum(x, na.rm = T)",
+            expected_message,
+            "true_false_symbol",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "sum(x, na.rm = T)",
+                    "x <- F",
+                    "c(T, TRUE, F, FALSE)",
+                    "if (T) 1",
+                    "T()",
+                    "mtcars$T",
+                ],
+                "true_false_symbol",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_true_false_symbol_assigned_no_fix() {
+        use insta::assert_snapshot;
+
+        assert_snapshot!(
+            "no_fix_assigned",
+            get_fixed_text(
+                vec![
+                    "T <- 1",
+                    "F = 1",
+                    "1 -> T",
+                    "T <<- 1",
+                    "for (T in x) print(1)"
+                ],
+                "true_false_symbol",
+                None
+            )
+        );
+    }
 
     #[test]
     fn test_no_lint_true_false_symbol() {
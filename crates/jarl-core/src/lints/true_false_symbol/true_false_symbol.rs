@@ -11,6 +11,9 @@ pub struct TrueFalseSymbol;
 /// something else, such as an object or a variable name, then no automatic
 /// fixes are applied.
 ///
+/// This rule has a safe automatic fix. It is not applied when `T` or `F` is
+/// assigned to (e.g. `T <- 1`) or is the variable of a `for` loop.
+///
 /// ## Why is this bad?
 ///
 /// `T` and `F` are not reserved symbols (like `break`) and therefore can be
@@ -72,6 +75,13 @@ pub fn true_false_symbol(ast: &RIdentifier) -> anyhow::Result<Option<Diagnostic>
     }
 
     let range = ast.syntax().text_trimmed_range();
+
+    // `T <- 1` or `for (T in x)` use `T` as a name, so replacing it by `TRUE`
+    // would give invalid code.
+    if is_assigned(ast) {
+        return Ok(Some(Diagnostic::new(TrueFalseSymbol, range, Fix::empty())));
+    }
+
     let diagnostic = Diagnostic::new(
         TrueFalseSymbol,
         range,
@@ -89,3 +99,28 @@ pub fn true_false_symbol(ast: &RIdentifier) -> anyhow::Result<Option<Diagnostic>
 
     Ok(Some(diagnostic))
 }
+
+fn is_assigned(ast: &RIdentifier) -> bool {
+    if let Some(for_loop) = ast.parent::<RForStatement>() {
+        return for_loop
+            .variable()
+            .is_ok_and(|variable| variable.syntax() == ast.syntax());
+    }
+
+    let Some(binary) = ast.parent::<RBinaryExpression>() else {
+        return false;
+    };
+    let (Ok(operator), Ok(left), Ok(right)) = (binary.operator(), binary.left(), binary.right())
+    else {
+        return false;
+    };
+    match operator.kind() {
+        RSyntaxKind::ASSIGN | RSyntaxKind::SUPER_ASSIGN | RSyntaxKind::EQUAL => {
+            left.syntax() == ast.syntax()
+        }
+        RSyntaxKind::ASSIGN_RIGHT | RSyntaxKind::SUPER_ASSIGN_RIGHT => {
+            right.syntax() == ast.syntax()
+        }
+        _ => false,
+    }
+}
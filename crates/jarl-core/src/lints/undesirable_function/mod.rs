@@ -0,0 +1,56 @@
+pub(crate) mod undesirable_function;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_undesirable_function() {
+        expect_no_lint("vapply(x, f, numeric(1))", "undesirable_function", None);
+        expect_no_lint("lapply(x, f)", "undesirable_function", None);
+        expect_no_lint("getwd()", "undesirable_function", None);
+        expect_no_lint("x$setwd('a')", "undesirable_function", None);
+        expect_no_lint("setwd <- 1", "undesirable_function", None);
+        expect_no_lint("'setwd'", "undesirable_function", None);
+    }
+
+    #[test]
+    fn test_lint_undesirable_function() {
+        expect_lint(
+            "attach(df)",
+            "`attach()` is undesirable",
+            "undesirable_function",
+            None,
+        );
+        expect_lint(
+            "sapply(x, f)",
+            "`sapply()` is undesirable",
+            "undesirable_function",
+            None,
+        );
+        expect_lint(
+            "setwd('data')",
+            "`setwd()` is undesirable",
+            "undesirable_function",
+            None,
+        );
+        expect_lint(
+            "source('a.R')",
+            "`source()` is undesirable",
+            "undesirable_function",
+            None,
+        );
+        expect_lint(
+            "base::setwd('data')",
+            "`setwd()` is undesirable",
+            "undesirable_function",
+            None,
+        );
+        expect_lint(
+            "setwd('data')",
+            "instead of changing the working directory",
+            "undesirable_function",
+            None,
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::diagnostic::*;
+use crate::utils::get_function_name;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+use std::collections::HashMap;
+
+/// ## What it does
+///
+/// Checks for calls to functions that should be avoided in the project. By
+/// default, those are:
+///
+/// - `attach()`, because it modifies the search path;
+/// - `sapply()`, because the type of its output is unpredictable;
+/// - `setwd()`, because changing the working directory makes code depend on
+///   the location of files on a specific computer;
+/// - `source()`, because it makes the dependencies between scripts implicit.
+///
+/// This list can be replaced with `[lint.undesirable_function]` in
+/// `jarl.toml`, which maps function names to the message to display:
+///
+/// ```toml
+/// [lint]
+/// extend-select = ["undesirable_function"]
+///
+/// [lint.undesirable_function]
+/// setwd = "Use paths relative to the project instead."
+/// library = ""
+/// ```
+///
+/// Calls with a namespace, such as `base::setwd()`, are also reported.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// Some functions are valid R code but make scripts harder to reuse or to
+/// reason about. Projects may also have their own reasons to forbid some
+/// functions.
+///
+/// ## Example
+///
+/// ```r
+/// setwd("data")
+/// x <- read.csv("raw.csv")
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- read.csv(file.path("data", "raw.csv"))
+/// ```
+pub fn undesirable_function(
+    ast: &RCall,
+    functions: &HashMap<String, String>,
+) -> anyhow::Result<Option<Diagnostic>> {
+    let function_name = get_function_name(ast.function()?);
+    let message = unwrap_or_return_none!(functions.get(&function_name));
+
+    let suggestion = if message.is_empty() {
+        None
+    } else {
+        Some(message.clone())
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "undesirable_function".to_string(),
+            format!("`{function_name}()` is undesirable."),
+            suggestion,
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
@@ -0,0 +1,49 @@
+pub(crate) mod undesirable_operator;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_undesirable_operator() {
+        expect_no_lint("pkg::f(x)", "undesirable_operator", None);
+        expect_no_lint("x <<- 1", "undesirable_operator", None);
+        expect_no_lint("`:::`(pkg, f)", "undesirable_operator", None);
+        expect_no_lint("x <- ':::'", "undesirable_operator", None);
+        expect_no_lint("# pkg:::f()", "undesirable_operator", None);
+        expect_no_lint(
+            "# nolint: undesirable_operator\npkg:::f(x)",
+            "undesirable_operator",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_undesirable_operator() {
+        expect_lint(
+            "pkg:::f(x)",
+            "`:::` is undesirable",
+            "undesirable_operator",
+            None,
+        );
+        expect_lint(
+            "x <- pkg:::f",
+            "`:::` is undesirable",
+            "undesirable_operator",
+            None,
+        );
+        expect_lint(
+            "pkg:::f(x)",
+            "use exported functions instead",
+            "undesirable_operator",
+            None,
+        );
+        expect_lint(
+            "function() {\n  g(pkg:::f)\n}",
+            "`:::` is undesirable",
+            "undesirable_operator",
+            None,
+        );
+        expect_diagnostic_highlight("pkg:::f(x)", "undesirable_operator", ":::");
+    }
+}
@@ -0,0 +1,87 @@
+use crate::diagnostic::*;
+use crate::utils_ast::enclosing_statement;
+use air_r_syntax::*;
+use biome_rowan::{AstNode, Direction};
+use std::collections::HashMap;
+
+/// ## What it does
+///
+/// Checks for operators that should be avoided in the project. By default,
+/// this is only `:::`, which accesses functions that a package doesn't
+/// export.
+///
+/// This list can be replaced with `[lint.undesirable_operator]` in
+/// `jarl.toml`, which maps operators to the message to display:
+///
+/// ```toml
+/// [lint]
+/// extend-select = ["undesirable_operator"]
+///
+/// [lint.undesirable_operator]
+/// ":::" = "Use exported functions instead."
+/// "<<-" = ""
+/// ```
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// Internal functions of a package are not part of its interface: they can
+/// change or be removed in any new version of the package without notice, so
+/// code relying on them can break at any time. Projects may also have their
+/// own reasons to forbid other operators, such as `<<-`.
+///
+/// ## Example
+///
+/// ```r
+/// pkg:::internal_helper(x)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// pkg::exported_function(x)
+/// ```
+pub fn undesirable_operator(
+    root: &RSyntaxNode,
+    operators: &HashMap<String, String>,
+) -> anyhow::Result<Vec<(RSyntaxNode, Diagnostic)>> {
+    let mut diagnostics = vec![];
+
+    for token in root.descendants_tokens(Direction::Next) {
+        let operator = token.text_trimmed();
+        let Some(message) = operators.get(operator) else {
+            continue;
+        };
+
+        // Only consider operators, not e.g. `=` for arguments in calls.
+        let Some(parent) = token.parent() else {
+            continue;
+        };
+        let is_operator = RBinaryExpression::can_cast(parent.kind())
+            || RNamespaceExpression::can_cast(parent.kind())
+            || RExtractExpression::can_cast(parent.kind())
+            || RUnaryExpression::can_cast(parent.kind());
+        if !is_operator {
+            continue;
+        }
+
+        let suggestion = if message.is_empty() {
+            None
+        } else {
+            Some(message.clone())
+        };
+
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "undesirable_operator".to_string(),
+                format!("`{operator}` is undesirable."),
+                suggestion,
+            ),
+            token.text_trimmed_range(),
+            Fix::empty(),
+        );
+        diagnostics.push((enclosing_statement(&parent), diagnostic));
+    }
+
+    Ok(diagnostics)
+}
@@ -0,0 +1,33 @@
+pub(crate) mod unknown_nolint_rule;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_unknown_nolint_rule() {
+        expect_no_lint("x = 1 # nolint: assignment", "unknown_nolint_rule", None);
+        expect_no_lint(
+            "# nolint start: any_is_na\nx <- 1\n# nolint end",
+            "unknown_nolint_rule",
+            None,
+        );
+        expect_no_lint("x = 1 # nolint", "unknown_nolint_rule", None);
+    }
+
+    #[test]
+    fn test_lint_unknown_nolint_rule() {
+        expect_lint(
+            "x = 1 # nolint: assignmnet",
+            "Unknown rule in nolint directive: `assignmnet`.",
+            "unknown_nolint_rule",
+            None,
+        );
+        expect_lint(
+            "# nolint start: foo, any_is_na\nx <- 1\n# nolint end",
+            "Unknown rule in nolint directive: `foo`.",
+            "unknown_nolint_rule",
+            None,
+        );
+    }
+}
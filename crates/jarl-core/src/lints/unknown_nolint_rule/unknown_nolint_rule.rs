@@ -0,0 +1,37 @@
+use crate::diagnostic::*;
+use biome_rowan::TextRange;
+
+/// ## What it does
+///
+/// Checks for rule names in `# nolint` directives that don't match any rule.
+///
+/// ## Why is this bad?
+///
+/// A directive listing a rule that doesn't exist, e.g. because of a typo,
+/// doesn't suppress anything, so the violations it was meant to hide are still
+/// reported. This is checked in all directives, even if the rule they are
+/// meant to suppress wouldn't report anything.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// x = 1 # nolint: assignmnet
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x = 1 # nolint: assignment
+/// ```
+pub fn unknown_nolint_rule(name: &str, range: TextRange) -> Diagnostic {
+    Diagnostic::new(
+        ViolationData::new(
+            "unknown_nolint_rule".to_string(),
+            format!("Unknown rule in nolint directive: `{name}`."),
+            None,
+        ),
+        range,
+        Fix::empty(),
+    )
+}
@@ -0,0 +1,94 @@
+pub(crate) mod unnecessary_concatenation;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_unnecessary_concatenation() {
+        expect_no_lint("c(x)", "unnecessary_concatenation", None);
+        expect_no_lint("c(foo(x))", "unnecessary_concatenation", None);
+        expect_no_lint("c(-1)", "unnecessary_concatenation", None);
+        expect_no_lint("c(1, 2)", "unnecessary_concatenation", None);
+        expect_no_lint("c(a = 1)", "unnecessary_concatenation", None);
+        expect_no_lint("c(...)", "unnecessary_concatenation", None);
+        expect_no_lint("x |> c()", "unnecessary_concatenation", None);
+        expect_no_lint("x %>% c()", "unnecessary_concatenation", None);
+        expect_no_lint("cc(1)", "unnecessary_concatenation", None);
+    }
+
+    #[test]
+    fn test_lint_unnecessary_concatenation() {
+        use insta::assert_snapshot;
+
+        expect_lint(
+            "c()",
+            "`c()` is an unnecessary way to create `NULL`",
+            "unnecessary_concatenation",
+            None,
+        );
+        let expected_message = "Using `c()` on a single constant is unnecessary";
+        expect_lint("c(1)", expected_message, "unnecessary_concatenation", None);
+        expect_lint("c(1L)", expected_message, "unnecessary_concatenation", None);
+        expect_lint(
+            "c('a')",
+            expected_message,
+            "unnecessary_concatenation",
+            None,
+        );
+        expect_lint(
+            "c(TRUE)",
+            expected_message,
+            "unnecessary_concatenation",
+            None,
+        );
+        expect_lint("c(NA)", expected_message, "unnecessary_concatenation", None);
+        expect_lint(
+            "c(NULL)",
+            expected_message,
+            "unnecessary_concatenation",
+            None,
+        );
+        expect_lint(
+            "x |> foo(c(1))",
+            expected_message,
+            "unnecessary_concatenation",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "c()",
+                    "x <- c(1)",
+                    "c(\"a\")",
+                    "c( TRUE )",
+                    "c(NA_character_)",
+                    "foo(c(), c(1.5))",
+                ],
+                "unnecessary_concatenation",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_unnecessary_concatenation_with_comments_no_fix() {
+        use insta::assert_snapshot;
+        // Should detect lint but skip fix when comments are present to avoid destroying them
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_fixed_text(
+                vec![
+                    "# leading comment\nc(1)",
+                    "c(\n  # comment\n  1\n)",
+                    "c(\n  # comment\n)",
+                    "c(1) # trailing comment",
+                ],
+                "unnecessary_concatenation",
+                None
+            )
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use crate::diagnostic::*;
+use crate::utils::{get_function_name, is_piped, node_contains_comments};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// ## What it does
+///
+/// Checks for calls to `c()` with no arguments or with a single constant, such
+/// as `c(1)` or `c("a")`.
+///
+/// ## Why is this bad?
+///
+/// `c()` is used to combine several values. With a single constant, it returns
+/// this constant unchanged, and with no arguments, it returns `NULL`. In both
+/// cases, the call to `c()` is unnecessary and makes the code harder to read.
+///
+/// This rule doesn't report calls to `c()` with a single argument that isn't a
+/// constant, such as `c(x)` or `c(foo())`, because `c()` removes most
+/// attributes (e.g. `dim`) of its input, so it isn't always a no-op. It also
+/// doesn't report named arguments, such as `c(a = 1)`, since it creates a named
+/// vector, and `c()` at the end of a pipe, such as `x |> c()`.
+///
+/// This rule has a safe fix.
+///
+/// ## Example
+///
+/// ```r
+/// x <- c("a")
+/// y <- c()
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- "a"
+/// y <- NULL
+/// ```
+pub fn unnecessary_concatenation(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    let function = ast.function()?;
+    if get_function_name(function) != "c" || is_piped(ast) {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let args = args.iter().collect::<Result<Vec<_>, _>>()?;
+
+    let (body, suggestion, content) = match args.as_slice() {
+        [] => (
+            "`c()` is an unnecessary way to create `NULL`.".to_string(),
+            "Use `NULL` instead.".to_string(),
+            "NULL".to_string(),
+        ),
+        [arg] => {
+            if arg.name_clause().is_some() {
+                return Ok(None);
+            }
+            let value = unwrap_or_return_none!(arg.value());
+            if !is_constant(&value) {
+                return Ok(None);
+            }
+            (
+                "Using `c()` on a single constant is unnecessary.".to_string(),
+                "Remove the call to `c()`.".to_string(),
+                value.to_trimmed_string(),
+            )
+        }
+        _ => return Ok(None),
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "unnecessary_concatenation".to_string(),
+            body,
+            Some(suggestion),
+        ),
+        range,
+        Fix {
+            content,
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+fn is_constant(expr: &AnyRExpression) -> bool {
+    expr.as_any_r_value().is_some()
+        || expr.as_r_true_expression().is_some()
+        || expr.as_r_false_expression().is_some()
+        || expr.as_r_null_expression().is_some()
+        || expr.as_r_na_expression().is_some()
+        || expr.as_r_nan_expression().is_some()
+}
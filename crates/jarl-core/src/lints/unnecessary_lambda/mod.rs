@@ -0,0 +1,123 @@
+pub(crate) mod unnecessary_lambda;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    #[test]
+    fn test_no_lint_unnecessary_lambda() {
+        expect_no_lint("sapply(x, function(i) f(i, 1))", "unnecessary_lambda", None);
+        expect_no_lint("sapply(x, function(i) f(i, i))", "unnecessary_lambda", None);
+        expect_no_lint("sapply(x, function(i) f(g(i)))", "unnecessary_lambda", None);
+        expect_no_lint(
+            "sapply(x, function(i) f(i + 1))",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_no_lint(
+            "sapply(x, function(i) f(y = i))",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_no_lint("sapply(x, function(i) f(j))", "unnecessary_lambda", None);
+        expect_no_lint("sapply(x, function(i, j) f(i))", "unnecessary_lambda", None);
+        expect_no_lint(
+            "sapply(x, function(i = 1) f(i))",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_no_lint(
+            "sapply(x, function(...) f(...))",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_no_lint("sapply(x, function(i) i + 1)", "unnecessary_lambda", None);
+        expect_no_lint("sapply(x, function(i) `+`(i))", "unnecessary_lambda", None);
+        expect_no_lint("sapply(x, function(f) f(f))", "unnecessary_lambda", None);
+        expect_no_lint("sapply(x, function(i) g(i)(i))", "unnecessary_lambda", None);
+        expect_no_lint("sapply(x, function(i) x$f(i))", "unnecessary_lambda", None);
+        expect_no_lint(
+            "sapply(x, function(i) { y <- 1; f(i) })",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_no_lint(
+            "sapply(x, function(i) quote(i))",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_no_lint(
+            "sapply(x, function(i) base::substitute(i))",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_no_lint(
+            "sapply(x, function(i) return(i))",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_no_lint("sapply(x, function(i) f())", "unnecessary_lambda", None);
+        expect_no_lint("g <- function(i) f(i)", "unnecessary_lambda", None);
+        expect_no_lint("function(i) f(i)", "unnecessary_lambda", None);
+    }
+
+    #[test]
+    fn test_lint_unnecessary_lambda() {
+        use insta::assert_snapshot;
+
+        expect_lint(
+            "sapply(x, function(i) f(i))",
+            "This anonymous function only passes its argument",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_lint(
+            "sapply(x, \\(i) f(i))",
+            "This anonymous function only passes its argument",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_lint(
+            "lapply(x, function(i) { f(i) })",
+            "This anonymous function only passes its argument",
+            "unnecessary_lambda",
+            None,
+        );
+        expect_lint(
+            "Map(f = function(i) stats::median(i), x)",
+            "This anonymous function only passes its argument",
+            "unnecessary_lambda",
+            None,
+        );
+
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "sapply(x, function(i) f(i))",
+                    "sapply(x, \\(i) f(i))",
+                    "lapply(x, function(i) { f(i) })",
+                    "Map(f = function(i) stats::median(i), x)",
+                ],
+                "unnecessary_lambda",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_unnecessary_lambda_with_comments_no_fix() {
+        use insta::assert_snapshot;
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_fixed_text(
+                vec![
+                    "sapply(x, function(i) {\n  # a comment\n  f(i)\n})",
+                    "sapply(x, function(i) f(i)) # trailing comment",
+                ],
+                "unnecessary_lambda",
+                None
+            )
+        );
+    }
+}
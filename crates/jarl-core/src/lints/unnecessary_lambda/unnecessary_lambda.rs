@@ -0,0 +1,144 @@
+use crate::diagnostic::*;
+use crate::utils::{get_function_name, node_contains_comments};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Functions that use the expression passed as argument rather than its value,
+/// or that behave differently when they are not called directly.
+const FUNCTIONS_USING_EXPRESSION: [&str; 7] = [
+    "bquote",
+    "missing",
+    "on.exit",
+    "quote",
+    "Recall",
+    "return",
+    "substitute",
+];
+
+/// ## What it does
+///
+/// Checks for anonymous functions that only pass their argument to another
+/// function, such as `function(i) f(i)` or `\(i) f(i)`, when they are passed
+/// as argument to another function.
+///
+/// ## Why is this bad?
+///
+/// The anonymous function adds noise without doing anything: the inner
+/// function can be passed directly instead.
+///
+/// This rule is conservative: it only reports anonymous functions with a
+/// single argument that is passed unchanged and without name as the only
+/// argument of the inner function. It ignores functions that use the
+/// expression of their argument rather than its value, such as `quote()`.
+///
+/// This rule has a safe fix.
+///
+/// ## Example
+///
+/// ```r
+/// sapply(x, function(i) is.numeric(i))
+/// lapply(x, \(i) toupper(i))
+/// ```
+///
+/// Use instead:
+/// ```r
+/// sapply(x, is.numeric)
+/// lapply(x, toupper)
+/// ```
+pub fn unnecessary_lambda(ast: &RFunctionDefinition) -> anyhow::Result<Option<Diagnostic>> {
+    // Only anonymous functions passed as argument, e.g. in `lapply()`.
+    let is_argument = ast
+        .syntax()
+        .parent()
+        .is_some_and(|parent| RArgument::can_cast(parent.kind()));
+    if !is_argument {
+        return Ok(None);
+    }
+
+    let params: Vec<_> = ast.parameters()?.items().into_iter().collect();
+    if params.len() != 1 {
+        return Ok(None);
+    }
+    let param = params[0].clone()?;
+    if param.default().is_some() {
+        return Ok(None);
+    }
+    let param_name = param.syntax().text_trimmed().to_string();
+
+    let call = unwrap_or_return_none!(get_single_call(ast.body()?));
+    let function = call.function()?;
+    let function_name = unwrap_or_return_none!(get_forwarded_function_name(&function));
+    if function_name == param_name
+        || FUNCTIONS_USING_EXPRESSION.contains(&get_function_name(function).as_str())
+    {
+        return Ok(None);
+    }
+
+    // The inner call must have a single unnamed argument which is the
+    // parameter of the anonymous function.
+    let args: Vec<_> = call.arguments()?.items().into_iter().collect();
+    if args.len() != 1 {
+        return Ok(None);
+    }
+    let arg = args[0].clone()?;
+    if arg.name_clause().is_some() {
+        return Ok(None);
+    }
+    let value = unwrap_or_return_none!(arg.value());
+    let value = unwrap_or_return_none!(value.as_r_identifier());
+    if value.syntax().text_trimmed() != param_name.as_str() {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "unnecessary_lambda".to_string(),
+            format!("This anonymous function only passes its argument to `{function_name}()`."),
+            Some(format!("Use `{function_name}` directly instead.")),
+        ),
+        range,
+        Fix {
+            content: function_name,
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Get the call that is the body of the function, either directly or as the
+/// only expression in braces.
+fn get_single_call(body: AnyRExpression) -> Option<RCall> {
+    match body {
+        AnyRExpression::RCall(call) => Some(call),
+        AnyRExpression::RBracedExpressions(braced) => {
+            let expressions: Vec<_> = braced.expressions().into_iter().collect();
+            match expressions.as_slice() {
+                [AnyRExpression::RCall(call)] => Some(call.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Get the name of the function called, e.g. `f` or `pkg::f`. Functions in
+/// backticks (such as operators) and functions that are the result of an
+/// expression are ignored.
+fn get_forwarded_function_name(function: &AnyRExpression) -> Option<String> {
+    let name = match function {
+        AnyRExpression::RIdentifier(id) => id.syntax().text_trimmed().to_string(),
+        AnyRExpression::RNamespaceExpression(ns) => {
+            ns.right().ok()?.as_r_identifier()?;
+            ns.syntax().text_trimmed().to_string()
+        }
+        _ => return None,
+    };
+    if name.contains('`') {
+        return None;
+    }
+    Some(name)
+}
@@ -1,9 +1,12 @@
 use super::graph::{BlockId, ControlFlowGraph, Terminator};
 use air_r_syntax::{
-    RBracedExpressions, RForStatement, RFunctionDefinition, RIfStatement, RRepeatStatement,
+    RBracedExpressions, RCall, RForStatement, RFunctionDefinition, RIfStatement, RRepeatStatement,
     RSyntaxKind, RSyntaxNode, RWhileStatement,
 };
-use biome_rowan::AstNode;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// Functions that always stop the execution, whatever their arguments.
+const STOP_FUNCTIONS: [&str; 6] = ["stop", ".Defunct", "abort", "cli_abort", "q", "quit"];
 
 /// Builder for constructing control flow graphs
 pub struct CfgBuilder {
@@ -20,6 +23,33 @@ struct LoopContext {
     break_target: BlockId,
 }
 
+/// Check whether a call always stops the execution: calls to [STOP_FUNCTIONS],
+/// `invokeRestart("abort")`, and `stopifnot(FALSE)`.
+fn is_stop_call(call: &RSyntaxNode, fun_name: &str) -> bool {
+    if STOP_FUNCTIONS.contains(&fun_name) {
+        return true;
+    }
+
+    let Some(arguments) = RCall::cast_ref(call).and_then(|call| call.arguments().ok()) else {
+        return false;
+    };
+    let arguments: Vec<String> = arguments
+        .items()
+        .iter()
+        .filter_map(Result::ok)
+        .map(|arg| arg.syntax().text_trimmed().to_string())
+        .collect();
+
+    match fun_name {
+        // Jumps back to the top level, like an error.
+        "invokeRestart" => {
+            matches!(arguments.as_slice(), [arg] if arg == "\"abort\"" || arg == "'abort'")
+        }
+        "stopifnot" => matches!(arguments.as_slice(), [arg] if arg == "FALSE"),
+        _ => false,
+    }
+}
+
 /// Evaluate a constant boolean condition if possible
 fn evaluate_constant_condition(node: &RSyntaxNode) -> Option<bool> {
     let kind = node.kind();
@@ -212,9 +242,7 @@ impl CfgBuilder {
                 } else if fun_name == "next" {
                     self.build_next(current, stmt.clone());
                     current
-                } else if ["stop", ".Defunct", "abort", "cli_abort", "q", "quit"]
-                    .contains(&fun_name.as_str())
-                {
+                } else if is_stop_call(stmt, &fun_name) {
                     self.build_stop(current, stmt.clone());
                     current
                 } else {
@@ -536,7 +564,7 @@ impl CfgBuilder {
     /// Build stop statement.
     ///
     /// This is a list of R functions that stop the execution, e.g. `stop()`,
-    /// `abort()`, `cli_abort()`.
+    /// `abort()`, `cli_abort()`, `quit()`, or `invokeRestart("abort")`.
     fn build_stop(&mut self, current: BlockId, _node: RSyntaxNode) {
         if let Some(block) = self.cfg.block_mut(current) {
             block.terminator = Terminator::Stop;
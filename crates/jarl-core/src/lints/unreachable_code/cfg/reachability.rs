@@ -17,7 +17,8 @@ pub enum UnreachableReason {
     AfterReturn,
     /// Code after a break statement
     AfterBreak,
-    /// Code after a statement to stop the execution (`stop()`, `abort()`, etc.)
+    /// Code after a statement to stop the execution (`stop()`, `abort()`,
+    /// `quit()`, `invokeRestart("abort")`, etc.)
     AfterStop,
     /// Code after a next statement
     AfterNext,
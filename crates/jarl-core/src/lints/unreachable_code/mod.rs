@@ -478,6 +478,86 @@ foo <- function() {
 
         let code = r#"
 foo <- function() {
+  q()
+  1 + 1
+}
+"#;
+        insta::assert_snapshot!(snapshot_lint(code), @r"
+        warning: unreachable_code
+         --> <test>:4:3
+          |
+        4 |   1 + 1
+          |   ----- This code is unreachable because it appears after a `stop()` statement (or equivalent).
+          |
+        Found 1 error.
+        ");
+
+        let code = r#"
+foo <- function() {
+  quit(save = "no")
+  1 + 1
+}
+"#;
+        insta::assert_snapshot!(snapshot_lint(code), @r"
+        warning: unreachable_code
+         --> <test>:4:3
+          |
+        4 |   1 + 1
+          |   ----- This code is unreachable because it appears after a `stop()` statement (or equivalent).
+          |
+        Found 1 error.
+        ");
+
+        let code = r#"
+foo <- function() {
+  invokeRestart("abort")
+  1 + 1
+}
+"#;
+        insta::assert_snapshot!(snapshot_lint(code), @r"
+        warning: unreachable_code
+         --> <test>:4:3
+          |
+        4 |   1 + 1
+          |   ----- This code is unreachable because it appears after a `stop()` statement (or equivalent).
+          |
+        Found 1 error.
+        ");
+
+        let code = r#"
+foo <- function() {
+  invokeRestart('abort')
+  1 + 1
+}
+"#;
+        insta::assert_snapshot!(snapshot_lint(code), @r"
+        warning: unreachable_code
+         --> <test>:4:3
+          |
+        4 |   1 + 1
+          |   ----- This code is unreachable because it appears after a `stop()` statement (or equivalent).
+          |
+        Found 1 error.
+        ");
+
+        let code = r#"
+foo <- function() {
+  stopifnot(FALSE)
+  1 + 1
+}
+"#;
+        insta::assert_snapshot!(snapshot_lint(code), @r"
+        warning: unreachable_code
+         --> <test>:4:3
+          |
+        4 |   1 + 1
+          |   ----- This code is unreachable because it appears after a `stop()` statement (or equivalent).
+          |
+        Found 1 error.
+        ");
+
+        let code = r#"
+foo <- function() {
   if (x > 0) {
     cli_abort("a")
   }
@@ -488,6 +568,30 @@ foo <- function() {
 
         let code = r#"
 foo <- function() {
+  invokeRestart("muffleWarning")
+  1 + 1
+}
+"#;
+        expect_no_lint(code, "unreachable_code", None);
+
+        let code = r#"
+foo <- function() {
+  stopifnot(x)
+  1 + 1
+}
+"#;
+        expect_no_lint(code, "unreachable_code", None);
+
+        let code = r#"
+foo <- function() {
+  stopifnot(FALSE, x)
+  1 + 1
+}
+"#;
+        expect_no_lint(code, "unreachable_code", None);
+
+        let code = r#"
+foo <- function() {
   bar$stop()
   1 + 1
 }
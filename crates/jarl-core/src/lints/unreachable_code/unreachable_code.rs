@@ -6,8 +6,9 @@ use super::cfg::{UnreachableReason, build_cfg, find_unreachable_code};
 /// ## What it does
 ///
 /// Detects code that can never be executed because it appears after control
-/// flow statements like `return`, `break`, or `next`, or in branches that
-/// cannot be reached.
+/// flow statements like `return`, `break`, or `next`, after calls that stop the
+/// execution like `stop()`, `quit()`, or `invokeRestart("abort")`, or in
+/// branches that cannot be reached.
 ///
 /// ## Why is this bad?
 ///
@@ -1,5 +1,5 @@
 use crate::diagnostic::*;
-use crate::utils::get_function_name;
+use crate::utils::{get_function_name, node_contains_comments};
 use crate::utils_ast::AstNodeExt;
 use air_r_syntax::*;
 use biome_rowan::AstNode;
@@ -19,12 +19,13 @@ use biome_rowan::AstNode;
 ///
 /// This rule only reports cases where the binary expression is the top operation
 /// of the `condition` in an `if` or `while` statement. For example, `if (x & y)`
-/// will be reported but `if (foo(x & y))` will not. The reason for this is
-/// that in those two contexts, the length of `condition` must be equal to 1
-/// (otherwise R would error as of 4.3.0), so using `& / |` or `&& / ||`
-/// is equivalent.
+/// will be reported but `if (foo(x & y))`, `if (x[y & z])`, or
+/// `if (ifelse(x & y, a, b))` will not. The reason for this is that in those two
+/// contexts, the length of `condition` must be equal to 1 (otherwise R would
+/// error as of 4.3.0), so using `& / |` or `&& / ||` is equivalent.
 ///
-/// This rule doesn't have an automatic fix.
+/// The automatic fix only replaces the operator, i.e. `&` by `&&` and `|` by
+/// `||`.
 ///
 /// ## Example
 ///
@@ -60,6 +61,12 @@ pub fn vector_logic(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic
         return Ok(None);
     }
 
+    let replacement = if operator.kind() == RSyntaxKind::AND {
+        "&&"
+    } else {
+        "||"
+    };
+
     let msg = if ast.parent_is_if_condition() {
         format!(
             "`{}` in `if()` statements can be inefficient.",
@@ -75,10 +82,20 @@ pub fn vector_logic(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic
     };
 
     let range = ast.syntax().text_trimmed_range();
+    let operator_range = operator.text_trimmed_range();
     let diagnostic = Diagnostic::new(
-        ViolationData::new("vector_logic".to_string(), msg.to_string(), None),
+        ViolationData::new(
+            "vector_logic".to_string(),
+            msg.to_string(),
+            Some(format!("Use `{replacement}` instead.")),
+        ),
         range,
-        Fix::empty(),
+        Fix {
+            content: replacement.to_string(),
+            start: operator_range.start().into(),
+            end: operator_range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
     );
 
     Ok(Some(diagnostic))
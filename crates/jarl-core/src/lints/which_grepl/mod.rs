@@ -39,6 +39,10 @@ mod tests {
     fn test_no_lint_which_grepl() {
         expect_no_lint("which(grepl(p1, x) | grepl(p2, x))", "which_grepl", None);
         expect_no_lint("which(grep(p1, x))", "which_grepl", None);
+        expect_no_lint("which(grepl(p, x), arr.ind = TRUE)", "which_grepl", None);
+        expect_no_lint("which(arr.ind = TRUE, grepl(p, x))", "which_grepl", None);
+        expect_no_lint("which(grepl(p, x), useNames = FALSE)", "which_grepl", None);
+        expect_no_lint("which(grepl(p, x), TRUE)", "which_grepl", None);
     }
 
     #[test]
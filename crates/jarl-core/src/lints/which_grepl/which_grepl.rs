@@ -1,7 +1,7 @@
 use crate::diagnostic::*;
 use crate::utils::{get_nested_functions_content, node_contains_comments};
 use air_r_syntax::*;
-use biome_rowan::AstNode;
+use biome_rowan::{AstNode, AstSeparatedList};
 
 pub struct WhichGrepl;
 
@@ -14,6 +14,9 @@ pub struct WhichGrepl;
 /// `which(grepl(...))` is harder to read and is less efficient than `grep()`
 /// since it requires two passes on the vector.
 ///
+/// This rule ignores calls where `which()` has other arguments, such as
+/// `arr.ind` or `useNames`, since `grep()` has no equivalent.
+///
 /// ## Example
 ///
 /// ```r
@@ -45,6 +48,11 @@ impl Violation for WhichGrepl {
 }
 
 pub fn which_grepl(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    // `arr.ind` and `useNames` change the output of `which()`.
+    if ast.arguments()?.items().iter().count() > 1 {
+        return Ok(None);
+    }
+
     let inner_content = get_nested_functions_content(ast, "which", "grepl")?;
 
     if let Some(inner_content) = inner_content {
@@ -0,0 +1,268 @@
+//! Extraction of the R code in the `@examples` and `@examplesIf` sections of
+//! roxygen2 comments, so that it can be checked like the rest of the file.
+
+use biome_rowan::{TextRange, TextSize};
+
+/// Rd macros whose content is not run when checking the package, so it may
+/// contain code that is wrong on purpose.
+const SKIPPED_RD_MACROS: &[&str] = &["\\dontrun{", "\\donttest{"];
+
+/// The code of an `@examples` or `@examplesIf` section, without the `#'` that
+/// start each line.
+#[derive(Debug, PartialEq)]
+pub struct RoxygenExamples {
+    /// The code of the examples, one line per line of the section.
+    pub code: String,
+    /// Offset of the start of each line of `code`.
+    code_line_starts: Vec<usize>,
+    /// Offset of the start of each line of `code` in the original file.
+    file_line_starts: Vec<usize>,
+}
+
+impl RoxygenExamples {
+    fn new() -> Self {
+        Self {
+            code: String::new(),
+            code_line_starts: vec![],
+            file_line_starts: vec![],
+        }
+    }
+
+    fn push_line(&mut self, line: &str, file_offset: usize) {
+        if !self.code_line_starts.is_empty() {
+            self.code.push('\n');
+        }
+        self.code_line_starts.push(self.code.len());
+        self.file_line_starts.push(file_offset);
+        self.code.push_str(line);
+    }
+
+    /// Replace the `\dontrun{}` and `\donttest{}` regions by spaces so that
+    /// they are not checked. This keeps the offsets of the rest of the code.
+    ///
+    /// The end of a region is found by counting braces, so braces in strings
+    /// or comments may end it too early or too late.
+    fn remove_skipped_regions(&mut self) {
+        let mut code = String::with_capacity(self.code.len());
+        let mut rest = self.code.as_str();
+
+        while let Some((start, rd_macro)) = SKIPPED_RD_MACROS
+            .iter()
+            .filter_map(|rd_macro| rest.find(rd_macro).map(|start| (start, rd_macro)))
+            .min()
+        {
+            code.push_str(&rest[..start]);
+
+            let open_brace = start + rd_macro.len() - 1;
+            let mut depth = 0;
+            let mut end = rest.len();
+            for (i, c) in rest[open_brace..].char_indices() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    _ => continue,
+                }
+                if depth == 0 {
+                    end = open_brace + i + 1;
+                    break;
+                }
+            }
+
+            for c in rest[start..end].chars() {
+                if c == '\n' {
+                    code.push('\n');
+                } else {
+                    code.push_str(&" ".repeat(c.len_utf8()));
+                }
+            }
+            rest = &rest[end..];
+        }
+        code.push_str(rest);
+
+        self.code = code;
+    }
+
+    fn is_empty(&self) -> bool {
+        self.code.trim().is_empty()
+    }
+
+    /// Index of the line of `code` that contains `offset`.
+    fn line_index(&self, offset: usize) -> usize {
+        self.code_line_starts
+            .partition_point(|start| *start <= offset)
+            .saturating_sub(1)
+    }
+
+    /// Convert an offset in `code` to an offset in the original file.
+    pub fn file_offset(&self, offset: usize) -> usize {
+        let line = self.line_index(offset);
+        self.file_line_starts[line] + offset - self.code_line_starts[line]
+    }
+
+    /// Convert a range in `code` to a range in the original file.
+    pub fn file_range(&self, range: TextRange) -> TextRange {
+        let start = self.file_offset(range.start().into());
+        let end = self.file_offset(range.end().into());
+        TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32))
+    }
+
+    /// Whether `start` and `end` (offsets in `code`) are on the same line.
+    pub fn is_single_line(&self, start: usize, end: usize) -> bool {
+        self.line_index(start) == self.line_index(end)
+    }
+}
+
+/// Find all `@examples` and `@examplesIf` sections in the roxygen2 comments of
+/// `contents`. A section ends at the next roxygen2 tag or at the first line
+/// that isn't a roxygen2 comment.
+///
+/// The condition of `@examplesIf` is not part of the code, and neither is the
+/// code in `\dontrun{}` and `\donttest{}`.
+pub fn find_roxygen_examples(contents: &str) -> Vec<RoxygenExamples> {
+    let mut all_examples = vec![];
+    let mut current: Option<RoxygenExamples> = None;
+    let mut line_start = 0;
+
+    for line in contents.split_inclusive('\n') {
+        let offset = line_start;
+        line_start += line.len();
+        let line = line.trim_end_matches(['\n', '\r']);
+
+        let indent = line.len() - line.trim_start().len();
+        let Some(comment) = line[indent..].strip_prefix("#'") else {
+            all_examples.extend(current.take());
+            continue;
+        };
+        // The code starts after the `#'` and one optional space.
+        let mut code_offset = offset + indent + 2;
+        let code = match comment.strip_prefix(' ') {
+            Some(code) => {
+                code_offset += 1;
+                code
+            }
+            None => comment,
+        };
+
+        if let Some(tag) = code.trim_start().strip_prefix('@') {
+            all_examples.extend(current.take());
+
+            let tag_name = tag.split_whitespace().next().unwrap_or_default();
+            if tag_name == "examples" {
+                let mut examples = RoxygenExamples::new();
+                // Code can start on the same line as `@examples`.
+                let rest = tag["examples".len()..].trim_start();
+                if !rest.is_empty() {
+                    examples.push_line(rest, offset + line.len() - rest.len());
+                }
+                current = Some(examples);
+            } else if tag_name == "examplesIf" {
+                current = Some(RoxygenExamples::new());
+            }
+            continue;
+        }
+
+        if let Some(examples) = current.as_mut() {
+            examples.push_line(code, code_offset);
+        }
+    }
+    all_examples.extend(current.take());
+
+    all_examples
+        .iter_mut()
+        .for_each(RoxygenExamples::remove_skipped_regions);
+    all_examples.retain(|examples| !examples.is_empty());
+    all_examples
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_roxygen_examples() {
+        let contents = "\
+#' Title
+#'
+#' @param x A number.
+#' @examples
+#' x = 1
+#'
+#' foo(x)
+#' @export
+foo <- function(x) x
+";
+        let examples = find_roxygen_examples(contents);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].code, "x = 1\n\nfoo(x)");
+
+        // `x` is at line 5, column 4.
+        let x = contents.find("x = 1").unwrap();
+        assert_eq!(examples[0].file_offset(0), x);
+        // `foo` is at line 7, column 4.
+        let foo = contents.find("foo(x)").unwrap();
+        assert_eq!(examples[0].file_offset(7), foo);
+    }
+
+    #[test]
+    fn test_find_roxygen_examples_if() {
+        let contents = "\
+#' @examplesIf interactive()
+#' x = 1
+#' @examples y = 2
+#'   z = 3
+foo <- function(x) x
+";
+        let examples = find_roxygen_examples(contents);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].code, "x = 1");
+        assert_eq!(examples[1].code, "y = 2\n  z = 3");
+
+        let y = contents.find("y = 2").unwrap();
+        assert_eq!(examples[1].file_offset(0), y);
+        let z = contents.find("z = 3").unwrap();
+        assert_eq!(examples[1].file_offset(8), z);
+    }
+
+    #[test]
+    fn test_no_roxygen_examples() {
+        assert!(find_roxygen_examples("x = 1\n# @examples\n# x = 1").is_empty());
+        assert!(find_roxygen_examples("#' @param x\n#' x = 1\nx = 1").is_empty());
+        assert!(find_roxygen_examples("#' @examples\n#'\nfoo <- 1").is_empty());
+    }
+
+    #[test]
+    fn test_roxygen_examples_dontrun() {
+        let contents = "\
+#' @examples
+#' x = 1
+#' \\dontrun{
+#' if (x) { y = 2 }
+#' }
+#' \\donttest{z = 3} ; w = 4
+foo <- function(x) x
+";
+        let examples = find_roxygen_examples(contents);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(
+            examples[0].code,
+            "x = 1\n         \n                \n \n                 ; w = 4"
+        );
+        let w = contents.find("w = 4").unwrap();
+        assert_eq!(
+            examples[0].file_offset(examples[0].code.find('w').unwrap()),
+            w
+        );
+
+        // Examples that are only in `\dontrun{}` are dropped.
+        assert!(find_roxygen_examples("#' @examples\n#' \\dontrun{\n#' x = 1\n#' }").is_empty());
+    }
+
+    #[test]
+    fn test_roxygen_examples_end_at_code() {
+        let contents = "  #' @examples\n  #' x = 1\ny = 2\n#' z = 3";
+        let examples = find_roxygen_examples(contents);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].code, "x = 1");
+        assert_eq!(examples[0].file_offset(0), contents.find("x = 1").unwrap());
+    }
+}
@@ -1,6 +1,9 @@
+use serde::{Serialize, Serializer};
 use std::fmt;
 use std::str::FromStr;
 
+use crate::diagnostic::Confidence;
+
 /// Category of a linting rule
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Category {
@@ -27,6 +30,17 @@ impl Category {
         }
     }
 
+    /// Human-readable name of the category, e.g. used in the documentation
+    pub const fn long_name(self) -> &'static str {
+        match self {
+            Self::Corr => "Correctness",
+            Self::Susp => "Suspicious",
+            Self::Perf => "Performance",
+            Self::Read => "Readability",
+            Self::Testthat => "Testthat",
+        }
+    }
+
     pub const ALL: &'static [Category] = &[
         Category::Corr,
         Category::Susp,
@@ -72,7 +86,16 @@ pub enum FixStatus {
     Unsafe,
 }
 
+/// Declare the `Rule` enum with the metadata of each rule.
+///
+/// Optional fields:
+/// - `lsp_autofix`: whether the safe fixes of the rule are applied by the
+///   "fix all" action of the language server (default `true`).
+/// - `confidence`: the default confidence of the rule's violations (default
+///   `High`).
 macro_rules! declare_rules {
+    (@or $default:expr) => { $default };
+    (@or $default:expr, $value:expr) => { $value };
     (
         $(
             $variant:ident => {
@@ -81,6 +104,8 @@ macro_rules! declare_rules {
                 default: $default:ident,
                 fix: $fix:ident,
                 min_r_version: $min_version:expr,
+                $(lsp_autofix: $lsp_autofix:literal,)?
+                $(confidence: $confidence:ident,)?
             }
         ),* $(,)?
     ) => {
@@ -126,6 +151,28 @@ macro_rules! declare_rules {
                 }
             }
 
+            /// Get the default confidence of the rule's violations. Rules that
+            /// rely on a heuristic to find violations have a lower confidence.
+            pub const fn confidence(self) -> Confidence {
+                match self {
+                    $(Self::$variant => declare_rules!(@or Confidence::High $(, Confidence::$confidence)?)),*
+                }
+            }
+
+            /// Whether the safe fixes of the rule can be applied on the whole
+            /// document by the "fix all" action of the language server, e.g.
+            /// when saving. Some fixes are fine when applied one by one but
+            /// disruptive when applied while the code is being written:
+            /// `consecutive_assertion` would merge a `stopifnot()` call that
+            /// was just added with the previous one. The fixes of those rules
+            /// are still available as individual quick fixes. This doesn't
+            /// affect `--fix`.
+            pub const fn is_lsp_autofix(self) -> bool {
+                match self {
+                    $(Self::$variant => declare_rules!(@or true $(, $lsp_autofix)?)),*
+                }
+            }
+
             /// Check if the rule has a safe fix
             pub const fn has_safe_fix(self) -> bool {
                 matches!(self.fix_status(), FixStatus::Safe)
@@ -185,6 +232,14 @@ macro_rules! declare_rules {
 
 // Declare all rules with their metadata
 declare_rules! {
+    AbsolutePath => {
+        name: "absolute_path",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+        confidence: Heuristic,
+    },
     AllEqual => {
         name: "all_equal",
         categories: [Susp],
@@ -213,6 +268,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    BraceStyle => {
+        name: "brace_style",
+        categories: [Read],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     Browser => {
         name: "browser",
         categories: [Corr],
@@ -227,6 +289,14 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    CommentedCode => {
+        name: "commented_code",
+        categories: [Read],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+        confidence: Heuristic,
+    },
     ComparisonNegation => {
         name: "comparison_negation",
         categories: [Read],
@@ -241,6 +311,21 @@ declare_rules! {
         fix: Safe,
         min_r_version: Some((4, 4, 0)),
     },
+    ConditionMessage => {
+        name: "condition_message",
+        categories: [Read],
+        default: Enabled,
+        fix: None,
+        min_r_version: None,
+    },
+    ConsecutiveAssertion => {
+        name: "consecutive_assertion",
+        categories: [Read],
+        default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+        lsp_autofix: false,
+    },
     DownloadFile => {
         name: "download_file",
         categories: [Susp],
@@ -248,6 +333,13 @@ declare_rules! {
         fix: None,
         min_r_version: None,
     },
+    DuplicateBlock => {
+        name: "duplicate_block",
+        categories: [Read],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     DuplicatedArguments => {
         name: "duplicated_arguments",
         categories: [Susp],
@@ -283,6 +375,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    ExpectComparison => {
+        name: "expect_comparison",
+        categories: [Testthat],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     ExpectLength => {
         name: "expect_length",
         categories: [Testthat],
@@ -334,7 +433,7 @@ declare_rules! {
     },
     FixedRegex => {
         name: "fixed_regex",
-        categories: [Perf],
+        categories: [Perf, Read],
         default: Disabled,
         fix: Safe,
         min_r_version: None,
@@ -353,6 +452,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: Some((4, 5, 0)),
     },
+    IfelseCensor => {
+        name: "ifelse_censor",
+        categories: [Perf, Read],
+        default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     ImplicitAssignment => {
         name: "implicit_assignment",
         categories: [Read],
@@ -360,6 +466,34 @@ declare_rules! {
         fix: None,
         min_r_version: None,
     },
+    ImplicitInteger => {
+        name: "implicit_integer",
+        categories: [Read],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
+    ImplicitReturn => {
+        name: "implicit_return",
+        categories: [Read],
+        default: Disabled,
+        fix: Unsafe,
+        min_r_version: None,
+    },
+    InfixSpaces => {
+        name: "infix_spaces",
+        categories: [Read],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
+    InnerCombine => {
+        name: "inner_combine",
+        categories: [Perf, Read],
+        default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     IsNumeric => {
         name: "is_numeric",
         categories: [Read],
@@ -388,6 +522,20 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    LibraryCall => {
+        name: "library_call",
+        categories: [Read],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    LineLength => {
+        name: "line_length",
+        categories: [Read],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     List2df => {
         name: "list2df",
         categories: [Perf, Read],
@@ -395,6 +543,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: Some((4, 0, 0)),
     },
+    LiteralCoercion => {
+        name: "literal_coercion",
+        categories: [Read],
+        default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     MatrixApply => {
         name: "matrix_apply",
         categories: [Perf],
@@ -402,6 +557,20 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    NestedIfelse => {
+        name: "nested_ifelse",
+        categories: [Read],
+        default: Enabled,
+        fix: None,
+        min_r_version: None,
+    },
+    NullCheckWithoutElse => {
+        name: "null_check_without_else",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     NumericLeadingZero => {
         name: "numeric_leading_zero",
         categories: [Read],
@@ -409,6 +578,27 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    Nzchar => {
+        name: "nzchar",
+        categories: [Perf, Read],
+        default: Enabled,
+        fix: Unsafe,
+        min_r_version: None,
+    },
+    ObjectName => {
+        name: "object_name",
+        categories: [Read],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    OrderMismatch => {
+        name: "order_mismatch",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     OuterNegation => {
         name: "outer_negation",
         categories: [Perf, Read],
@@ -416,6 +606,27 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    PasteSep => {
+        name: "paste_sep",
+        categories: [Read],
+        default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+    },
+    PipeConsistency => {
+        name: "pipe_consistency",
+        categories: [Read],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
+    Quotes => {
+        name: "quotes",
+        categories: [Read],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     RedundantEquals => {
         name: "redundant_equals",
         categories: [Read],
@@ -437,6 +648,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    RevSort => {
+        name: "rev_sort",
+        categories: [Read],
+        default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     SampleInt => {
         name: "sample_int",
         categories: [Read],
@@ -444,6 +662,27 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    SapplyType => {
+        name: "sapply_type",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    ScalarIn => {
+        name: "scalar_in",
+        categories: [Read],
+        default: Enabled,
+        fix: Unsafe,
+        min_r_version: None,
+    },
+    Semicolon => {
+        name: "semicolon",
+        categories: [Read],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     Seq => {
         name: "seq",
         categories: [Susp],
@@ -486,13 +725,62 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    TrailingBlankLines => {
+        name: "trailing_blank_lines",
+        categories: [Read],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
+    TrailingWhitespace => {
+        name: "trailing_whitespace",
+        categories: [Read],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     TrueFalseSymbol => {
         name: "true_false_symbol",
         categories: [Read],
         default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+    },
+    UndesirableFunction => {
+        name: "undesirable_function",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    UndesirableOperator => {
+        name: "undesirable_operator",
+        categories: [Susp],
+        default: Disabled,
         fix: None,
         min_r_version: None,
     },
+    UnknownNolintRule => {
+        name: "unknown_nolint_rule",
+        categories: [Susp],
+        default: Enabled,
+        fix: None,
+        min_r_version: None,
+    },
+    UnnecessaryConcatenation => {
+        name: "unnecessary_concatenation",
+        categories: [Read],
+        default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+    },
+    UnnecessaryLambda => {
+        name: "unnecessary_lambda",
+        categories: [Read],
+        default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     UnnecessaryNesting => {
         name: "unnecessary_nesting",
         categories: [Read],
@@ -509,9 +797,9 @@ declare_rules! {
     },
     VectorLogic => {
         name: "vector_logic",
-        categories: [Perf],
+        categories: [Perf, Susp],
         default: Enabled,
-        fix: None,
+        fix: Safe,
         min_r_version: None,
     },
     WhichGrepl => {
@@ -529,6 +817,15 @@ pub struct RuleSet {
     rules: Vec<Rule>,
 }
 
+/// Rule sets are serialized as the sorted names of their rules.
+impl Serialize for RuleSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut names: Vec<&str> = self.rules.iter().map(|rule| rule.name()).collect();
+        names.sort_unstable();
+        names.serialize(serializer)
+    }
+}
+
 impl RuleSet {
     /// Create an empty rule set
     pub fn empty() -> Self {
@@ -593,8 +890,27 @@ impl<'a> FromIterator<&'a Rule> for RuleSet {
     }
 }
 
+// Defines `rule_documentation()`, see `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/rule_docs.rs"));
+
 /// Helper functions for working with rules
 impl Rule {
+    /// Whether the rule checks the lines of the file rather than its code.
+    /// These rules are not run on the code of roxygen2 `@examples` sections
+    /// since the lines containing it are already checked.
+    pub const fn checks_lines(self) -> bool {
+        matches!(
+            self,
+            Rule::LineLength | Rule::TrailingBlankLines | Rule::TrailingWhitespace
+        )
+    }
+
+    /// Get the documentation of the rule (what it does, why it is bad, and an
+    /// example), taken from the doc comment of its lint.
+    pub fn documentation(self) -> Option<&'static str> {
+        rule_documentation(self.name())
+    }
+
     /// Get all rules with a specific fix status
     pub fn by_fix_status(status: FixStatus) -> impl Iterator<Item = Rule> {
         ALL_RULES
@@ -4,6 +4,8 @@
 //
 // MIT License - Posit PBC
 
+use std::collections::BTreeMap;
+
 /// Resolved configuration settings used within jarl
 #[derive(Debug, Default)]
 pub struct Settings {
@@ -20,6 +22,17 @@ pub struct LinterSettings {
     pub default_exclude: Option<bool>,
     pub fixable: Option<Vec<String>>,
     pub unfixable: Option<Vec<String>>,
+    pub severity: Option<BTreeMap<String, String>>,
+    pub duplicate_block_min_size: Option<usize>,
+    pub undesirable_function: Option<BTreeMap<String, String>>,
+    pub undesirable_operator: Option<BTreeMap<String, String>>,
+    pub nested_ifelse_max_depth: Option<usize>,
+    pub duplicated_arguments_partial_matching: Option<bool>,
+    pub quotes_style: Option<String>,
+    pub line_length_max: Option<usize>,
+    pub infix_spaces_exponent: Option<bool>,
+    pub object_name_styles: Option<Vec<String>>,
+    pub pipe_consistency_style: Option<String>,
 }
 
 impl Default for LinterSettings {
@@ -36,6 +49,17 @@ impl Default for LinterSettings {
             default_exclude: None,
             fixable: None,
             unfixable: None,
+            severity: None,
+            duplicate_block_min_size: None,
+            undesirable_function: None,
+            undesirable_operator: None,
+            nested_ifelse_max_depth: None,
+            duplicated_arguments_partial_matching: None,
+            quotes_style: None,
+            line_length_max: None,
+            infix_spaces_exponent: None,
+            object_name_styles: None,
+            pipe_consistency_style: None,
         }
     }
 }
@@ -10,7 +10,9 @@ use biome_formatter::comments::{
 use biome_rowan::{SyntaxTriviaPieceComments, TextRange};
 use std::collections::HashSet;
 
+use crate::diagnostic::Diagnostic;
 use crate::directive::{LintDirective, parse_comment_directive, parse_special_skip_file};
+use crate::lints::unknown_nolint_rule::unknown_nolint_rule::unknown_nolint_rule;
 use crate::rule_set::Rule;
 /// Comment style for R that identifies nolint directives
 #[derive(Default)]
@@ -136,6 +138,38 @@ impl SuppressionManager {
         false
     }
 
+    /// Report the rules listed in nolint directives that don't exist
+    ///
+    /// This checks all directives, not only those attached to nodes with a
+    /// violation, so that typos in rule names are caught instead of silently
+    /// suppressing nothing.
+    pub fn unknown_rules_diagnostics(&self, root: &RSyntaxNode) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        self.collect_unknown_rules(root, &mut diagnostics);
+        diagnostics
+    }
+
+    fn collect_unknown_rules(&self, node: &RSyntaxNode, diagnostics: &mut Vec<Diagnostic>) {
+        for comment in self
+            .comments
+            .leading_comments(node)
+            .iter()
+            .chain(self.comments.trailing_comments(node))
+            .chain(self.comments.dangling_comments(node))
+        {
+            let Some(directive) = parse_comment_directive(comment.piece().text()) else {
+                continue;
+            };
+            for name in directive.unknown_rules() {
+                diagnostics.push(unknown_nolint_rule(name, comment.piece().text_range()));
+            }
+        }
+
+        for child in node.children() {
+            self.collect_unknown_rules(&child, diagnostics);
+        }
+    }
+
     /// Build skip regions from nolint start/end directives
     fn build_skip_regions(root: &RSyntaxNode, comments: &Comments<RLanguage>) -> Vec<SkipRegion> {
         let mut regions = Vec::new();
@@ -489,4 +523,37 @@ any(is.na(x))
         // Should not skip the file
         assert!(!manager.should_skip_file(&parsed.syntax()));
     }
+
+    #[test]
+    fn test_unknown_rules_in_directive() {
+        let code = r#"
+# nolint: assignmnet
+x = 1
+y <- 2 # nolint: any_is_na, coalesce_linter
+# nolint start: foo, any_is_na
+z <- 3
+# nolint end
+"#;
+
+        let parsed = parse(code, RParserOptions::default());
+        let manager = SuppressionManager::from_node(&parsed.syntax(), code);
+
+        let diagnostics = manager.unknown_rules_diagnostics(&parsed.syntax());
+        let messages: Vec<_> = diagnostics
+            .iter()
+            .map(|d| d.message.body.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Unknown rule in nolint directive: `assignmnet`.",
+                "Unknown rule in nolint directive: `foo`."
+            ]
+        );
+        assert!(
+            diagnostics
+                .iter()
+                .all(|d| d.message.name == "unknown_nolint_rule")
+        );
+    }
 }
@@ -6,6 +6,7 @@
 //
 // MIT License - Posit PBC
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fs;
@@ -111,6 +112,25 @@ pub struct LinterTomlOptions {
     /// `--fix` in the CLI.
     pub unfixable: Option<Vec<String>>,
 
+    /// # Severity of rule violations
+    ///
+    /// A table mapping rule names (or groups of rules, such as `"PERF"`) to
+    /// a severity: `"error"`, `"warning"`, or `"info"`. For example:
+    ///
+    /// ```toml
+    /// [lint.severity]
+    /// equals_na = "error"
+    /// PERF = "info"
+    /// ```
+    ///
+    /// Violations of rules with severity `"error"` make `jarl check` fail.
+    /// Violations of rules with severity `"warning"` only make it fail if
+    /// `--warnings-as-errors` is passed, and violations of rules with
+    /// severity `"info"` never make it fail. Rules that don't appear in this
+    /// table make `jarl check` fail. If a rule appears both by name and
+    /// through its group, the severity given by name is used.
+    pub severity: Option<BTreeMap<String, String>>,
+
     /// # Patterns to exclude from checking
     ///
     /// By default, jarl will refuse to check files matched by patterns listed in
@@ -170,6 +190,231 @@ pub struct LinterTomlOptions {
     /// This can be either `"<-"` or `"="`. Both are valid in R, so this
     /// option is useful to ensure consistency in a project.
     pub assignment: Option<String>,
+    /// # Minimum size of duplicated blocks
+    ///
+    /// The minimum number of consecutive statements that must appear several
+    /// times in a file to be reported by the rule `duplicate_block`. This must
+    /// be at least 2 and defaults to 3.
+    pub duplicate_block_min_size: Option<usize>,
+    /// # Functions reported by `undesirable_function`
+    ///
+    /// A table mapping names of functions to avoid to the message displayed
+    /// when they are used. For example:
+    ///
+    /// ```toml
+    /// [lint.undesirable_function]
+    /// setwd = "Use paths relative to the project instead."
+    /// library = ""
+    /// ```
+    ///
+    /// This replaces the default list of undesirable functions (`attach`,
+    /// `sapply`, `setwd`, and `source`). It is only useful if the rule
+    /// `undesirable_function` is active.
+    #[serde(rename = "undesirable_function")]
+    pub undesirable_function: Option<BTreeMap<String, String>>,
+    /// # Operators reported by `undesirable_operator`
+    ///
+    /// A table mapping operators to avoid to the message displayed when they
+    /// are used. For example:
+    ///
+    /// ```toml
+    /// [lint.undesirable_operator]
+    /// ":::" = "Use exported functions instead."
+    /// "<<-" = ""
+    /// ```
+    ///
+    /// This replaces the default list of undesirable operators (`:::`). It is
+    /// only useful if the rule `undesirable_operator` is active.
+    #[serde(rename = "undesirable_operator")]
+    pub undesirable_operator: Option<BTreeMap<String, String>>,
+    /// # Options of `nested_ifelse`
+    ///
+    /// For example:
+    ///
+    /// ```toml
+    /// [lint.nested_ifelse]
+    /// max_depth = 3
+    /// ```
+    ///
+    /// It is only useful if the rule `nested_ifelse` is active.
+    #[serde(rename = "nested_ifelse")]
+    pub nested_ifelse: Option<NestedIfelseTomlOptions>,
+    /// # Options of `duplicated_arguments`
+    ///
+    /// For example:
+    ///
+    /// ```toml
+    /// [lint.duplicated_arguments]
+    /// partial_matching = true
+    /// ```
+    ///
+    /// It is only useful if the rule `duplicated_arguments` is active.
+    #[serde(rename = "duplicated_arguments")]
+    pub duplicated_arguments: Option<DuplicatedArgumentsTomlOptions>,
+    /// # Options of `quotes`
+    ///
+    /// For example:
+    ///
+    /// ```toml
+    /// [lint.quotes]
+    /// style = "single"
+    /// ```
+    ///
+    /// It is only useful if the rule `quotes` is active.
+    #[serde(rename = "quotes")]
+    pub quotes: Option<QuotesTomlOptions>,
+    /// # Options of `line_length`
+    ///
+    /// For example:
+    ///
+    /// ```toml
+    /// [lint.line_length]
+    /// max = 120
+    /// ```
+    ///
+    /// It is only useful if the rule `line_length` is active.
+    #[serde(rename = "line_length")]
+    pub line_length: Option<LineLengthTomlOptions>,
+    /// # Options of `infix_spaces`
+    ///
+    /// For example:
+    ///
+    /// ```toml
+    /// [lint.infix_spaces]
+    /// exponent = true
+    /// ```
+    ///
+    /// It is only useful if the rule `infix_spaces` is active.
+    #[serde(rename = "infix_spaces")]
+    pub infix_spaces: Option<InfixSpacesTomlOptions>,
+    /// # Options of `object_name`
+    ///
+    /// For example:
+    ///
+    /// ```toml
+    /// [lint.object_name]
+    /// style = ["snake_case", "UPPER_SNAKE"]
+    /// ```
+    ///
+    /// It is only useful if the rule `object_name` is active.
+    #[serde(rename = "object_name")]
+    pub object_name: Option<ObjectNameTomlOptions>,
+    /// # Options of `pipe_consistency`
+    ///
+    /// For example:
+    ///
+    /// ```toml
+    /// [lint.pipe_consistency]
+    /// style = "native"
+    /// ```
+    ///
+    /// It is only useful if the rule `pipe_consistency` is active.
+    #[serde(rename = "pipe_consistency")]
+    pub pipe_consistency: Option<PipeConsistencyTomlOptions>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct NestedIfelseTomlOptions {
+    /// # Maximum number of nested `ifelse()` calls
+    ///
+    /// Chains of `ifelse()` calls nested in the `no` argument are reported
+    /// when they contain more calls than this. This must be at least 1 and
+    /// defaults to 2.
+    pub max_depth: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct DuplicatedArgumentsTomlOptions {
+    /// # Report partially matching argument names
+    ///
+    /// If `true`, argument names that are a prefix of another argument name
+    /// in the same call (e.g. `ver` and `verbose`) are also reported, since R
+    /// may partially match them to the same argument. This is a heuristic
+    /// that doesn't know the arguments of the called function, so it
+    /// defaults to `false`.
+    pub partial_matching: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct QuotesTomlOptions {
+    /// # Quote used to delimit strings
+    ///
+    /// This can be either `"double"` or `"single"` and defaults to
+    /// `"double"`.
+    pub style: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct LineLengthTomlOptions {
+    /// # Maximum number of characters in a line
+    ///
+    /// Lines with more characters than this are reported. This must be at
+    /// least 1 and defaults to 80.
+    pub max: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct InfixSpacesTomlOptions {
+    /// # Report `^` without spaces
+    ///
+    /// If `true`, the exponent operators `^` and `**` must also be surrounded
+    /// by spaces. They are conventionally written without spaces (e.g.
+    /// `x^2`), so this defaults to `false`.
+    pub exponent: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ObjectNameTomlOptions {
+    /// # Naming styles of objects
+    ///
+    /// A style or a list of styles that names of objects must follow. The
+    /// possible styles are `"snake_case"`, `"camelCase"`, `"CamelCase"`,
+    /// `"dotted.case"`, and `"UPPER_SNAKE"`. Names matching any of the styles
+    /// are accepted. This defaults to `"snake_case"`.
+    pub style: Option<OneOrMany>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct PipeConsistencyTomlOptions {
+    /// # Pipe operator to use
+    ///
+    /// This can be `"native"` to use `|>` everywhere, `"magrittr"` to use
+    /// `%>%` everywhere, or `"auto"` to only report files mixing both pipes.
+    /// In the latter case, the pipe that is used the least in the file is
+    /// reported. This defaults to `"auto"`.
+    pub style: Option<String>,
+}
+
+/// A single string or a list of strings.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl From<OneOrMany> for Vec<String> {
+    fn from(value: OneOrMany) -> Self {
+        match value {
+            OneOrMany::One(value) => vec![value],
+            OneOrMany::Many(values) => values,
+        }
+    }
 }
 
 /// Return the path to the `jarl.toml` or `.jarl.toml` file in a given directory.
@@ -213,6 +458,22 @@ impl TomlOptions {
             default_exclude: linter.default_exclude,
             fixable: linter.fixable,
             unfixable: linter.unfixable,
+            severity: linter.severity,
+            duplicate_block_min_size: linter.duplicate_block_min_size,
+            undesirable_function: linter.undesirable_function,
+            undesirable_operator: linter.undesirable_operator,
+            nested_ifelse_max_depth: linter.nested_ifelse.and_then(|options| options.max_depth),
+            duplicated_arguments_partial_matching: linter
+                .duplicated_arguments
+                .and_then(|options| options.partial_matching),
+            quotes_style: linter.quotes.and_then(|options| options.style),
+            line_length_max: linter.line_length.and_then(|options| options.max),
+            infix_spaces_exponent: linter.infix_spaces.and_then(|options| options.exponent),
+            object_name_styles: linter
+                .object_name
+                .and_then(|options| options.style)
+                .map(Vec::from),
+            pipe_consistency_style: linter.pipe_consistency.and_then(|options| options.style),
         };
 
         Ok(Settings { linter })
@@ -1,12 +1,14 @@
 use crate::diagnostic::Diagnostic;
 use crate::location::Location;
+use crate::utils_ast::assignment_parts;
 use air_r_syntax::{
-    AnyRExpression, RArgument, RArgumentList, RCall, RCallFields, RExtractExpressionFields,
-    RSyntaxNode,
+    AnyRExpression, RArgument, RArgumentList, RBinaryExpression, RBinaryExpressionFields, RCall,
+    RCallFields, RExtractExpressionFields, RFunctionDefinition, RSyntaxKind, RSyntaxNode,
 };
 use anyhow::{Result, anyhow};
 use biome_rowan::AstNode;
 use biome_rowan::AstSeparatedList;
+use biome_rowan::{NodeOrToken, TextRange};
 
 /// Macro to unwrap an Option or return Ok(None) early.
 ///
@@ -41,43 +43,42 @@ pub fn find_new_lines(ast: &RSyntaxNode) -> Result<Vec<usize>> {
     }
 }
 
-/// Takes the start of the range of a Diagnostic and the indices for the new
-/// lines. Returns the (row, col) position of the Diagnostic in the file.
+/// Takes the start or the end of the range of a Diagnostic and the indices for
+/// the new lines. Returns the (row, col) position of this offset in the file.
 ///
 /// The row position is the 1 + the number of new line characters before the
-/// start of the range.
+/// offset.
 /// "1 + 1\nany(is.na(x))"
 /// -> there is one \n so this diagnostic appears on line 2.
 ///
-/// The col position is the number of characters between the start of the range
-/// and the last new line character before the start of the range.
+/// The col position is the number of characters between the offset and the
+/// last new line character before the offset.
 /// "1 + 1\nany(is.na(x))"
 /// -> the range of the diagnostic starts immediately following \n so it's in
 ///    column 0
 ///
+/// A new line character at the offset itself isn't counted, since the end of a
+/// range is exclusive and is often followed by a new line.
+///
 /// Note that the row position is 1-indexed but the column position is 0-indexed.
-pub fn find_row_col(start: usize, loc_new_lines: &[usize]) -> (usize, usize) {
+pub fn find_row_col(offset: usize, loc_new_lines: &[usize]) -> (usize, usize) {
     let new_lines_before = loc_new_lines
         .iter()
-        .filter(|x| *x <= &start)
+        .filter(|x| **x < offset)
         .collect::<Vec<&usize>>();
     let n_new_lines = new_lines_before.len();
-    let last_new_line = match new_lines_before.last() {
-        Some(x) => **x,
-        None => 0_usize,
-    };
 
-    let col: usize = if last_new_line == 0 {
-        start
-    } else {
-        start - last_new_line - 1
+    let col: usize = match new_lines_before.last() {
+        Some(last_new_line) => offset - **last_new_line - 1,
+        None => offset,
     };
     let row: usize = n_new_lines + 1;
     (row, col)
 }
 
 /// Takes a vector of `Diagnostic`s, all of which come with a range, and convert
-/// this range into actual (row, col) location using the position of new lines.
+/// the start and the end of this range into actual (row, col) locations using
+/// the position of new lines.
 pub fn compute_lints_location(
     diagnostics: Vec<Diagnostic>,
     loc_new_lines: &[usize],
@@ -88,11 +89,57 @@ pub fn compute_lints_location(
             let start: usize = diagnostic.range.start().into();
             let loc = find_row_col(start, loc_new_lines);
             diagnostic.location = Some(Location::new(loc.0, loc.1));
+            let end: usize = diagnostic.range.end().into();
+            let end_loc = find_row_col(end, loc_new_lines);
+            diagnostic.end_location = Some(Location::new(end_loc.0, end_loc.1));
+            diagnostic
+        })
+        .collect()
+}
+
+/// Takes a vector of `Diagnostic`s and stores the name of the function in which
+/// each of them is located, if any.
+pub fn compute_enclosing_functions(
+    diagnostics: Vec<Diagnostic>,
+    root: &RSyntaxNode,
+) -> Vec<Diagnostic> {
+    diagnostics
+        .into_iter()
+        .map(|mut diagnostic| {
+            diagnostic.enclosing_function = find_enclosing_function_name(root, diagnostic.range);
             diagnostic
         })
         .collect()
 }
 
+/// Find the name of the closest named function that contains `range`, e.g.
+/// `f` in `f <- function(x) { any(is.na(x)) }`.
+///
+/// Anonymous functions (e.g. `function(x) x` in `lapply(y, function(x) x)`)
+/// are skipped, so the name of the closest named function containing them is
+/// returned instead. Returns `None` if `range` isn't in a named function.
+pub fn find_enclosing_function_name(root: &RSyntaxNode, range: TextRange) -> Option<String> {
+    if !root.text_range().contains_range(range) {
+        return None;
+    }
+    let node = match root.covering_element(range) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+
+    node.ancestors()
+        .filter(|ancestor| ancestor.text_trimmed_range() != range)
+        .filter_map(RFunctionDefinition::cast)
+        .find_map(|func| get_assigned_name(&func))
+}
+
+// Returns the name to which a function definition is assigned, if any.
+fn get_assigned_name(func: &RFunctionDefinition) -> Option<String> {
+    let parent = RBinaryExpression::cast(func.syntax().parent()?)?;
+    let (target, value) = assignment_parts(&parent)?;
+    (value.syntax() == func.syntax()).then(|| target.to_trimmed_string())
+}
+
 /// Takes a list of arguments and returns all the unnamed ones (mostly used when a function has `...`).
 pub fn get_unnamed_args(args: &RArgumentList) -> Vec<RArgument> {
     args.into_iter()
@@ -285,6 +332,23 @@ pub fn get_nested_functions_content(
     outer_fn: &str,
     inner_fn: &str,
 ) -> Result<Option<String>> {
+    let Some(inner_call) = get_nested_function_call(call, outer_fn, inner_fn)? else {
+        return Ok(None);
+    };
+
+    let inner_content = inner_call.arguments()?.items().into_syntax().to_string();
+
+    Ok(Some(inner_content))
+}
+
+// Same as `get_nested_functions_content()` but returns the inner call itself,
+// e.g. `duplicated(x)` in `any(duplicated(x))`, so that its arguments can be
+// inspected.
+pub fn get_nested_function_call(
+    call: &RCall,
+    outer_fn: &str,
+    inner_fn: &str,
+) -> Result<Option<RCall>> {
     let RCallFields { function, arguments } = call.as_fields();
 
     let function = function?;
@@ -310,18 +374,14 @@ pub fn get_nested_functions_content(
     if let Some(inner) = value
         && let Some(inner2) = inner.as_r_call()
     {
-        let RCallFields { function, arguments } = inner2.as_fields();
-
-        let function = function?;
+        let function = inner2.function()?;
         let inner_fn_name = get_function_name(function);
 
         if inner_fn_name != inner_fn {
             return Ok(None);
         }
 
-        let inner_content = arguments?.items().into_syntax().to_string();
-
-        Ok(Some(inner_content))
+        Ok(Some(inner2.clone()))
     } else {
         Ok(None)
     }
@@ -354,3 +414,32 @@ pub fn node_contains_comments(node: &air_r_syntax::RSyntaxNode) -> bool {
         && !node.has_trailing_comments()
         && !node.has_leading_comments()
 }
+
+/// Returns the comparison operator that is the negation of `operator`, e.g.
+/// `<=` for `>`. Returns `None` if `operator` isn't a comparison operator.
+pub fn negated_comparison_operator(operator: RSyntaxKind) -> Option<&'static str> {
+    match operator {
+        RSyntaxKind::GREATER_THAN => Some("<="),
+        RSyntaxKind::GREATER_THAN_OR_EQUAL_TO => Some("<"),
+        RSyntaxKind::LESS_THAN => Some(">="),
+        RSyntaxKind::LESS_THAN_OR_EQUAL_TO => Some(">"),
+        RSyntaxKind::EQUAL2 => Some("!="),
+        RSyntaxKind::NOT_EQUAL => Some("=="),
+        _ => None,
+    }
+}
+
+/// Is this call on the right side of a pipe, e.g. `x |> c()` or `x %>% c()`?
+/// In this case, the call has an implicit first argument.
+pub fn is_piped(ast: &RCall) -> bool {
+    let Some(parent) = ast.syntax().parent().and_then(RBinaryExpression::cast) else {
+        return false;
+    };
+    let RBinaryExpressionFields { operator, right, .. } = parent.as_fields();
+    let (Ok(operator), Ok(right)) = (operator, right) else {
+        return false;
+    };
+    let is_pipe = operator.kind() == RSyntaxKind::PIPE
+        || (operator.kind() == RSyntaxKind::SPECIAL && operator.text_trimmed() == "%>%");
+    is_pipe && right.syntax() == ast.syntax()
+}
@@ -1,7 +1,9 @@
 //! Extension traits for AST nodes providing ergonomic helper methods.
 
+use crate::diagnostic::Fix;
+use crate::utils::{get_function_name, node_contains_comments};
 use air_r_syntax::*;
-use biome_rowan::AstNode;
+use biome_rowan::{AstNode, TextSize};
 
 /// Extension trait for R AST nodes providing common parent and sibling checks.
 pub trait AstNodeExt: AstNode<Language = RLanguage> {
@@ -67,6 +69,26 @@ pub trait AstNodeExt: AstNode<Language = RLanguage> {
             .unwrap_or(false)
     }
 
+    /// Returns the statement just before this node if this node is a statement,
+    /// i.e. if it is directly in a braced block or at the top level of the file.
+    fn previous_statement(&self) -> Option<AnyRExpression> {
+        let parent = self.syntax().parent()?;
+        if !RExpressionList::can_cast(parent.kind()) {
+            return None;
+        }
+        self.syntax().prev_sibling().and_then(AnyRExpression::cast)
+    }
+
+    /// Returns the statement just after this node if this node is a statement,
+    /// i.e. if it is directly in a braced block or at the top level of the file.
+    fn next_statement(&self) -> Option<AnyRExpression> {
+        let parent = self.syntax().parent()?;
+        if !RExpressionList::can_cast(parent.kind()) {
+            return None;
+        }
+        self.syntax().next_sibling().and_then(AnyRExpression::cast)
+    }
+
     /// Returns true if parent is a unary expression with a BANG operator.
     /// This returns false for rlang's `!!` and `!!!`.
     fn parent_is_bang_unary(&self) -> bool {
@@ -89,3 +111,232 @@ pub trait AstNodeExt: AstNode<Language = RLanguage> {
 
 // Blanket implementation for all R AST node types
 impl<T> AstNodeExt for T where T: AstNode<Language = RLanguage> {}
+
+/// Functions returning the length or a dimension of their argument.
+pub const LENGTH_LIKE_FUNCTIONS: [&str; 5] = ["length", "nrow", "ncol", "NROW", "NCOL"];
+
+/// A call to one of [LENGTH_LIKE_FUNCTIONS], e.g. `nrow(x)`.
+///
+/// This is used by rules that replace sequences built from the length of an
+/// object, such as `1:nrow(x)` or `seq(nrow(x))`, by `seq_len()` or
+/// `seq_along()`.
+#[derive(Debug, PartialEq)]
+pub struct LengthLikeCall {
+    /// Name of the function, e.g. `nrow`.
+    pub function: String,
+    /// Arguments of the call, e.g. `x`.
+    pub arguments: String,
+}
+
+impl LengthLikeCall {
+    /// Returns `None` if `expr` isn't a call to a length-like function.
+    pub fn from_expression(expr: &AnyRExpression) -> Option<Self> {
+        let call = expr.as_r_call()?;
+        let function = get_function_name(call.function().ok()?);
+
+        if !LENGTH_LIKE_FUNCTIONS.contains(&function.as_str()) {
+            return None;
+        }
+
+        let arguments = call
+            .arguments()
+            .ok()?
+            .items()
+            .into_iter()
+            .map(|x| x.map(|x| x.to_trimmed_string()))
+            .collect::<SyntaxResult<Vec<String>>>()
+            .ok()?
+            .join(", ");
+
+        Some(Self { function, arguments })
+    }
+
+    /// The sequence to suggest, e.g. `seq_len(nrow(...))`.
+    pub fn suggestion(&self) -> String {
+        self.sequence("...")
+    }
+
+    /// The sequence replacing the original code, e.g. `seq_len(nrow(x))`.
+    pub fn replacement(&self) -> String {
+        self.sequence(&self.arguments)
+    }
+
+    fn sequence(&self, arguments: &str) -> String {
+        match self.function.as_str() {
+            "length" => format!("seq_along({arguments})"),
+            _ => format!("seq_len({}({arguments}))", self.function),
+        }
+    }
+}
+
+/// The content of a string literal as written in the code, without its quotes
+/// (and without the delimiters of raw strings such as `r"(...)"`). Escape
+/// sequences are kept as is, e.g. `"a\\b"` gives `a\\b`.
+pub fn string_literal_content(value: &RStringValue) -> Option<String> {
+    let token = value.value_token().ok()?;
+    let text = token.text_trimmed();
+
+    if let Some(raw) = text.strip_prefix(['r', 'R']) {
+        // Raw strings look like `r"(...)"`, `r"[...]"`, or `r"--{...}--"`.
+        let dashes = raw.chars().skip(1).take_while(|c| *c == '-').count();
+        let delimiter_length = dashes + 2;
+        return raw
+            .get(delimiter_length..raw.len().checked_sub(delimiter_length)?)
+            .map(String::from);
+    }
+
+    text.get(1..text.len().checked_sub(1)?).map(String::from)
+}
+
+/// Build a fix replacing the whole `node` by `content`.
+///
+/// The fix is skipped if `node` contains comments since they would be lost.
+pub fn fix_replacing_node(node: &RSyntaxNode, content: String) -> Fix {
+    let range = node.text_trimmed_range();
+    Fix {
+        content,
+        start: range.start().into(),
+        end: range.end().into(),
+        to_skip: node_contains_comments(node),
+    }
+}
+
+/// The target and the value of an assignment with `<-`, `<<-`, `=`, `->`, or
+/// `->>`, e.g. `x` and `1` in both `x <- 1` and `1 -> x`. Return `None` if
+/// `ast` isn't an assignment.
+pub fn assignment_parts(ast: &RBinaryExpression) -> Option<(AnyRExpression, AnyRExpression)> {
+    let operator = ast.operator().ok()?;
+    let left = ast.left().ok()?;
+    let right = ast.right().ok()?;
+
+    match operator.kind() {
+        RSyntaxKind::ASSIGN | RSyntaxKind::SUPER_ASSIGN | RSyntaxKind::EQUAL => Some((left, right)),
+        RSyntaxKind::ASSIGN_RIGHT | RSyntaxKind::SUPER_ASSIGN_RIGHT => Some((right, left)),
+        _ => None,
+    }
+}
+
+/// The operator, the left side, and the right side of a pipe with `|>`,
+/// `%>%`, or `%T>%`, e.g. `%>%`, `x`, and `f()` in `x %>% f()`. Return `None`
+/// if `ast` isn't a pipe.
+pub fn pipe_parts(
+    ast: &RBinaryExpression,
+) -> Option<(RSyntaxToken, AnyRExpression, AnyRExpression)> {
+    let operator = ast.operator().ok()?;
+    let is_pipe = match operator.kind() {
+        RSyntaxKind::PIPE => true,
+        RSyntaxKind::SPECIAL => matches!(operator.text_trimmed(), "%>%" | "%T>%"),
+        _ => false,
+    };
+    if !is_pipe {
+        return None;
+    }
+    Some((operator, ast.left().ok()?, ast.right().ok()?))
+}
+
+/// The statement containing `node`, i.e. its ancestor whose parent is a list
+/// of expressions (the file or a `{ }` block). This is used by rules working on
+/// the text of the file, so that suppression comments apply to the whole
+/// statement. Return `node` itself if it isn't in a statement.
+pub fn enclosing_statement(node: &RSyntaxNode) -> RSyntaxNode {
+    node.ancestors()
+        .find(|n| {
+            n.parent()
+                .is_some_and(|p| p.kind() == RSyntaxKind::R_EXPRESSION_LIST)
+        })
+        .unwrap_or_else(|| node.clone())
+}
+
+/// The statement containing the text at `offset`, including comments and
+/// whitespace. See [enclosing_statement()].
+pub fn statement_at_offset(root: &RSyntaxNode, offset: TextSize) -> RSyntaxNode {
+    root.token_at_offset(offset)
+        .right_biased()
+        .and_then(|token| token.parent())
+        .map_or_else(|| root.clone(), |node| enclosing_statement(&node))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use air_r_parser::{RParserOptions, parse};
+
+    fn first_expression(code: &str) -> AnyRExpression {
+        let parsed = parse(code, RParserOptions::default());
+        parsed.tree().expressions().into_iter().next().unwrap()
+    }
+
+    fn length_like_call(code: &str) -> Option<LengthLikeCall> {
+        LengthLikeCall::from_expression(&first_expression(code))
+    }
+
+    #[test]
+    fn test_length_like_call() {
+        let expected = [
+            ("length(x)", "seq_along(...)", "seq_along(x)"),
+            ("nrow(x)", "seq_len(nrow(...))", "seq_len(nrow(x))"),
+            ("ncol(x)", "seq_len(ncol(...))", "seq_len(ncol(x))"),
+            ("NROW(x)", "seq_len(NROW(...))", "seq_len(NROW(x))"),
+            ("NCOL(x)", "seq_len(NCOL(...))", "seq_len(NCOL(x))"),
+        ];
+
+        for (code, suggestion, replacement) in expected {
+            let call = length_like_call(code).unwrap();
+            assert_eq!(call.suggestion(), suggestion);
+            assert_eq!(call.replacement(), replacement);
+        }
+    }
+
+    #[test]
+    fn test_length_like_call_arguments() {
+        assert_eq!(
+            length_like_call("base::nrow(foo(x))"),
+            Some(LengthLikeCall {
+                function: "nrow".to_string(),
+                arguments: "foo(x)".to_string()
+            })
+        );
+        assert_eq!(
+            length_like_call("length( x )").unwrap().replacement(),
+            "seq_along(x)"
+        );
+    }
+
+    #[test]
+    fn test_not_length_like_call() {
+        assert_eq!(length_like_call("x"), None);
+        assert_eq!(length_like_call("lengths(x)"), None);
+        assert_eq!(length_like_call("dim(x)"), None);
+        assert_eq!(length_like_call("nrow"), None);
+        assert_eq!(length_like_call("1:nrow(x)"), None);
+    }
+
+    #[test]
+    fn test_string_literal_content() {
+        let content = |code: &str| {
+            let expr = first_expression(code);
+            let value = expr.as_any_r_value().unwrap().as_r_string_value().unwrap();
+            string_literal_content(value)
+        };
+        assert_eq!(content("\"abc\"").unwrap(), "abc");
+        assert_eq!(content("'abc'").unwrap(), "abc");
+        assert_eq!(content("\"\"").unwrap(), "");
+        assert_eq!(content("\"a\\\\b\"").unwrap(), "a\\\\b");
+        assert_eq!(content("r\"(abc)\"").unwrap(), "abc");
+        assert_eq!(content("R'[abc]'").unwrap(), "abc");
+        assert_eq!(content("r\"--{a)\"b}--\"").unwrap(), "a)\"b");
+    }
+
+    #[test]
+    fn test_fix_replacing_node() {
+        let expr = first_expression("1:length(x)");
+        let fix = fix_replacing_node(expr.syntax(), "seq_along(x)".to_string());
+        assert_eq!(fix.content, "seq_along(x)");
+        assert_eq!((fix.start, fix.end), (0, 11));
+        assert!(!fix.to_skip);
+
+        let expr = first_expression("1:length(\n # a comment \nx)");
+        let fix = fix_replacing_node(expr.syntax(), "seq_along(x)".to_string());
+        assert!(fix.to_skip);
+    }
+}
@@ -28,6 +28,12 @@ pub fn has_lint(text: &str, msg: &str, rule: &str, min_r_version: Option<&str>)
         allow_dirty: false,
         allow_no_vcs: true,
         assignment: None,
+        show_context: false,
+        atomic: false,
+        only: None,
+        roxygen_examples: false,
+        min_confidence: None,
+        fix_iterations: None,
     };
 
     let mut resolver = PathResolver::new(Settings::default());
@@ -89,6 +95,12 @@ pub fn has_no_lint(text: &str, rule: &str, min_r_version: Option<&str>) -> bool
         allow_dirty: false,
         allow_no_vcs: true,
         assignment: None,
+        show_context: false,
+        atomic: false,
+        only: None,
+        roxygen_examples: false,
+        min_confidence: None,
+        fix_iterations: None,
     };
 
     let mut resolver = PathResolver::new(Settings::default());
@@ -147,6 +159,12 @@ pub fn apply_fixes(
         allow_dirty: false,
         allow_no_vcs: true,
         assignment: None,
+        show_context: false,
+        atomic: false,
+        only: None,
+        roxygen_examples: false,
+        min_confidence: None,
+        fix_iterations: None,
     };
 
     let mut resolver = PathResolver::new(Settings::default());
@@ -193,6 +211,12 @@ pub fn check_code(text: &str, rule: &str, min_r_version: Option<&str>) -> Vec<Di
         allow_dirty: false,
         allow_no_vcs: true,
         assignment: None,
+        show_context: false,
+        atomic: false,
+        only: None,
+        roxygen_examples: false,
+        min_confidence: None,
+        fix_iterations: None,
     };
 
     let mut resolver = PathResolver::new(Settings::default());
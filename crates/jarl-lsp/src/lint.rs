@@ -4,10 +4,11 @@
 //! It handles diagnostics, code actions, and fixes for automatic issue resolution.
 
 use anyhow::{Result, anyhow};
-use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range, TextEdit, Url, WorkspaceEdit};
 use serde::{Deserialize, Serialize};
 use tempfile::TempDir;
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use crate::DIAGNOSTIC_SOURCE;
@@ -19,7 +20,7 @@ use air_workspace::resolve::PathResolver;
 use jarl_core::discovery::{DiscoveredSettings, discover_r_file_paths, discover_settings};
 use jarl_core::{
     config::ArgsConfig, config::build_config, diagnostic::Diagnostic as JarlDiagnostic,
-    settings::Settings,
+    diagnostic::Severity as JarlSeverity, rule_set::Rule, settings::Settings,
 };
 
 /// Fix information that can be attached to a diagnostic for code actions
@@ -28,16 +29,44 @@ pub struct DiagnosticFix {
     pub content: String,
     pub start: usize,
     pub end: usize,
+    /// Whether the fix is always correct (`Applicability::Always`). Those fixes
+    /// are marked as preferred in code actions.
     pub is_safe: bool,
     pub rule_name: String,
 }
 
+impl DiagnosticFix {
+    /// The fix of a Jarl diagnostic. This is also used by `jarl check` in the
+    /// JSON output, so that the CLI and the LSP share one representation.
+    pub fn new(diagnostic: &JarlDiagnostic) -> Self {
+        Self {
+            content: diagnostic.fix.content.clone(),
+            start: diagnostic.fix.start,
+            end: diagnostic.fix.end,
+            is_safe: diagnostic.has_safe_fix(),
+            rule_name: diagnostic.message.name.clone(),
+        }
+    }
+
+    /// Whether this fix can be applied by the "fix all" action, which only
+    /// uses safe fixes of rules that allow it (see [Rule::is_lsp_autofix()]).
+    /// Other fixes are only offered as individual quick fixes.
+    pub fn is_in_fix_all(&self) -> bool {
+        self.is_safe && Rule::from_name(&self.rule_name).is_some_and(Rule::is_lsp_autofix)
+    }
+}
+
 /// Main entry point for linting a document
 ///
 /// Takes a document snapshot, runs your Jarl linter, and returns LSP diagnostics
 /// for highlighting issues in the editor. The diagnostics include fix information
 /// that can be used for code actions if needed.
 pub fn lint_document(snapshot: &DocumentSnapshot) -> Result<Vec<Diagnostic>> {
+    if !is_r_document(snapshot) {
+        tracing::debug!("Skipping linting for non-R document: {}", snapshot.uri());
+        return Ok(Vec::new());
+    }
+
     let content = snapshot.content();
     let file_path = snapshot.file_path();
     let encoding = snapshot.position_encoding();
@@ -55,6 +84,30 @@ pub fn lint_document(snapshot: &DocumentSnapshot) -> Result<Vec<Diagnostic>> {
     Ok(lsp_diagnostics)
 }
 
+/// Whether the document only contains R code and can be linted
+///
+/// The language ID sent by the client is used if there is one, so that other
+/// documents that the client sends us (e.g. `python`) are skipped. R Markdown
+/// and Quarto documents are also skipped based on their extension since
+/// linting them as R code would report their prose.
+pub fn is_r_document(snapshot: &DocumentSnapshot) -> bool {
+    if let Some(language_id) = snapshot.language_id()
+        && !language_id.eq_ignore_ascii_case("r")
+    {
+        return false;
+    }
+
+    let is_markdown = snapshot
+        .file_path()
+        .and_then(|path| {
+            path.extension()
+                .map(|ext| ext.to_string_lossy().to_lowercase())
+        })
+        .is_some_and(|ext| matches!(ext.as_str(), "rmd" | "qmd" | "rmarkdown"));
+
+    !is_markdown
+}
+
 /// Run the Jarl linting engine on the given content
 fn run_jarl_linting(content: &str, file_path: Option<&Path>) -> Result<Vec<JarlDiagnostic>> {
     let file_path = match file_path {
@@ -99,7 +152,7 @@ fn run_jarl_linting(content: &str, file_path: Option<&Path>) -> Result<Vec<JarlD
     let temp_path: Vec<String> = vec![temp_path_str];
 
     // Use temp path for discovering R file paths (just the temp file itself)
-    let paths = discover_r_file_paths(&temp_path, &resolver, true, true)
+    let paths = discover_r_file_paths(&temp_path, &resolver, true, true, false)
         .into_iter()
         .filter_map(Result::ok)
         .collect::<Vec<_>>();
@@ -116,6 +169,12 @@ fn run_jarl_linting(content: &str, file_path: Option<&Path>) -> Result<Vec<JarlD
         allow_dirty: false,
         allow_no_vcs: false,
         assignment: None,
+        show_context: false,
+        atomic: false,
+        only: None,
+        roxygen_examples: false,
+        min_confidence: None,
+        fix_iterations: None,
     };
 
     let config = build_config(&check_config, &resolver, paths)?;
@@ -164,19 +223,15 @@ fn convert_to_lsp_diagnostic(
 
     let range = Range::new(start_pos, end_pos);
 
-    // TODO-etienne: don't have that
-    // let severity = convert_severity(jarl_diag.severity);
-    let severity = DiagnosticSeverity::WARNING;
+    // Rules without a severity in `jarl.toml` are shown as warnings.
+    let severity = jarl_diag
+        .severity
+        .map(convert_severity)
+        .unwrap_or(DiagnosticSeverity::WARNING);
 
     // Extract fix information if available
     // Always include fix_data even if there's no actual fix, so we can access the rule_name
-    let diagnostic_fix = DiagnosticFix {
-        content: jarl_diag.fix.content.clone(),
-        start: jarl_diag.fix.start,
-        end: jarl_diag.fix.end,
-        is_safe: jarl_diag.has_safe_fix(),
-        rule_name: jarl_diag.message.name.clone(),
-    };
+    let diagnostic_fix = DiagnosticFix::new(jarl_diag);
     let fix_data = Some(serde_json::to_value(diagnostic_fix).unwrap_or_default());
 
     // Build the LSP diagnostic with fix information
@@ -273,15 +328,61 @@ pub fn byte_offset_to_lsp_position(
     Ok(Position::new(line as u32, lsp_character))
 }
 
-// /// Convert Jarl severity to LSP diagnostic severity
-// fn convert_severity(severity: JarlSeverity) -> DiagnosticSeverity {
-//     match severity {
-//         JarlSeverity::Error => DiagnosticSeverity::ERROR,
-//         JarlSeverity::Warning => DiagnosticSeverity::WARNING,
-//         JarlSeverity::Info => DiagnosticSeverity::INFORMATION,
-//         JarlSeverity::Hint => DiagnosticSeverity::HINT,
-//     }
-// }
+/// Convert the safe fixes of Jarl diagnostics to a single `WorkspaceEdit`,
+/// with the edits of each file keyed by its URI
+///
+/// This is used outside of the server, by clients that apply edits in batch.
+/// Files are read from disk to convert the byte offsets of the fixes to LSP
+/// positions (in UTF-16, the default encoding of LSP). Fixes that overlap a
+/// previous fix of the same file are dropped since they can't be applied in
+/// a single pass.
+pub fn diagnostics_to_workspace_edit(diagnostics: &[&JarlDiagnostic]) -> Result<WorkspaceEdit> {
+    let mut fixes_by_file: BTreeMap<&Path, Vec<&JarlDiagnostic>> = BTreeMap::new();
+    for diagnostic in diagnostics.iter().filter(|d| d.has_safe_fix()) {
+        fixes_by_file
+            .entry(diagnostic.filename.as_path())
+            .or_default()
+            .push(diagnostic);
+    }
+
+    let mut changes = HashMap::new();
+    for (path, mut fixes) in fixes_by_file {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))?;
+        let uri = Url::from_file_path(std::path::absolute(path)?)
+            .map_err(|_| anyhow!("Failed to convert {} to a URI", path.display()))?;
+
+        fixes.sort_by_key(|d| (d.fix.start, d.fix.end));
+
+        let mut edits = Vec::new();
+        let mut last_end = 0;
+        for diagnostic in fixes {
+            let fix = &diagnostic.fix;
+            if fix.start < last_end {
+                continue;
+            }
+            let start = byte_offset_to_lsp_position(fix.start, &content, PositionEncoding::UTF16)?;
+            let end = byte_offset_to_lsp_position(fix.end, &content, PositionEncoding::UTF16)?;
+            edits.push(TextEdit {
+                range: Range::new(start, end),
+                new_text: fix.content.clone(),
+            });
+            last_end = fix.end;
+        }
+        changes.insert(uri, edits);
+    }
+
+    Ok(WorkspaceEdit { changes: Some(changes), ..Default::default() })
+}
+
+/// Convert Jarl severity to LSP diagnostic severity
+fn convert_severity(severity: JarlSeverity) -> DiagnosticSeverity {
+    match severity {
+        JarlSeverity::Error => DiagnosticSeverity::ERROR,
+        JarlSeverity::Warning => DiagnosticSeverity::WARNING,
+        JarlSeverity::Info => DiagnosticSeverity::INFORMATION,
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -14,10 +14,12 @@ use std::time::Instant;
 
 use crate::LspResult;
 use crate::client::{Client, ToLspError};
-use crate::document::TextDocument;
+use crate::document::{DocumentVersion, TextDocument};
 use crate::lint;
 use crate::session::{DocumentSnapshot, Session, negotiate_position_encoding};
 
+use jarl_core::rule_set::Rule;
+
 /// Main LSP server
 pub struct Server {
     connection: Connection,
@@ -32,6 +34,13 @@ pub enum Event {
     Message(Message),
     /// Internal task to send a response
     SendResponse(Response),
+    /// Publish the diagnostics of a version of a document, unless it changed
+    /// in the meantime
+    PublishDiagnostics {
+        uri: types::Url,
+        version: DocumentVersion,
+        diagnostics: Vec<types::Diagnostic>,
+    },
     /// Shutdown the server
     Shutdown,
 }
@@ -39,10 +48,7 @@ pub enum Event {
 /// Background task that can be executed by worker threads
 pub enum Task {
     /// Lint a document and publish diagnostics
-    LintDocument {
-        snapshot: Box<DocumentSnapshot>,
-        client: Client,
-    },
+    LintDocument { snapshot: Box<DocumentSnapshot> },
     /// Handle a diagnostic request
     HandleDiagnosticRequest {
         snapshot: Box<DocumentSnapshot>,
@@ -56,6 +62,13 @@ pub enum Task {
         params: Box<types::CodeActionParams>,
         client: Client,
     },
+    /// Handle a hover request
+    HandleHoverRequest {
+        snapshot: Box<DocumentSnapshot>,
+        request_id: RequestId,
+        params: Box<types::HoverParams>,
+        client: Client,
+    },
 }
 
 impl Server {
@@ -146,6 +159,12 @@ impl Server {
         tracing::info!("Starting main event loop");
 
         loop {
+            // Wake up when the next document that changed must be linted
+            let scheduled_lint = session
+                .next_scheduled_lint()
+                .map(channel::at)
+                .unwrap_or_else(channel::never);
+
             crossbeam::select! {
                 // Handle LSP messages from client
                 recv(self.connection.receiver) -> msg => {
@@ -174,6 +193,13 @@ impl Server {
                                 tracing::error!("Error sending response: {}", e);
                             }
                         }
+                        Ok(Event::PublishDiagnostics { uri, version, diagnostics }) => {
+                            if !session.is_latest_version(&uri, version) {
+                                tracing::debug!("Dropping outdated diagnostics of {} (version {})", uri, version);
+                            } else if let Err(e) = session.client().publish_diagnostics(uri, diagnostics, Some(version)) {
+                                tracing::error!("Error publishing diagnostics: {}", e);
+                            }
+                        }
                         Ok(Event::Shutdown) => {
                             tracing::info!("Shutdown event received");
                             break;
@@ -184,6 +210,14 @@ impl Server {
                         }
                     }
                 }
+                // Lint the documents that haven't changed for the debounce delay
+                recv(scheduled_lint) -> _ => {
+                    for snapshot in session.take_due_lints(Instant::now()) {
+                        if let Err(e) = task_sender.send(Task::LintDocument { snapshot: Box::new(snapshot) }) {
+                            tracing::error!("Error scheduling lint task: {}", e);
+                        }
+                    }
+                }
             }
 
             if session.is_shutdown_requested() {
@@ -266,6 +300,29 @@ impl Server {
                 }
                 Ok(())
             }
+            types::request::HoverRequest::METHOD => {
+                let params: types::HoverParams = serde_json::from_value(request.params)?;
+                let uri = params
+                    .text_document_position_params
+                    .text_document
+                    .uri
+                    .clone();
+
+                if let Some(snapshot) = session.take_snapshot(uri) {
+                    task_sender.send(Task::HandleHoverRequest {
+                        snapshot: Box::new(snapshot),
+                        request_id: request.id,
+                        params: Box::new(params),
+                        client,
+                    })?;
+                } else {
+                    client.send_error_response(
+                        request.id,
+                        anyhow!("Document not found").to_lsp_error(),
+                    )?;
+                }
+                Ok(())
+            }
             _ => {
                 tracing::debug!(
                     "Unhandled request method: {} (not supported in diagnostics-only mode)",
@@ -312,6 +369,7 @@ impl Server {
                 // Check and notify about config file location (once per session, only if not in CWD)
                 if let Ok(file_path) = params.text_document.uri.to_file_path() {
                     session.check_and_notify_config(&file_path);
+                    session.check_config_errors(&[file_path]);
                 }
 
                 // Trigger linting for push diagnostics (real-time as you type)
@@ -320,10 +378,7 @@ impl Server {
                 if !supports_pull_diagnostics
                     && let Some(snapshot) = session.take_snapshot(params.text_document.uri)
                 {
-                    task_sender.send(Task::LintDocument {
-                        snapshot: Box::new(snapshot),
-                        client: session.client().clone(),
-                    })?;
+                    task_sender.send(Task::LintDocument { snapshot: Box::new(snapshot) })?;
                 }
                 Ok(())
             }
@@ -339,7 +394,10 @@ impl Server {
                     params.text_document.version,
                 )?;
 
-                // Don't trigger linting on every change, only on save
+                // Lint once the user stops typing rather than on every change
+                if !session.supports_pull_diagnostics() {
+                    session.schedule_lint(params.text_document.uri);
+                }
                 Ok(())
             }
             types::notification::DidCloseTextDocument::METHOD => {
@@ -354,21 +412,32 @@ impl Server {
                     .publish_diagnostics(params.text_document.uri, vec![], None)?;
                 Ok(())
             }
+            types::notification::DidChangeConfiguration::METHOD => {
+                tracing::debug!("Configuration changed");
+
+                // Check the `jarl.toml` of all open documents again
+                let file_paths = session
+                    .open_documents()
+                    .filter_map(|uri| uri.to_file_path().ok())
+                    .collect::<Vec<_>>();
+                session.check_config_errors(&file_paths);
+                Ok(())
+            }
             types::notification::DidSaveTextDocument::METHOD => {
                 let params: types::DidSaveTextDocumentParams =
                     serde_json::from_value(notification.params)?;
 
                 tracing::debug!("Document saved: {}", params.text_document.uri);
 
+                // The document is linted right away
+                session.cancel_scheduled_lint(params.text_document.uri.clone());
+
                 let supports_pull_diagnostics = session.supports_pull_diagnostics();
 
                 if !supports_pull_diagnostics
                     && let Some(snapshot) = session.take_snapshot(params.text_document.uri)
                 {
-                    task_sender.send(Task::LintDocument {
-                        snapshot: Box::new(snapshot),
-                        client: session.client().clone(),
-                    })?;
+                    task_sender.send(Task::LintDocument { snapshot: Box::new(snapshot) })?;
                 }
                 Ok(())
             }
@@ -387,8 +456,8 @@ impl Server {
     ) {
         while let Ok(task) = task_receiver.recv() {
             match task {
-                Task::LintDocument { snapshot, client } => {
-                    if let Err(e) = Self::handle_lint_task(*snapshot, client) {
+                Task::LintDocument { snapshot } => {
+                    if let Err(e) = Self::handle_lint_task(*snapshot, &event_sender) {
                         tracing::error!("Error in lint task: {}", e);
                     }
                 }
@@ -405,12 +474,22 @@ impl Server {
                 Task::HandleCodeActionRequest { snapshot, request_id, params, client } => {
                     Self::handle_code_action_request(*snapshot, request_id, *params, client);
                 }
+                Task::HandleHoverRequest { snapshot, request_id, params, client } => {
+                    Self::handle_hover_request(*snapshot, request_id, *params, client);
+                }
             }
         }
     }
 
     /// Handle linting a document and publishing diagnostics
-    fn handle_lint_task(snapshot: DocumentSnapshot, client: Client) -> LspResult<()> {
+    ///
+    /// The diagnostics are published by the main loop, which drops them if the
+    /// document changed while it was linted: they would be outdated, and the
+    /// new version is already scheduled to be linted.
+    fn handle_lint_task(
+        snapshot: DocumentSnapshot,
+        event_sender: &channel::Sender<Event>,
+    ) -> LspResult<()> {
         let start = Instant::now();
         let diagnostics = lint::lint_document(&snapshot)?;
         let elapsed = start.elapsed();
@@ -422,11 +501,11 @@ impl Server {
             diagnostics.len()
         );
 
-        client.publish_diagnostics(
-            snapshot.uri().clone(),
+        event_sender.send(Event::PublishDiagnostics {
+            uri: snapshot.uri().clone(),
+            version: snapshot.version(),
             diagnostics,
-            Some(snapshot.version()),
-        )?;
+        })?;
         Ok(())
     }
 
@@ -494,28 +573,167 @@ impl Server {
         let mut actions = Vec::new();
 
         // Filter diagnostics that intersect with the requested range
-        for diagnostic in diagnostics {
+        for diagnostic in &diagnostics {
             if ranges_overlap(&diagnostic.range, &params.range) {
                 // Add the regular fix action if available
-                if let Some(action) = Self::diagnostic_to_code_action(&diagnostic, snapshot) {
+                if let Some(action) = Self::diagnostic_to_code_action(diagnostic, snapshot) {
                     actions.push(types::CodeActionOrCommand::CodeAction(action));
                 }
 
                 // Add nolint actions
-                if let Some(action) = Self::diagnostic_to_nolint_rule_action(&diagnostic, snapshot)
-                {
+                if let Some(action) = Self::diagnostic_to_nolint_rule_action(diagnostic, snapshot) {
                     actions.push(types::CodeActionOrCommand::CodeAction(action));
                 }
 
-                if let Some(action) = Self::diagnostic_to_nolint_all_action(&diagnostic, snapshot) {
+                if let Some(action) = Self::diagnostic_to_nolint_all_action(diagnostic, snapshot) {
                     actions.push(types::CodeActionOrCommand::CodeAction(action));
                 }
             }
         }
 
+        // The "fix all" action applies to the whole document, not only to the
+        // requested range
+        if wants_code_action_kind(params, &types::CodeActionKind::SOURCE_FIX_ALL)
+            && let Some(action) = Self::fix_all_code_action(&diagnostics, snapshot)
+        {
+            actions.push(types::CodeActionOrCommand::CodeAction(action));
+        }
+
         Ok(actions)
     }
 
+    /// Combine the safe fixes of all diagnostics of the document in a single
+    /// code action
+    ///
+    /// Only fixes accepted by [DiagnosticFix::is_in_fix_all()] are used. As in
+    /// `jarl check --fix`, fixes are sorted by position and a fix that overlaps
+    /// a previous one is skipped, so that the edits never overlap. Skipped
+    /// violations are fixed by the next "fix all" once the document is linted
+    /// again.
+    fn fix_all_code_action(
+        diagnostics: &[types::Diagnostic],
+        snapshot: &DocumentSnapshot,
+    ) -> Option<types::CodeAction> {
+        let mut fixes = diagnostics
+            .iter()
+            .filter_map(|diagnostic| diagnostic.data.clone())
+            .filter_map(|data| serde_json::from_value::<crate::lint::DiagnosticFix>(data).ok())
+            .filter(|fix| fix.is_in_fix_all())
+            .collect::<Vec<_>>();
+        fixes.sort_by_key(|fix| (fix.start, fix.end));
+
+        let content = snapshot.content();
+        let encoding = snapshot.position_encoding();
+
+        let mut edits = Vec::new();
+        let mut last_end = 0;
+        for fix in fixes {
+            if fix.start < last_end {
+                continue;
+            }
+            let start =
+                crate::lint::byte_offset_to_lsp_position(fix.start, content, encoding).ok()?;
+            let end = crate::lint::byte_offset_to_lsp_position(fix.end, content, encoding).ok()?;
+            edits.push(types::TextEdit {
+                range: types::Range::new(start, end),
+                new_text: fix.content,
+            });
+            last_end = fix.end;
+        }
+
+        if edits.is_empty() {
+            return None;
+        }
+
+        let mut changes = std::collections::HashMap::new();
+        changes.insert(snapshot.uri().clone(), edits);
+
+        Some(types::CodeAction {
+            title: "Fix all safe violations of Jarl".to_string(),
+            kind: Some(types::CodeActionKind::SOURCE_FIX_ALL),
+            diagnostics: None,
+            edit: Some(types::WorkspaceEdit { changes: Some(changes), ..Default::default() }),
+            command: None,
+            is_preferred: None,
+            disabled: None,
+            data: None,
+        })
+    }
+
+    /// Handle a hover request by describing the diagnostic under the cursor
+    fn handle_hover_request(
+        snapshot: DocumentSnapshot,
+        request_id: RequestId,
+        params: types::HoverParams,
+        client: Client,
+    ) {
+        match Self::generate_hover(&snapshot, &params) {
+            Ok(hover) => {
+                if let Err(e) = client.send_response(request_id, hover) {
+                    tracing::error!("Failed to send hover: {}", e);
+                }
+            }
+            Err(e) => {
+                tracing::error!("Failed to generate hover: {}", e);
+                if let Err(send_err) = client.send_error_response(request_id, e.to_lsp_error()) {
+                    tracing::error!("Failed to send error response: {}", send_err);
+                }
+            }
+        }
+    }
+
+    /// Generate the hover for the first diagnostic covering the position, or
+    /// `None` if there is no diagnostic there
+    fn generate_hover(
+        snapshot: &DocumentSnapshot,
+        params: &types::HoverParams,
+    ) -> LspResult<Option<types::Hover>> {
+        let position = params.text_document_position_params.position;
+        let diagnostics = lint::lint_document(snapshot)?;
+
+        Ok(diagnostics
+            .iter()
+            .find(|diagnostic| range_contains(&diagnostic.range, position))
+            .and_then(Self::diagnostic_to_hover))
+    }
+
+    /// Describe a diagnostic with its rule name, message, categories, and fix
+    fn diagnostic_to_hover(diagnostic: &types::Diagnostic) -> Option<types::Hover> {
+        let fix_data = diagnostic.data.as_ref()?;
+        let fix: crate::lint::DiagnosticFix = serde_json::from_value(fix_data.clone()).ok()?;
+
+        let categories = Rule::from_name(&fix.rule_name)
+            .map(|rule| {
+                rule.categories()
+                    .iter()
+                    .map(|c| format!("{} (`{}`)", c.long_name(), c.as_str()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+
+        let fix_status = if fix.content.is_empty() && fix.start == fix.end {
+            "none"
+        } else if fix.is_safe {
+            "safe"
+        } else {
+            "unsafe"
+        };
+
+        let value = format!(
+            "**{}**\n\n{}\n\nCategory: {categories}\n\nFix: {fix_status}",
+            fix.rule_name, diagnostic.message
+        );
+
+        Some(types::Hover {
+            contents: types::HoverContents::Markup(types::MarkupContent {
+                kind: types::MarkupKind::Markdown,
+                value,
+            }),
+            range: Some(diagnostic.range),
+        })
+    }
+
     /// Convert a diagnostic with fix information to a code action
     fn diagnostic_to_code_action(
         diagnostic: &types::Diagnostic,
@@ -765,6 +983,27 @@ fn ranges_overlap(a: &types::Range, b: &types::Range) -> bool {
     a.start <= b.end && b.start <= a.end
 }
 
+/// Check if the client accepts code actions of this kind. Kinds are
+/// hierarchical, so asking for `source` includes `source.fixAll`.
+fn wants_code_action_kind(params: &types::CodeActionParams, kind: &types::CodeActionKind) -> bool {
+    let Some(only) = &params.context.only else {
+        return true;
+    };
+    only.iter().any(|requested| {
+        kind.as_str() == requested.as_str()
+            || kind
+                .as_str()
+                .strip_prefix(requested.as_str())
+                .is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
+/// Check if a position is in a range, including its end so that hovering
+/// right after the last character still works
+fn range_contains(range: &types::Range, position: types::Position) -> bool {
+    range.start <= position && position <= range.end
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -858,6 +1097,65 @@ mod tests {
         assert_eq!(text_edits[0].new_text, "x <- 1");
     }
 
+    #[test]
+    fn test_diagnostic_to_code_action_removing_trailing_whitespace() {
+        // The `\r` of the line ending must be kept.
+        let snapshot = create_test_snapshot("x <- 1  \r\ny <- 2\r\n");
+
+        let fix = DiagnosticFix {
+            content: "".to_string(),
+            start: 6,
+            end: 8,
+            is_safe: true,
+            rule_name: "trailing_whitespace".to_string(),
+        };
+
+        let diagnostic = create_test_diagnostic_with_fix(
+            Range::new(Position::new(0, 6), Position::new(0, 8)),
+            "Trailing whitespace is superfluous. Remove it.".to_string(),
+            fix,
+        );
+
+        let action = Server::diagnostic_to_code_action(&diagnostic, &snapshot).unwrap();
+        let changes = action.edit.unwrap().changes.unwrap();
+        let text_edits = changes.values().next().unwrap();
+        assert_eq!(text_edits.len(), 1);
+        assert_eq!(
+            text_edits[0].range,
+            Range::new(Position::new(0, 6), Position::new(0, 8))
+        );
+        assert_eq!(text_edits[0].new_text, "");
+    }
+
+    #[test]
+    fn test_diagnostic_to_code_action_inserting_final_newline() {
+        let snapshot = create_test_snapshot("x <- 1");
+
+        let fix = DiagnosticFix {
+            content: "\n".to_string(),
+            start: 6,
+            end: 6,
+            is_safe: true,
+            rule_name: "trailing_blank_lines".to_string(),
+        };
+
+        let diagnostic = create_test_diagnostic_with_fix(
+            Range::new(Position::new(0, 0), Position::new(0, 6)),
+            "The file doesn't end with a newline.".to_string(),
+            fix,
+        );
+
+        // An insertion has an empty range but is still a fix.
+        let action = Server::diagnostic_to_code_action(&diagnostic, &snapshot).unwrap();
+        let changes = action.edit.unwrap().changes.unwrap();
+        let text_edits = changes.values().next().unwrap();
+        assert_eq!(
+            text_edits[0].range,
+            Range::new(Position::new(0, 6), Position::new(0, 6))
+        );
+        assert_eq!(text_edits[0].new_text, "\n");
+    }
+
     #[test]
     fn test_diagnostic_to_code_action_with_no_fix() {
         let snapshot = create_test_snapshot("class(x) == \"foo\"\n");
@@ -968,6 +1266,56 @@ mod tests {
         assert_eq!(text_edits[0].new_text, "anyNA(data$column)");
     }
 
+    #[test]
+    fn test_fix_all_excludes_non_lsp_autofix_rules() {
+        let content = "stopifnot(x)\nstopifnot(y)\n";
+        let snapshot = create_test_snapshot(content);
+
+        let fix = DiagnosticFix {
+            content: "stopifnot(x, y)".to_string(),
+            start: 0,
+            end: 25,
+            is_safe: true,
+            rule_name: "consecutive_assertion".to_string(),
+        };
+        // The fix is safe but it isn't applied by the "fix all" action.
+        assert!(!fix.is_in_fix_all());
+
+        let diagnostic = create_test_diagnostic_with_fix(
+            Range::new(Position::new(0, 0), Position::new(1, 12)),
+            "2 consecutive calls to `stopifnot()` can be merged.".to_string(),
+            fix,
+        );
+
+        // It is still offered as an individual quick fix.
+        let action = Server::diagnostic_to_code_action(&diagnostic, &snapshot).unwrap();
+        assert_eq!(action.kind, Some(types::CodeActionKind::QUICKFIX));
+        let changes = action.edit.unwrap().changes.unwrap();
+        let text_edits = changes.values().next().unwrap();
+        assert_eq!(text_edits[0].new_text, "stopifnot(x, y)");
+    }
+
+    #[test]
+    fn test_fix_all_only_includes_safe_fixes() {
+        let safe_fix = DiagnosticFix {
+            content: "anyNA(x)".to_string(),
+            start: 0,
+            end: 13,
+            is_safe: true,
+            rule_name: "any_is_na".to_string(),
+        };
+        assert!(safe_fix.is_in_fix_all());
+
+        let unsafe_fix = DiagnosticFix {
+            content: "isTRUE(all.equal(a, b))".to_string(),
+            start: 4,
+            end: 19,
+            is_safe: false,
+            rule_name: "all_equal".to_string(),
+        };
+        assert!(!unsafe_fix.is_in_fix_all());
+    }
+
     #[test]
     fn test_class_comparison_no_quick_fix() {
         let snapshot = create_test_snapshot("if (class(obj) == \"data.frame\") { }\n");
@@ -1064,6 +1412,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_wants_code_action_kind() {
+        let params_with_only = |only| CodeActionParams {
+            text_document: TextDocumentIdentifier { uri: Url::parse("file:///test.R").unwrap() },
+            range: Range::new(Position::new(0, 0), Position::new(0, 0)),
+            context: CodeActionContext { diagnostics: vec![], only, trigger_kind: None },
+            partial_result_params: Default::default(),
+            work_done_progress_params: Default::default(),
+        };
+        let fix_all = types::CodeActionKind::SOURCE_FIX_ALL;
+
+        assert!(wants_code_action_kind(&params_with_only(None), &fix_all));
+        assert!(wants_code_action_kind(
+            &params_with_only(Some(vec![types::CodeActionKind::SOURCE])),
+            &fix_all
+        ));
+        assert!(wants_code_action_kind(
+            &params_with_only(Some(vec![fix_all.clone()])),
+            &fix_all
+        ));
+        assert!(!wants_code_action_kind(
+            &params_with_only(Some(vec![types::CodeActionKind::QUICKFIX])),
+            &fix_all
+        ));
+        assert!(!wants_code_action_kind(
+            &params_with_only(Some(vec![types::CodeActionKind::from("sour".to_string())])),
+            &fix_all
+        ));
+    }
+
+    #[test]
+    fn test_fix_all_code_action() {
+        let content = "any(is.na(x))\nstopifnot(a)\nstopifnot(b)\nall.equal(a, b)\nany(is.na(y))\n";
+        let snapshot = create_test_snapshot(content);
+
+        let diagnostic = |start: usize, end: usize, fix_content: &str, is_safe, rule: &str| {
+            create_test_diagnostic_with_fix(
+                Range::new(Position::new(0, 0), Position::new(0, 0)),
+                String::new(),
+                DiagnosticFix {
+                    content: fix_content.to_string(),
+                    start,
+                    end,
+                    is_safe,
+                    rule_name: rule.to_string(),
+                },
+            )
+        };
+
+        // Diagnostics are not sorted by position
+        let diagnostics = vec![
+            diagnostic(56, 69, "anyNA(y)", true, "any_is_na"),
+            diagnostic(0, 13, "anyNA(x)", true, "any_is_na"),
+            // Overlaps the previous fix, so it is skipped
+            diagnostic(4, 12, "foo", true, "any_is_na"),
+            // Not in "fix all"
+            diagnostic(14, 39, "stopifnot(a, b)", true, "consecutive_assertion"),
+            diagnostic(40, 55, "isTRUE(all.equal(a, b))", false, "all_equal"),
+        ];
+
+        let action = Server::fix_all_code_action(&diagnostics, &snapshot).unwrap();
+        assert_eq!(action.kind, Some(types::CodeActionKind::SOURCE_FIX_ALL));
+
+        let changes = action.edit.unwrap().changes.unwrap();
+        let text_edits = changes.get(snapshot.uri()).unwrap();
+        assert_eq!(
+            text_edits,
+            &vec![
+                types::TextEdit {
+                    range: Range::new(Position::new(0, 0), Position::new(0, 13)),
+                    new_text: "anyNA(x)".to_string(),
+                },
+                types::TextEdit {
+                    range: Range::new(Position::new(4, 0), Position::new(4, 13)),
+                    new_text: "anyNA(y)".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fix_all_code_action_without_safe_fixes() {
+        let snapshot = create_test_snapshot("all.equal(a, b)\n");
+
+        let diagnostic = create_test_diagnostic_with_fix(
+            Range::new(Position::new(0, 0), Position::new(0, 15)),
+            String::new(),
+            DiagnosticFix {
+                content: "isTRUE(all.equal(a, b))".to_string(),
+                start: 0,
+                end: 15,
+                is_safe: false,
+                rule_name: "all_equal".to_string(),
+            },
+        );
+
+        assert!(Server::fix_all_code_action(&[diagnostic], &snapshot).is_none());
+    }
+
     #[test]
     fn test_ranges_overlap() {
         let range1 = Range::new(Position::new(0, 0), Position::new(0, 5));
@@ -1087,6 +1534,177 @@ mod tests {
         assert!(ranges_overlap(&range1, &range1));
     }
 
+    #[test]
+    fn test_range_contains() {
+        let range = Range::new(Position::new(0, 2), Position::new(1, 3));
+
+        assert!(range_contains(&range, Position::new(0, 2)));
+        assert!(range_contains(&range, Position::new(0, 10)));
+        assert!(range_contains(&range, Position::new(1, 3)));
+
+        assert!(!range_contains(&range, Position::new(0, 1)));
+        assert!(!range_contains(&range, Position::new(1, 4)));
+    }
+
+    #[test]
+    fn test_diagnostic_to_hover() {
+        let fix = DiagnosticFix {
+            content: "anyNA(x)".to_string(),
+            start: 0,
+            end: 13,
+            is_safe: true,
+            rule_name: "any_is_na".to_string(),
+        };
+        let range = Range::new(Position::new(0, 0), Position::new(0, 13));
+        let diagnostic = create_test_diagnostic_with_fix(
+            range,
+            "`any(is.na(...))` is inefficient.".to_string(),
+            fix,
+        );
+
+        let hover = Server::diagnostic_to_hover(&diagnostic).unwrap();
+        assert_eq!(hover.range, Some(range));
+
+        let types::HoverContents::Markup(contents) = hover.contents else {
+            panic!("Expected markup contents");
+        };
+        assert_eq!(contents.kind, types::MarkupKind::Markdown);
+        assert_eq!(
+            contents.value,
+            "**any_is_na**\n\n`any(is.na(...))` is inefficient.\n\nCategory: Performance (`PERF`)\n\nFix: safe"
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_to_hover_without_fix() {
+        let fix = DiagnosticFix {
+            content: String::new(),
+            start: 0,
+            end: 0,
+            is_safe: false,
+            rule_name: "class_equals".to_string(),
+        };
+        let diagnostic = create_test_diagnostic_with_fix(
+            Range::new(Position::new(0, 0), Position::new(0, 17)),
+            "Comparing `class(x)` with `==` can be problematic.".to_string(),
+            fix,
+        );
+
+        let hover = Server::diagnostic_to_hover(&diagnostic).unwrap();
+        let types::HoverContents::Markup(contents) = hover.contents else {
+            panic!("Expected markup contents");
+        };
+        assert!(contents.value.ends_with("Fix: none"));
+    }
+
+    #[test]
+    fn test_generate_hover() -> Result<()> {
+        let directory = tempfile::TempDir::new()?;
+        let file_path = directory.path().join("test.R");
+        let content = "x <- 1\nany(is.na(x))\n";
+        std::fs::write(&file_path, content)?;
+
+        let uri = Url::from_file_path(&file_path).unwrap();
+        let document = TextDocument::new(content.to_string(), 1);
+        let snapshot = DocumentSnapshot::new(
+            document,
+            DocumentKey::from(uri.clone()),
+            PositionEncoding::UTF8,
+            lsp_types::ClientCapabilities::default(),
+        );
+
+        let hover_params = |position| types::HoverParams {
+            text_document_position_params: types::TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position,
+            },
+            work_done_progress_params: Default::default(),
+        };
+
+        // Hovering the violation of `any_is_na`
+        let hover = Server::generate_hover(&snapshot, &hover_params(Position::new(1, 5)))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            hover.range,
+            Some(Range::new(Position::new(1, 0), Position::new(1, 13)))
+        );
+        let types::HoverContents::Markup(contents) = hover.contents else {
+            panic!("Expected markup contents");
+        };
+        assert!(contents.value.starts_with("**any_is_na**"));
+
+        // No diagnostic on the first line
+        let hover = Server::generate_hover(&snapshot, &hover_params(Position::new(0, 2))).unwrap();
+        assert!(hover.is_none());
+
+        Ok(())
+    }
+
+    fn create_test_snapshot_on_disk(
+        directory: &std::path::Path,
+        file_name: &str,
+        content: &str,
+        language_id: Option<&str>,
+    ) -> DocumentSnapshot {
+        let file_path = directory.join(file_name);
+        std::fs::write(&file_path, content).unwrap();
+
+        let mut document = TextDocument::new(content.to_string(), 1);
+        if let Some(language_id) = language_id {
+            document = document.with_language_id(language_id);
+        }
+
+        DocumentSnapshot::new(
+            document,
+            DocumentKey::from(Url::from_file_path(&file_path).unwrap()),
+            PositionEncoding::UTF8,
+            lsp_types::ClientCapabilities::default(),
+        )
+    }
+
+    #[test]
+    fn test_r_documents_are_linted() -> Result<()> {
+        let directory = tempfile::TempDir::new()?;
+        let content = "any(is.na(x))\n";
+
+        for language_id in [Some("r"), Some("R"), None] {
+            let snapshot =
+                create_test_snapshot_on_disk(directory.path(), "test.R", content, language_id);
+            assert_eq!(lint::lint_document(&snapshot)?.len(), 1);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_non_r_documents_are_not_linted() -> Result<()> {
+        let directory = tempfile::TempDir::new()?;
+        let content = "any(is.na(x))\n";
+
+        let snapshots = [
+            create_test_snapshot_on_disk(directory.path(), "test.R", content, Some("python")),
+            create_test_snapshot_on_disk(directory.path(), "test.Rmd", content, Some("rmd")),
+            create_test_snapshot_on_disk(directory.path(), "test.Rmd", content, Some("r")),
+            create_test_snapshot_on_disk(directory.path(), "test.qmd", content, None),
+        ];
+
+        for snapshot in snapshots {
+            assert!(lint::lint_document(&snapshot)?.is_empty());
+
+            let hover_params = types::HoverParams {
+                text_document_position_params: types::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri: snapshot.uri().clone() },
+                    position: Position::new(0, 2),
+                },
+                work_done_progress_params: Default::default(),
+            };
+            assert!(Server::generate_hover(&snapshot, &hover_params)?.is_none());
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_unicode_diagnostics_and_fixes() {
         // Test that diagnostics and fixes work correctly with multibyte Unicode characters
@@ -6,14 +6,15 @@
 use anyhow::{Result, anyhow};
 use lsp_types::{
     ClientCapabilities, CodeActionKind, CodeActionOptions, CodeActionProviderCapability,
-    InitializeParams, InitializeResult, SaveOptions, ServerCapabilities, ServerInfo,
-    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions, Url,
+    HoverProviderCapability, InitializeParams, InitializeResult, SaveOptions, ServerCapabilities,
+    ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions, Url,
     WorkDoneProgressOptions,
 };
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use crate::LspResult;
 use crate::client::Client;
@@ -27,8 +28,15 @@ pub struct InitializationOptions {
     pub log_level: Option<String>,
     /// Log levels for dependencies
     pub dependency_log_levels: Option<String>,
+    /// Delay in milliseconds between the last change of a document and its
+    /// linting
+    pub lint_debounce_ms: Option<u64>,
 }
 
+/// Default delay between the last change of a document and its linting, so
+/// that a document isn't linted after every keystroke
+pub const DEFAULT_LINT_DEBOUNCE: Duration = Duration::from_millis(300);
+
 /// Main session state for the LSP server
 pub struct Session {
     /// Documents currently open in the editor
@@ -45,6 +53,12 @@ pub struct Session {
     client: Client,
     /// Whether we've shown the config notification
     config_notification_shown: bool,
+    /// The error in `jarl.toml` that was last shown to the user, if any
+    config_error: Option<String>,
+    /// Delay between the last change of a document and its linting
+    lint_debounce: Duration,
+    /// Documents that changed and when they should be linted
+    scheduled_lints: FxHashMap<DocumentKey, Instant>,
 }
 
 /// Immutable snapshot of a document and its context
@@ -75,6 +89,9 @@ impl Session {
             workspace_roots,
             client,
             config_notification_shown: false,
+            config_error: None,
+            lint_debounce: DEFAULT_LINT_DEBOUNCE,
+            scheduled_lints: FxHashMap::default(),
         }
     }
 
@@ -97,6 +114,19 @@ impl Session {
             self.workspace_roots = vec![PathBuf::from(root_path)];
         }
 
+        let options = params
+            .initialization_options
+            .map(serde_json::from_value::<InitializationOptions>)
+            .transpose()
+            .unwrap_or_else(|e| {
+                tracing::warn!("Invalid initialization options: {}", e);
+                None
+            })
+            .unwrap_or_default();
+        if let Some(lint_debounce_ms) = options.lint_debounce_ms {
+            self.lint_debounce = Duration::from_millis(lint_debounce_ms);
+        }
+
         tracing::info!(
             "Initialized Jarl LSP with {} workspace roots (diagnostics only)",
             self.workspace_roots.len()
@@ -125,11 +155,15 @@ impl Session {
                 },
             )),
             diagnostic_provider: None, // Use push diagnostics only
-            // Add code action support for quick fixes
-            hover_provider: None,
+            // Show the rule and the fix of the diagnostic under the cursor
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
             completion_provider: None,
+            // Add code action support for quick fixes
             code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
-                code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                code_action_kinds: Some(vec![
+                    CodeActionKind::QUICKFIX,
+                    CodeActionKind::SOURCE_FIX_ALL,
+                ]),
                 resolve_provider: Some(false),
                 work_done_progress_options: WorkDoneProgressOptions::default(),
             })),
@@ -176,6 +210,7 @@ impl Session {
     pub fn close_document(&mut self, uri: Url) -> LspResult<()> {
         let key = DocumentKey::from(uri);
 
+        self.scheduled_lints.remove(&key);
         if self.documents.remove(&key).is_some() {
             tracing::debug!("Closed document: {}", key.uri());
             Ok(())
@@ -184,6 +219,50 @@ impl Session {
         }
     }
 
+    /// Lint a document once it hasn't changed for the debounce delay. This
+    /// replaces the previously scheduled linting of the document, if any.
+    pub fn schedule_lint(&mut self, uri: Url) {
+        let key = DocumentKey::from(uri);
+        self.scheduled_lints
+            .insert(key, Instant::now() + self.lint_debounce);
+    }
+
+    /// Cancel the scheduled linting of a document, e.g. because it is linted
+    /// right away
+    pub fn cancel_scheduled_lint(&mut self, uri: Url) {
+        self.scheduled_lints.remove(&DocumentKey::from(uri));
+    }
+
+    /// When the next scheduled linting is due, if any
+    pub fn next_scheduled_lint(&self) -> Option<Instant> {
+        self.scheduled_lints.values().min().copied()
+    }
+
+    /// Remove the scheduled lintings that are due and return the snapshots of
+    /// their documents
+    pub fn take_due_lints(&mut self, now: Instant) -> Vec<DocumentSnapshot> {
+        let due = self
+            .scheduled_lints
+            .iter()
+            .filter(|(_, deadline)| **deadline <= now)
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+
+        due.into_iter()
+            .filter_map(|key| {
+                self.scheduled_lints.remove(&key);
+                self.take_snapshot(key.uri().clone())
+            })
+            .collect()
+    }
+
+    /// Whether the document is still open at this version. Diagnostics for an
+    /// older version are outdated and must not be published.
+    pub fn is_latest_version(&self, uri: &Url, version: DocumentVersion) -> bool {
+        self.get_document(uri)
+            .is_some_and(|document| document.version() == version)
+    }
+
     /// Get a document by URI
     pub fn get_document(&self, uri: &Url) -> Option<&TextDocument> {
         let key = DocumentKey::from(uri.clone());
@@ -308,6 +387,55 @@ impl Session {
 
         false
     }
+
+    /// Check that the `jarl.toml` used for these files are valid, and show a
+    /// warning with the error otherwise. Files can't be linted with an invalid
+    /// `jarl.toml`, so this is the only feedback the user gets.
+    ///
+    /// The same error is only shown once, but it is shown again if it comes
+    /// back after the configuration was fixed. Returns true if a warning was
+    /// shown.
+    pub fn check_config_errors(&mut self, file_paths: &[PathBuf]) -> bool {
+        let error = file_paths
+            .iter()
+            .find_map(|file_path| validate_config(file_path).err())
+            .map(|e| format!("{e:#}"));
+
+        let Some(error) = error else {
+            if self.config_error.take().is_some() {
+                tracing::info!("The configuration of Jarl is valid again");
+            }
+            return false;
+        };
+
+        if self.config_error.as_ref() == Some(&error) {
+            return false;
+        }
+
+        if let Err(e) = self.client.show_message(
+            &format!("Jarl couldn't use its configuration: {error}"),
+            lsp_types::MessageType::WARNING,
+        ) {
+            tracing::error!("Failed to show config error: {}", e);
+        }
+        self.config_error = Some(error);
+        true
+    }
+}
+
+/// Parse the `jarl.toml` that applies to this file and validate its fields,
+/// as is done when building the config to lint the file
+fn validate_config(file_path: &Path) -> Result<()> {
+    use jarl_core::config::{parse_fixable_toml, parse_rules_toml, parse_severity_toml};
+    use jarl_core::discovery::discover_settings;
+
+    let file_path_str = vec![file_path.to_string_lossy().to_string()];
+    for ds in discover_settings(&file_path_str)? {
+        parse_rules_toml(Some(&ds.settings))?;
+        parse_fixable_toml(Some(&ds.settings))?;
+        parse_severity_toml(Some(&ds.settings))?;
+    }
+    Ok(())
 }
 
 impl DocumentSnapshot {
@@ -465,6 +593,67 @@ mod tests {
         assert!(session.get_document(&uri).is_none());
     }
 
+    #[test]
+    fn test_scheduled_lints() {
+        let mut session = create_test_session();
+        let uri = Url::parse("file:///test.R").unwrap();
+        session.open_document(uri.clone(), TextDocument::new("x".to_string(), 1));
+        assert!(session.next_scheduled_lint().is_none());
+
+        let change = |text: &str| lsp_types::TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: text.to_string(),
+        };
+
+        // Two quick changes only lead to one linting, of the latest version
+        session
+            .update_document(uri.clone(), vec![change("x <- 1")], 2)
+            .unwrap();
+        session.schedule_lint(uri.clone());
+        session
+            .update_document(uri.clone(), vec![change("x <- 12")], 3)
+            .unwrap();
+        session.schedule_lint(uri.clone());
+
+        let deadline = session.next_scheduled_lint().unwrap();
+        assert!(session.take_due_lints(Instant::now()).is_empty());
+
+        let snapshots = session.take_due_lints(deadline);
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].version(), 3);
+        assert_eq!(snapshots[0].content(), "x <- 12");
+        assert!(session.next_scheduled_lint().is_none());
+
+        // Diagnostics of older versions are outdated
+        assert!(session.is_latest_version(&uri, 3));
+        assert!(!session.is_latest_version(&uri, 2));
+
+        // Closing the document cancels its linting
+        session.schedule_lint(uri.clone());
+        session.close_document(uri.clone()).unwrap();
+        assert!(session.next_scheduled_lint().is_none());
+        assert!(!session.is_latest_version(&uri, 3));
+    }
+
+    #[test]
+    fn test_lint_debounce_initialization_option() {
+        let mut session = create_test_session();
+        session
+            .initialize(InitializeParams {
+                initialization_options: Some(serde_json::json!({ "lintDebounceMs": 0 })),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let uri = Url::parse("file:///test.R").unwrap();
+        session.open_document(uri.clone(), TextDocument::new("x".to_string(), 1));
+        session.schedule_lint(uri);
+
+        // Without delay, the linting is due right away
+        assert_eq!(session.take_due_lints(Instant::now()).len(), 1);
+    }
+
     #[test]
     fn test_position_encoding_negotiation() {
         // Test UTF-8 preference
@@ -504,6 +693,22 @@ mod tests {
 
         assert!(caps.text_document_sync.is_some());
         assert!(caps.diagnostic_provider.is_none());
+        assert_eq!(
+            caps.hover_provider,
+            Some(HoverProviderCapability::Simple(true))
+        );
+
+        let Some(CodeActionProviderCapability::Options(code_actions)) = caps.code_action_provider
+        else {
+            panic!("Expected code action options");
+        };
+        assert_eq!(
+            code_actions.code_action_kinds,
+            Some(vec![
+                CodeActionKind::QUICKFIX,
+                CodeActionKind::SOURCE_FIX_ALL
+            ])
+        );
 
         if let Some(TextDocumentSyncCapability::Options(options)) = caps.text_document_sync {
             assert_eq!(options.open_close, Some(true));
@@ -609,6 +814,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_errors_notification() {
+        let mut session = create_test_session();
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("jarl.toml");
+        let test_file = temp_dir.path().join("test.R");
+        std::fs::write(&test_file, "x <- 1\n").unwrap();
+        let files = vec![test_file];
+
+        // Invalid TOML
+        std::fs::write(&config_path, "[lint\n").unwrap();
+        assert!(session.check_config_errors(&files));
+        assert!(
+            session
+                .config_error
+                .as_ref()
+                .unwrap()
+                .contains("Failed to parse")
+        );
+
+        // The same error isn't shown twice
+        assert!(!session.check_config_errors(&files));
+
+        // Valid TOML but unknown rule
+        std::fs::write(&config_path, "[lint]\nselect = [\"foo\"]\n").unwrap();
+        assert!(session.check_config_errors(&files));
+        assert!(session.config_error.as_ref().unwrap().contains("foo"));
+
+        // A valid config clears the error
+        std::fs::write(&config_path, "[lint]\nselect = [\"any_is_na\"]\n").unwrap();
+        assert!(!session.check_config_errors(&files));
+        assert!(session.config_error.is_none());
+
+        // So the error is shown again if it comes back
+        std::fs::write(&config_path, "[lint\n").unwrap();
+        assert!(session.check_config_errors(&files));
+    }
+
     #[test]
     fn test_config_notification_with_no_config() {
         use std::fs;
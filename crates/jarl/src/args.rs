@@ -1,8 +1,14 @@
+use crate::commands::explain::ExplainFormat;
+use crate::commands::rules::RulesFormat;
+use crate::fix_report::FixReportFormat;
 use crate::logging::LogLevel;
-use crate::output_format::OutputFormat;
+use crate::output_format::{ColumnBase, GroupBy, HyperlinkMode, OutputFormat};
 use clap::builder::Styles;
 use clap::builder::styling::{AnsiColor, Effects};
 use clap::{Parser, Subcommand};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::time::Duration;
 
 // Configures Clap v3-style help menu colors
 const STYLES: Styles = Styles::styled()
@@ -34,6 +40,26 @@ pub(crate) enum Command {
 
     /// Start a language server
     Server(ServerCommand),
+
+    /// Check code sent as JSON over a Unix socket
+    Daemon(DaemonCommand),
+
+    /// Remove the cache of the current directory
+    ClearCache(ClearCacheCommand),
+
+    /// Print the documentation of a rule
+    Explain(ExplainCommand),
+
+    /// List all rules and whether they are enabled in the current directory
+    Rules(RulesCommand),
+
+    /// Generate markdown stubs for the documentation of each rule
+    #[command(hide = true)]
+    RuleDocs(RuleDocsCommand),
+
+    /// Print the syntax tree of an R file, to debug rules
+    #[command(hide = true)]
+    DumpAst(DumpAstCommand),
 }
 
 #[derive(Clone, Debug, Parser)]
@@ -61,7 +87,7 @@ pub struct CheckCommand {
     #[arg(
         long,
         default_value = "false",
-        help = "Apply fixes to resolve lint violations, but don't report on leftover violations. Implies `--fix`."
+        help = "Apply fixes to resolve lint violations, and only report the number of leftover violations by rule. Implies `--fix`."
     )]
     pub fix_only: bool,
     #[arg(
@@ -97,6 +123,19 @@ pub struct CheckCommand {
         help = "Names of rules to exclude, separated by a comma (no spaces). This also accepts names of groups of rules, such as \"PERF\"."
     )]
     pub ignore: String,
+    #[arg(
+        long,
+        default_value = "",
+        value_name = "CATEGORIES",
+        help = "Names of groups of rules to include, separated by a comma (no spaces), such as \"CORR,SUSP\". This is combined with the rules passed to `--select`."
+    )]
+    pub select_category: String,
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Path to a TOML file to use as configuration for all files, instead of looking for a `jarl.toml` in the directories of the files to check."
+    )]
+    pub config: Option<PathBuf>,
     #[arg(
         short,
         long,
@@ -132,13 +171,252 @@ pub struct CheckCommand {
     #[arg(
         long,
         default_value = "false",
-        help = "Show counts for every rule with at least one violation."
+        help = "Show counts for every rule with at least one violation. With `--output-format json`, the counts are printed as JSON."
     )]
     pub statistics: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Exit with a non-zero status if there are violations whose severity is \"warning\" in `jarl.toml`."
+    )]
+    pub warnings_as_errors: bool,
+    #[arg(
+        long,
+        value_name = "CATEGORIES",
+        help = "Only exit with a non-zero status if there are violations of rules in these groups, separated by a comma (no spaces), such as \"CORR\". Violations of other rules are still reported. By default, violations of all rules make the check fail."
+    )]
+    pub fail_on: Option<String>,
+    #[arg(
+        long,
+        value_enum,
+        conflicts_with_all = ["fix", "unsafe_fixes", "fix_only"],
+        help = "Report the number of violations that have a safe fix, an unsafe fix, or no fix, for every rule. Fixes are not applied."
+    )]
+    pub fix_report: Option<FixReportFormat>,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Show the name of the function in which each violation is located."
+    )]
+    pub show_context: bool,
+    #[arg(
+        long,
+        help = "Name of the file to use when reading R code from stdin with `jarl check -`. It is used in diagnostics and to find the `jarl.toml` that applies."
+    )]
+    pub stdin_filename: Option<PathBuf>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = HyperlinkMode::default(),
+        help = "When to make rule names clickable links to their documentation in the `full` and `concise` output formats."
+    )]
+    pub hyperlinks: HyperlinkMode,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Compute the fixes of all files before writing them. If a file can't be written, the files that were already fixed are restored."
+    )]
+    pub atomic: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Prefix rule names with `jarl:` in the `json`, `github`, `sarif`, and `junit` output formats, e.g. `jarl:any_is_na`."
+    )]
+    pub namespaced_rule_ids: bool,
+    #[arg(
+        long,
+        value_name = "RULE",
+        help = "Only run this rule, ignoring `--select`, `--ignore`, the rules selected in `jarl.toml`, and the minimum R version. This is meant to debug rules."
+    )]
+    pub only: Option<String>,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Also check the code in the `@examples` and `@examplesIf` sections of roxygen2 comments."
+    )]
+    pub roxygen_examples: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        conflicts_with_all = ["fix", "unsafe_fixes", "fix_only", "fix_report", "statistics"],
+        help = "Print the safe fixes of all violations as a JSON LSP `WorkspaceEdit`. Fixes are not applied."
+    )]
+    pub workspace_edit: bool,
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        value_parser = parse_time_budget,
+        conflicts_with = "atomic",
+        help = "Stop checking new files once this number of seconds has elapsed, and report the violations found so far. The exit code is 2 if some files were not checked."
+    )]
+    pub time_budget: Option<Duration>,
+    #[arg(
+        long,
+        default_value = "false",
+        conflicts_with_all = [
+            "fix",
+            "unsafe_fixes",
+            "fix_only",
+            "fix_report",
+            "statistics",
+            "workspace_edit"
+        ],
+        help = "Print a hash of the violations instead of the violations themselves. It only changes when the violations change, so it can be used to cache results in CI."
+    )]
+    pub print_fingerprint: bool,
+    #[arg(
+        long,
+        value_enum,
+        help = "Whether columns start at 0 or 1 in the `concise` and `json` output formats. By default, they start at 1 in `concise` and at 0 in `json`."
+    )]
+    pub column_base: Option<ColumnBase>,
+    #[arg(
+        long,
+        value_enum,
+        help = "Group the violations in the `concise` output format. With `category`, rules that belong to several categories are shown under the first one."
+    )]
+    pub group_by: Option<GroupBy>,
+    #[arg(
+        long,
+        value_name = "CONFIDENCE",
+        help = "Only report violations with at least this confidence. Some rules use heuristics and their violations have a `heuristic` confidence, the others have a `high` confidence. By default, all violations are reported."
+    )]
+    pub min_confidence: Option<String>,
+    #[arg(
+        long,
+        default_value = "false",
+        conflicts_with_all = [
+            "fix",
+            "fix_only",
+            "fix_report",
+            "statistics",
+            "workspace_edit",
+            "print_fingerprint"
+        ],
+        help = "Print the changes that `--fix` would make as a unified diff instead of applying them. Combine with `--unsafe-fixes` to include unsafe fixes."
+    )]
+    pub diff: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Show additional information, such as the files in which rules requiring a specific R version would report violations when the minimum R version is unknown."
+    )]
+    pub verbose: bool,
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Path to a JSON file listing existing violations that are not reported. Violations that moved by a few lines are still matched. Use `--write-baseline` to create it."
+    )]
+    pub baseline: Option<PathBuf>,
+    #[arg(
+        long,
+        default_value = "false",
+        requires = "baseline",
+        conflicts_with_all = ["fix", "unsafe_fixes", "fix_only", "diff"],
+        help = "Write all violations to the file passed to `--baseline` instead of reporting them."
+    )]
+    pub write_baseline: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        conflicts_with = "group_by",
+        help = "Print one line per file with the number of violations and rules violated instead of one line per violation. Only used in the `concise` output format."
+    )]
+    pub compact_files: bool,
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Maximum number of files checked in parallel. By default, this is the number of available CPUs."
+    )]
+    pub jobs: Option<NonZeroUsize>,
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with = "diff",
+        help = "Write a JSON file listing the rules that were run on each file."
+    )]
+    pub coverage_report: Option<PathBuf>,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Check all files instead of reusing the violations of the files that didn't change since the last run. Use `jarl clear-cache` to remove the cache."
+    )]
+    pub no_cache: bool,
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Maximum number of times fixes are applied on a file, since fixing some violations can reveal new ones. Fixing stops earlier if the file doesn't change anymore. By default, this is 5."
+    )]
+    pub fix_iterations: Option<NonZeroUsize>,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Also check the R code chunks of R Markdown (`.Rmd`) and Quarto (`.qmd`) files found in directories. Inline R code is not checked."
+    )]
+    pub include_rmd: bool,
 }
 #[derive(Clone, Debug, Parser)]
 pub(crate) struct ServerCommand {}
 
+#[derive(Clone, Debug, Parser)]
+pub(crate) struct DaemonCommand {
+    #[arg(
+        long,
+        required = true,
+        help = "Path of the Unix socket to listen on. Each line received is a JSON request with the fields `source`, `path`, and optionally `config`, and gets a JSON response with the diagnostics."
+    )]
+    pub socket: PathBuf,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub(crate) struct ClearCacheCommand {}
+
+#[derive(Clone, Debug, Parser)]
+pub(crate) struct ExplainCommand {
+    #[arg(required = true, help = "Name of the rule, for example `any_is_na`.")]
+    pub rule: String,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = ExplainFormat::default(),
+        help = "Output format of the documentation."
+    )]
+    pub output_format: ExplainFormat,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub(crate) struct RulesCommand {
+    #[arg(
+        short,
+        long,
+        help = "The minimum R version used to determine which rules are enabled. By default, it is read from the `DESCRIPTION` file of the current directory, if any."
+    )]
+    pub min_r_version: Option<String>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = RulesFormat::default(),
+        help = "Output format of the list of rules."
+    )]
+    pub output_format: RulesFormat,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub(crate) struct RuleDocsCommand {
+    #[arg(
+        long,
+        required = true,
+        help = "Directory in which the markdown files are written. Existing files are not overwritten."
+    )]
+    pub rule_docs_dir: PathBuf,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub(crate) struct DumpAstCommand {
+    #[arg(required = true, help = "R file whose syntax tree is printed.")]
+    pub file: PathBuf,
+}
+
 /// All configuration options that can be passed "globally"
 #[derive(Debug, Default, clap::Args)]
 #[command(next_help_heading = "Global options")]
@@ -148,3 +426,12 @@ pub(crate) struct GlobalOptions {
     #[arg(long, global = true)]
     pub(crate) log_level: Option<LogLevel>,
 }
+
+/// Parse a number of seconds that is finite and non-negative.
+fn parse_time_budget(value: &str) -> Result<Duration, String> {
+    let seconds: f64 = value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a number"))?;
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| "must be a finite and non-negative number of seconds".to_string())
+}
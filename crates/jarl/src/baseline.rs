@@ -0,0 +1,135 @@
+use anyhow::Context;
+use jarl_core::diagnostic::Diagnostic;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::fingerprint::Fnv1a;
+use crate::status::ExitStatus;
+
+/// Maximum number of lines by which a violation can move and still match its
+/// entry in the baseline. This avoids reporting all the violations of a file
+/// again as soon as a line is added at the top of it.
+const LINE_TOLERANCE: usize = 5;
+
+/// Violations that existed when the baseline was written and that shouldn't be
+/// reported anymore.
+#[derive(Serialize, Deserialize)]
+struct Baseline {
+    diagnostics: Vec<BaselineEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineEntry {
+    file: String,
+    rule: String,
+    line: usize,
+    /// Hash of the message, in hexadecimal.
+    message_hash: String,
+}
+
+impl BaselineEntry {
+    fn new(diagnostic: &Diagnostic) -> Self {
+        let (file, rule, message_hash) = key(diagnostic);
+        Self {
+            file,
+            rule,
+            line: diagnostic.location.map_or(0, |loc| loc.row()),
+            message_hash,
+        }
+    }
+}
+
+/// Violations are matched on their file, rule, and message, and then on their
+/// line.
+fn key(diagnostic: &Diagnostic) -> (String, String, String) {
+    let mut hasher = Fnv1a::new();
+    hasher.write(diagnostic.message.body.as_bytes());
+
+    (
+        // Use the same separator on all platforms.
+        diagnostic.filename.to_string_lossy().replace('\\', "/"),
+        diagnostic.message.name.clone(),
+        format!("{:016x}", hasher.finish()),
+    )
+}
+
+/// Write all the violations to the baseline file, so that they are ignored by
+/// the next calls to `jarl check --baseline`.
+pub fn write_baseline(path: &Path, diagnostics: &[&Diagnostic]) -> anyhow::Result<ExitStatus> {
+    let baseline = Baseline {
+        diagnostics: diagnostics
+            .iter()
+            .copied()
+            .map(BaselineEntry::new)
+            .collect(),
+    };
+
+    let mut contents = serde_json::to_string_pretty(&baseline)?;
+    contents.push('\n');
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write baseline: {}", path.display()))?;
+
+    let n_violations = diagnostics.len();
+    let violations = if n_violations == 1 {
+        "violation"
+    } else {
+        "violations"
+    };
+    println!(
+        "Wrote {n_violations} {violations} to the baseline `{}`.",
+        path.display()
+    );
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove the violations that are in the baseline.
+///
+/// Each entry of the baseline can only match one violation, so that adding a
+/// new violation that is identical to an existing one in the same file is
+/// still reported. When several entries could match, the closest one is used.
+pub fn filter_baseline<'a>(
+    path: &Path,
+    diagnostics: Vec<&'a Diagnostic>,
+) -> anyhow::Result<Vec<&'a Diagnostic>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read baseline: {}", path.display()))?;
+    let baseline: Baseline = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse baseline: {}", path.display()))?;
+
+    // Lines of the entries that are not matched yet.
+    let mut remaining: HashMap<(String, String, String), Vec<usize>> = HashMap::new();
+    for entry in baseline.diagnostics {
+        remaining
+            .entry((entry.file, entry.rule, entry.message_hash))
+            .or_default()
+            .push(entry.line);
+    }
+
+    let filtered = diagnostics
+        .into_iter()
+        .filter(|diagnostic| {
+            let Some(lines) = remaining.get_mut(&key(diagnostic)) else {
+                return true;
+            };
+            let line = diagnostic.location.map_or(0, |loc| loc.row());
+            let closest = lines
+                .iter()
+                .enumerate()
+                .map(|(i, entry_line)| (i, entry_line.abs_diff(line)))
+                .filter(|(_, distance)| *distance <= LINE_TOLERANCE)
+                .min_by_key(|(_, distance)| *distance);
+
+            match closest {
+                Some((i, _)) => {
+                    lines.swap_remove(i);
+                    false
+                }
+                None => true,
+            }
+        })
+        .collect();
+
+    Ok(filtered)
+}
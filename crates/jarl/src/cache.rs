@@ -0,0 +1,156 @@
+use air_fs::relativize_path;
+use jarl_core::config::Config;
+use jarl_core::diagnostic::Diagnostic;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::fingerprint::Fnv1a;
+
+/// Directory in which the diagnostics of each file are stored, relative to the
+/// current directory.
+pub const CACHE_DIRECTORY: &str = ".jarl_cache";
+
+/// The diagnostics of a file, with the key they were computed for.
+#[derive(Serialize, Deserialize)]
+struct CacheEntry<D> {
+    /// Hash of the version of Jarl, the configuration, and the content of the
+    /// file, in hexadecimal.
+    key: String,
+    diagnostics: D,
+}
+
+/// The path of a file and the result of its check.
+type FileResult = (String, anyhow::Result<Vec<Diagnostic>>);
+
+/// Diagnostics stored in the cache, so that the files that didn't change since
+/// the last run are not checked again.
+pub struct Cache {
+    directory: PathBuf,
+    /// Hash of the version of Jarl and of the configuration. It is shared by
+    /// all files.
+    config_hash: u64,
+}
+
+/// A file that isn't in the cache or whose entry is outdated.
+pub struct CacheMiss {
+    pub path: PathBuf,
+    /// Path of the file as it appears in the results of the check.
+    file: String,
+    /// `None` if the file couldn't be read.
+    key: Option<String>,
+}
+
+impl Cache {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            directory: PathBuf::from(CACHE_DIRECTORY),
+            config_hash: config_hash(config),
+        }
+    }
+
+    /// Split the paths of the configuration into the files whose diagnostics
+    /// are in the cache, with these diagnostics, and the files that must be
+    /// checked.
+    pub fn partition(&self, paths: &[PathBuf]) -> (Vec<FileResult>, Vec<CacheMiss>) {
+        let lookups: Vec<_> = paths.par_iter().map(|path| self.lookup(path)).collect();
+
+        let mut hits = Vec::new();
+        let mut misses = Vec::new();
+        for lookup in lookups {
+            match lookup {
+                Ok(hit) => hits.push(hit),
+                Err(miss) => misses.push(miss),
+            }
+        }
+        (hits, misses)
+    }
+
+    fn lookup(&self, path: &Path) -> Result<FileResult, CacheMiss> {
+        let file = relativize_path(path);
+
+        // If the file can't be read, let the check report the error.
+        let Ok(contents) = std::fs::read(path) else {
+            return Err(CacheMiss { path: path.to_path_buf(), file, key: None });
+        };
+        let key = self.key(&contents);
+
+        let entry = std::fs::read_to_string(self.entry_path(&file))
+            .ok()
+            .and_then(|entry| serde_json::from_str::<CacheEntry<Vec<Diagnostic>>>(&entry).ok());
+
+        match entry {
+            Some(entry) if entry.key == key => Ok((file, Ok(entry.diagnostics))),
+            _ => Err(CacheMiss { path: path.to_path_buf(), file, key: Some(key) }),
+        }
+    }
+
+    /// Store the diagnostics of the files that were checked. Files that
+    /// couldn't be checked are not stored so that their errors are reported
+    /// again. Failing to write the cache doesn't make the check fail.
+    pub fn store(&self, misses: &[CacheMiss], results: &[FileResult]) {
+        if let Err(err) = self.create_directory() {
+            tracing::warn!("Failed to create the cache: {err:#}");
+            return;
+        }
+
+        let keys: HashMap<&str, &String> = misses
+            .iter()
+            .filter_map(|miss| Some((miss.file.as_str(), miss.key.as_ref()?)))
+            .collect();
+
+        for (file, result) in results {
+            let (Some(key), Ok(diagnostics)) = (keys.get(file.as_str()), result) else {
+                continue;
+            };
+            let entry = CacheEntry { key: key.to_string(), diagnostics };
+            if let Err(err) = self.write_entry(file, &entry) {
+                tracing::warn!("Failed to write the cache of {file}: {err:#}");
+            }
+        }
+    }
+
+    fn write_entry(&self, file: &str, entry: &CacheEntry<&Vec<Diagnostic>>) -> anyhow::Result<()> {
+        std::fs::write(self.entry_path(file), serde_json::to_string(entry)?)?;
+        Ok(())
+    }
+
+    fn create_directory(&self) -> anyhow::Result<()> {
+        std::fs::create_dir_all(&self.directory)?;
+        // Keep the cache out of version control.
+        let gitignore = self.directory.join(".gitignore");
+        if !gitignore.exists() {
+            std::fs::write(gitignore, "*\n")?;
+        }
+        Ok(())
+    }
+
+    fn key(&self, contents: &[u8]) -> String {
+        let mut hasher = Fnv1a::new();
+        hasher.write(&self.config_hash.to_le_bytes());
+        hasher.write(contents);
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// Each file has its own entry, named after the hash of its path.
+    fn entry_path(&self, file: &str) -> PathBuf {
+        let mut hasher = Fnv1a::new();
+        // Use the same separator on all platforms.
+        hasher.write(file.replace('\\', "/").as_bytes());
+        self.directory
+            .join(format!("{:016x}.json", hasher.finish()))
+    }
+}
+
+/// Hash of the version of Jarl and of the configuration, which is everything
+/// that changes the diagnostics of a file apart from its content.
+fn config_hash(config: &Config) -> u64 {
+    let mut hasher = Fnv1a::new();
+    hasher.write(env!("CARGO_PKG_VERSION").as_bytes());
+    hasher.write(&[0]);
+    // This can't fail since all maps in `Config` have string keys.
+    let config = serde_json::to_vec(config).expect("Failed to serialize the configuration");
+    hasher.write(&config);
+    hasher.finish()
+}
@@ -1,2 +1,9 @@
 pub(crate) mod check;
+pub(crate) mod clear_cache;
+#[cfg(unix)]
+pub(crate) mod daemon;
+pub(crate) mod dump_ast;
+pub(crate) mod explain;
+pub(crate) mod rule_docs;
+pub(crate) mod rules;
 pub(crate) mod server;
@@ -1,48 +1,149 @@
+use air_fs::relativize_path;
 use air_workspace::resolve::PathResolver;
-use jarl_core::discovery::{discover_r_file_paths, discover_settings};
+use jarl_core::discovery::{discover_r_file_paths, discover_settings, load_settings};
+use jarl_core::rule_set::Category;
 use jarl_core::{
-    config::ArgsConfig, config::build_config, diagnostic::Diagnostic, settings::Settings,
+    config::ArgsConfig, config::Config, config::build_config, config::build_configs,
+    diagnostic::Diagnostic, settings::Settings,
 };
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
+use std::collections::HashSet;
 use std::env;
+use std::io::{IsTerminal, Read, Write};
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::time::Instant;
 
 use crate::args::CheckCommand;
-use crate::output_format::{self, GithubEmitter};
+use crate::baseline::{filter_baseline, write_baseline};
+use crate::cache::Cache;
+use crate::coverage::write_coverage_report;
+use crate::diff::print_diff;
+use crate::fingerprint::print_fingerprint;
+use crate::fix_report::print_fix_report;
+use crate::output_format::{self, ColumnBase, GithubEmitter, JunitEmitter, SarifEmitter};
 use crate::statistics::print_statistics;
 use crate::status::ExitStatus;
+use crate::unfixed::{UnfixedCount, count_unfixed, print_unfixed};
+use crate::workspace_edit::print_workspace_edit;
 
 use output_format::{ConciseEmitter, Emitter, FullEmitter, JsonEmitter, OutputFormat};
 
 pub fn check(args: CheckCommand) -> Result<ExitStatus> {
+    let start_budget = Instant::now();
     let start = if args.with_timing {
         Some(Instant::now())
     } else {
         None
     };
 
+    // `jarl check -` reads the R code from stdin instead of walking the
+    // filesystem.
+    let is_stdin = args.files.iter().any(|file| file == "-");
+    if is_stdin && args.files.len() > 1 {
+        return Err(anyhow::anyhow!(
+            "`-` (read from stdin) cannot be combined with other paths."
+        ));
+    }
+    if is_stdin && args.workspace_edit {
+        return Err(anyhow::anyhow!(
+            "`--workspace-edit` cannot be used when reading from stdin."
+        ));
+    }
+    if is_stdin && args.diff {
+        return Err(anyhow::anyhow!(
+            "`--diff` cannot be used when reading from stdin."
+        ));
+    }
+    if is_stdin && args.coverage_report.is_some() {
+        return Err(anyhow::anyhow!(
+            "`--coverage-report` cannot be used when reading from stdin."
+        ));
+    }
+    if args.group_by.is_some() && args.output_format != OutputFormat::Concise {
+        return Err(anyhow::anyhow!(
+            "`--group-by` can only be used with `--output-format concise`."
+        ));
+    }
+    if args.compact_files && args.output_format != OutputFormat::Concise {
+        return Err(anyhow::anyhow!(
+            "`--compact-files` can only be used with `--output-format concise`."
+        ));
+    }
+    if !is_stdin && args.stdin_filename.is_some() {
+        return Err(anyhow::anyhow!(
+            "`--stdin-filename` can only be used when reading from stdin with `jarl check -`."
+        ));
+    }
+
+    // `--select-category` only accepts names of groups of rules, which are
+    // then expanded like the ones passed to `--select`.
+    parse_categories(&args.select_category, "--select-category")?;
+    let select = [args.select.as_str(), args.select_category.as_str()]
+        .into_iter()
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<_>>()
+        .join(",");
+    let fail_on = args
+        .fail_on
+        .as_deref()
+        .map(|value| parse_categories(value, "--fail-on"))
+        .transpose()?;
+
+    // The logical name of the code read from stdin. It is used in diagnostics
+    // and to find the `jarl.toml` that applies.
+    let stdin_filename = args
+        .stdin_filename
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("<stdin>"));
+
+    // Paths used to discover the settings. When reading from stdin, this is
+    // the directory of the logical filename.
+    let settings_paths: Vec<PathBuf> = if is_stdin {
+        let parent = stdin_filename
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from("."));
+        vec![parent]
+    } else {
+        args.files.iter().map(PathBuf::from).collect()
+    };
+
     let mut resolver = PathResolver::new(Settings::default());
 
     // Track if we're using a config from a parent directory
     let mut parent_config_path: Option<PathBuf> = None;
     let cwd = env::current_dir().ok();
 
+    // `--config` bypasses the discovery of `jarl.toml` and uses the same
+    // settings for all files.
+    let discovered_settings = match &args.config {
+        Some(config) => vec![load_settings(config)?],
+        None => discover_settings(&settings_paths)?,
+    };
+
     // Load discovered settings. If the user passed `--no-default-exclude`,
     // override each discovered settings' `default_exclude` to `false` so the
     // default patterns from `DEFAULT_EXCLUDE_PATTERNS` are not applied during
     // discovery.
-    for mut ds in discover_settings(&args.files)? {
+    for mut ds in discovered_settings {
         if args.no_default_exclude {
             ds.settings.linter.default_exclude = Some(false);
         }
 
-        // Check if config is from a parent directory (not CWD)
-        if let (Some(config_path), Some(current_dir)) = (&ds.config_path, &cwd)
+        // Check if config is from a parent directory (not CWD). This isn't
+        // reported when the config was passed explicitly, nor when it was
+        // found in a subdirectory of the checked paths.
+        if args.config.is_none()
+            && let (Some(config_path), Some(current_dir)) = (&ds.config_path, &cwd)
             && let Some(config_dir) = config_path.parent()
             && config_dir != current_dir
+            && settings_paths
+                .iter()
+                .any(|path| jarl_core::fs::normalize_path(path).starts_with(config_dir))
         {
             parent_config_path = Some(config_path.clone());
         }
@@ -50,10 +151,20 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         resolver.add(&ds.directory, ds.settings);
     }
 
-    let paths = discover_r_file_paths(&args.files, &resolver, true, args.no_default_exclude)
+    let paths = if is_stdin {
+        vec![stdin_filename.clone()]
+    } else {
+        discover_r_file_paths(
+            &args.files,
+            &resolver,
+            true,
+            args.no_default_exclude,
+            args.include_rmd,
+        )
         .into_iter()
         .filter_map(Result::ok)
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+    };
 
     if paths.is_empty() {
         println!(
@@ -66,28 +177,227 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
 
     let check_config = ArgsConfig {
         files: args.files.iter().map(|s| s.into()).collect(),
-        fix: args.fix,
+        // `--diff` computes the fixes without writing them.
+        fix: args.fix || args.diff,
         unsafe_fixes: args.unsafe_fixes,
         fix_only: args.fix_only,
-        select: args.select.clone(),
+        select,
         extend_select: args.extend_select.clone(),
         ignore: args.ignore.clone(),
         min_r_version: args.min_r_version.clone(),
         allow_dirty: args.allow_dirty,
         allow_no_vcs: args.allow_no_vcs,
         assignment: args.assignment,
+        show_context: args.show_context,
+        atomic: args.atomic,
+        only: args.only.clone(),
+        roxygen_examples: args.roxygen_examples,
+        min_confidence: args.min_confidence.clone(),
+        fix_iterations: args.fix_iterations.map(NonZeroUsize::get),
     };
 
-    let config = build_config(&check_config, &resolver, paths)?;
+    // Files are checked in parallel on rayon's global thread pool, which uses
+    // all available CPUs by default.
+    if let Some(jobs) = args.jobs {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs.get())
+            .build_global()
+            .context("Failed to set the number of parallel jobs")?;
+    }
+
+    // Each file is checked with the closest `jarl.toml`, so there is one
+    // configuration per `jarl.toml`. `--config` applies to all files.
+    let configs = match &args.config {
+        Some(_) => vec![build_config(&check_config, &resolver, paths)?],
+        None => build_configs(&check_config, &resolver, paths)?,
+    };
+
+    if args.diff {
+        let mut files = configs
+            .into_iter()
+            .flat_map(jarl_core::check::check_diff)
+            .collect::<Vec<_>>();
+        files.sort_by(|(a, _), (b, _)| a.cmp(b));
+        return print_diff(files);
+    }
+
+    // With `--verbose`, the rules skipped because the minimum R version is
+    // unknown are run separately to tell the user which files are affected.
+    let version_gated_configs = (args.verbose && !is_stdin).then(|| {
+        configs
+            .iter()
+            .filter(|config| !config.version_gated_rules.is_empty())
+            .map(|config| Config {
+                rules: config.version_gated_rules.clone(),
+                rules_to_apply: config.version_gated_rules.clone(),
+                apply_fixes: false,
+                apply_unsafe_fixes: false,
+                ..config.clone()
+            })
+            .collect::<Vec<_>>()
+    });
 
-    let file_results = jarl_core::check::check(config);
+    // With `--fix-only`, the violations left after applying fixes are not
+    // reported. The fixed code is checked again with all selected rules to
+    // tell the user how many of them remain.
+    let unfixed_configs =
+        (args.fix_only && (check_config.fix || check_config.unsafe_fixes)).then(|| {
+            configs
+                .iter()
+                .map(|config| Config {
+                    rules_to_apply: config.rules.clone(),
+                    apply_fixes: false,
+                    apply_unsafe_fixes: false,
+                    ..config.clone()
+                })
+                .collect::<Vec<_>>()
+        });
+
+    // The configurations are consumed by the check, so keep a copy to list
+    // the rules run on each file.
+    let coverage_report = args
+        .coverage_report
+        .as_ref()
+        .map(|path| (path, configs.clone()));
+
+    // When reading from stdin with `--fix`, the fixed code is written to
+    // stdout, so violations are written to stderr instead.
+    let apply_fixes = check_config.fix || check_config.unsafe_fixes;
+    let mut fixed_stdin: Option<String> = None;
+    let mut n_skipped_files = 0;
+
+    let file_results = if is_stdin {
+        let mut contents = String::new();
+        std::io::stdin()
+            .read_to_string(&mut contents)
+            .context("Failed to read from stdin")?;
+
+        // There is a single file, so a single configuration.
+        let config = &configs[0];
+        let result = match jarl_core::check::check_contents(&contents, &stdin_filename, config) {
+            Ok((diagnostics, fixed)) => {
+                if apply_fixes {
+                    fixed_stdin = Some(fixed);
+                }
+                Ok(diagnostics)
+            }
+            Err(e) => {
+                // Give back the code unchanged so that editors piping code
+                // through Jarl don't lose it.
+                if apply_fixes {
+                    fixed_stdin = Some(contents);
+                }
+                Err(e)
+            }
+        };
+        vec![(stdin_filename.display().to_string(), result)]
+    } else {
+        // Files that didn't change since the last run are not checked again.
+        // The cache isn't used when applying fixes since they modify files.
+        // Each configuration has its own cache keys.
+        let caches = (!args.no_cache && !apply_fixes).then(|| {
+            configs
+                .iter()
+                .map(|config| {
+                    let cache = Cache::new(config);
+                    let (cached_results, misses) = cache.partition(&config.paths);
+                    (cache, cached_results, misses)
+                })
+                .collect::<Vec<_>>()
+        });
+        let configs = match &caches {
+            Some(caches) => configs
+                .into_iter()
+                .zip(caches)
+                .map(|(config, (_cache, _cached_results, misses))| Config {
+                    paths: misses.iter().map(|miss| miss.path.clone()).collect(),
+                    ..config
+                })
+                .collect(),
+            None => configs,
+        };
+
+        // A budget too large to be represented as a deadline is never exceeded.
+        let deadline = args
+            .time_budget
+            .and_then(|time_budget| start_budget.checked_add(time_budget));
+        let mut file_results = if let Some(deadline) = deadline {
+            check_with_deadline(configs, deadline, &mut n_skipped_files)
+        } else {
+            jarl_core::check::check_all(configs)
+        };
+
+        if let Some(caches) = caches {
+            for (cache, cached_results, misses) in caches {
+                cache.store(&misses, &file_results);
+                file_results.extend(cached_results);
+            }
+        }
+        file_results
+    };
+
+    if let Some((path, coverage_configs)) = &coverage_report {
+        write_coverage_report(path, &file_results, coverage_configs)?;
+    }
+
+    // Files that couldn't be fixed are not checked again: their errors are
+    // already reported.
+    let unfixed: Option<Vec<UnfixedCount>> = unfixed_configs.map(|unfixed_configs| {
+        let checked: HashSet<&str> = file_results
+            .iter()
+            .filter(|(_path, result)| result.is_ok())
+            .map(|(path, _result)| path.as_str())
+            .collect();
+        let results = match &fixed_stdin {
+            Some(fixed) if !checked.is_empty() => vec![(
+                stdin_filename.display().to_string(),
+                jarl_core::check::check_contents(fixed, &stdin_filename, &unfixed_configs[0])
+                    .map(|(diagnostics, _)| diagnostics),
+            )],
+            Some(_) => vec![],
+            None => jarl_core::check::check_all(
+                unfixed_configs
+                    .into_iter()
+                    .map(|config| Config {
+                        paths: config
+                            .paths
+                            .iter()
+                            .filter(|path| checked.contains(relativize_path(path).as_str()))
+                            .cloned()
+                            .collect(),
+                        ..config
+                    })
+                    .collect(),
+            ),
+        };
+        count_unfixed(&results)
+    });
 
     let mut all_errors = Vec::new();
     let mut all_diagnostics = Vec::new();
 
+    // Skip timing and config info for JSON/GitHub/SARIF/JUnit to avoid
+    // corrupting structured output.
+    let is_structured_format = matches!(
+        args.output_format,
+        OutputFormat::Json | OutputFormat::Github | OutputFormat::Sarif | OutputFormat::Junit
+    );
+
     for (path, result) in file_results {
         match result {
-            Ok(diagnostics) => {
+            Ok(mut diagnostics) => {
+                if args.namespaced_rule_ids && is_structured_format {
+                    diagnostics
+                        .iter_mut()
+                        .for_each(Diagnostic::namespace_rule_id);
+                }
+                if args.output_format == OutputFormat::Json
+                    && args.column_base == Some(ColumnBase::One)
+                {
+                    diagnostics
+                        .iter_mut()
+                        .for_each(Diagnostic::use_one_based_column);
+                }
                 if !diagnostics.is_empty() {
                     all_diagnostics.push((path, diagnostics));
                 }
@@ -106,35 +416,90 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
 
     all_diagnostics_flat.sort();
 
+    if let Some(baseline) = &args.baseline {
+        if args.write_baseline {
+            return write_baseline(baseline, &all_diagnostics_flat);
+        }
+        all_diagnostics_flat = filter_baseline(baseline, all_diagnostics_flat)?;
+    }
+
     if args.statistics {
-        return print_statistics(&all_diagnostics_flat, parent_config_path);
+        let json = args.output_format == OutputFormat::Json;
+        return print_statistics(&all_diagnostics_flat, parent_config_path, json);
+    }
+
+    if let Some(format) = args.fix_report {
+        return print_fix_report(&all_diagnostics_flat, format);
+    }
+
+    if args.workspace_edit {
+        return print_workspace_edit(&all_diagnostics_flat);
+    }
+
+    if args.print_fingerprint {
+        return print_fingerprint(&all_diagnostics_flat);
     }
 
-    let mut stdout = std::io::stdout();
+    let (mut writer, is_terminal): (Box<dyn Write>, bool) = if let Some(fixed) = &fixed_stdin {
+        print!("{fixed}");
+        std::io::stdout().flush()?;
+        (Box::new(std::io::stderr()), std::io::stderr().is_terminal())
+    } else {
+        (Box::new(std::io::stdout()), std::io::stdout().is_terminal())
+    };
+    let hyperlinks = args.hyperlinks.is_enabled(is_terminal);
 
     match args.output_format {
         OutputFormat::Concise => {
-            ConciseEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+            let column_base = args.column_base.unwrap_or(ColumnBase::One);
+            let group_by = args.group_by;
+            let compact_files = args.compact_files;
+            ConciseEmitter { hyperlinks, column_base, group_by, compact_files }.emit(
+                &mut writer,
+                &all_diagnostics_flat,
+                &all_errors,
+            )?;
         }
         OutputFormat::Json => {
-            JsonEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+            let unfixed = unfixed.as_deref();
+            JsonEmitter { unfixed }.emit(&mut writer, &all_diagnostics_flat, &all_errors)?;
         }
         OutputFormat::Github => {
-            GithubEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+            GithubEmitter.emit(&mut writer, &all_diagnostics_flat, &all_errors)?;
+        }
+        OutputFormat::Sarif => {
+            let namespaced_rule_ids = args.namespaced_rule_ids;
+            SarifEmitter { namespaced_rule_ids }.emit(
+                &mut writer,
+                &all_diagnostics_flat,
+                &all_errors,
+            )?;
+        }
+        OutputFormat::Junit => {
+            JunitEmitter.emit(&mut writer, &all_diagnostics_flat, &all_errors)?;
         }
         OutputFormat::Full => {
-            FullEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+            FullEmitter { hyperlinks }.emit(&mut writer, &all_diagnostics_flat, &all_errors)?;
         }
     }
 
-    // For human-readable formats, print timing and config info
-    // Skip for JSON/GitHub to avoid corrupting structured output
-    let is_structured_format = matches!(
-        args.output_format,
-        OutputFormat::Json | OutputFormat::Github
-    );
+    if let Some(unfixed) = &unfixed
+        && args.output_format != OutputFormat::Json
+    {
+        print_unfixed(unfixed);
+    }
+
+    // This is written to stderr so that it doesn't corrupt structured output.
+    if n_skipped_files > 0 {
+        eprintln!(
+            "{}: Time budget exceeded, {n_skipped_files} files were not checked.",
+            "Warning".yellow().bold()
+        );
+    }
 
-    if !is_structured_format {
+    // For human-readable formats, print timing and config info.
+    // Don't mix the fixed code written to stdout with additional info.
+    if !is_structured_format && fixed_stdin.is_none() {
         // Inform the user if the config file used comes from a parent directory.
         if let Some(config_path) = parent_config_path {
             println!("\nUsed '{}'", config_path.display());
@@ -144,15 +509,125 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
             let duration = start.elapsed();
             println!("\nChecked files in: {duration:?}");
         }
+
+        if let Some(version_gated_configs) = version_gated_configs {
+            print_version_gated_note(version_gated_configs);
+        }
     }
 
     if !all_errors.is_empty() {
         return Ok(ExitStatus::Error);
     }
 
-    if all_diagnostics.is_empty() {
-        return Ok(ExitStatus::Success);
+    if n_skipped_files > 0 {
+        return Ok(ExitStatus::TimeBudgetExceeded);
+    }
+
+    // Violations whose severity is "warning" or "info" in `jarl.toml` don't
+    // make the check fail (unless `--warnings-as-errors` is passed for
+    // warnings). With `--fail-on`, only violations of rules in the given
+    // categories make the check fail.
+    if all_diagnostics_flat.iter().any(|d| {
+        d.is_failure(args.warnings_as_errors)
+            && fail_on
+                .as_deref()
+                .is_none_or(|categories| has_any_category(d, categories))
+    }) {
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Print the files in which the rules that require a specific R version would
+/// report violations. Those rules are disabled when the minimum R version is
+/// unknown, so these files may look clean while they are only partially
+/// checked.
+fn print_version_gated_note(configs: Vec<Config>) {
+    let mut results = jarl_core::check::check_all(configs);
+    results.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let files = results
+        .into_iter()
+        .filter_map(|(path, result)| {
+            let mut rules: Vec<String> = result.ok()?.into_iter().map(|d| d.message.name).collect();
+            rules.sort();
+            rules.dedup();
+            (!rules.is_empty()).then(|| format!("  - {path} ({})", rules.join(", ")))
+        })
+        .collect::<Vec<_>>();
+
+    if files.is_empty() {
+        return;
+    }
+
+    println!(
+        "\n{}: The minimum R version is unknown, so rules that require a specific R version were skipped. They would report violations in:",
+        "Note".bold()
+    );
+    for file in files {
+        println!("{file}");
+    }
+    println!(
+        "Set the minimum R version with `--min-r-version` or in the `Depends` field of `DESCRIPTION` to enable them."
+    );
+}
+
+/// Parse the names of groups of rules passed to `flag`, separated by a comma,
+/// e.g. `--fail-on CORR,SUSP`.
+fn parse_categories(value: &str, flag: &str) -> Result<Vec<Category>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            name.parse::<Category>().map_err(|_| {
+                let categories = Category::ALL
+                    .iter()
+                    .map(|category| category.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                anyhow::anyhow!("Invalid value in `{flag}`: {name} (must be one of {categories}).")
+            })
+        })
+        .collect()
+}
+
+/// Whether the rule violated by `diagnostic` belongs to one of `categories`.
+fn has_any_category(diagnostic: &Diagnostic, categories: &[Category]) -> bool {
+    diagnostic.rule().is_some_and(|rule| {
+        categories
+            .iter()
+            .any(|category| rule.has_category(*category))
+    })
+}
+
+/// Number of files checked in parallel between two checks of the time budget.
+const TIME_BUDGET_BATCH_SIZE: usize = 32;
+
+/// Check the files in batches and stop once `deadline` is passed. The first
+/// batch is always checked so that there is some progress even with a tiny
+/// budget. The number of files that were not checked is stored in
+/// `n_skipped_files`.
+fn check_with_deadline(
+    configs: Vec<Config>,
+    deadline: Instant,
+    n_skipped_files: &mut usize,
+) -> Vec<(String, Result<Vec<Diagnostic>>)> {
+    let mut results = Vec::new();
+    let mut n_batches = 0;
+
+    for config in &configs {
+        for (i, batch) in config.paths.chunks(TIME_BUDGET_BATCH_SIZE).enumerate() {
+            if n_batches > 0 && Instant::now() >= deadline {
+                *n_skipped_files += config.paths.len() - i * TIME_BUDGET_BATCH_SIZE;
+                break;
+            }
+            let batch_config = Config { paths: batch.to_vec(), ..config.clone() };
+            results.extend(jarl_core::check::check(batch_config));
+            n_batches += 1;
+        }
     }
 
-    Ok(ExitStatus::Failure)
+    results
 }
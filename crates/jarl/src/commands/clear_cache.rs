@@ -0,0 +1,21 @@
+use anyhow::Context;
+use std::path::Path;
+
+use crate::cache::CACHE_DIRECTORY;
+use crate::{args::ClearCacheCommand, status::ExitStatus};
+
+/// Remove the cache of the current directory, e.g. to free disk space or if
+/// it looks outdated.
+pub(crate) fn clear_cache(_command: ClearCacheCommand) -> anyhow::Result<ExitStatus> {
+    let directory = Path::new(CACHE_DIRECTORY);
+    if !directory.exists() {
+        println!("No cache to remove.");
+        return Ok(ExitStatus::Success);
+    }
+
+    std::fs::remove_dir_all(directory)
+        .with_context(|| format!("Failed to remove the cache: {CACHE_DIRECTORY}"))?;
+    println!("Removed the cache `{CACHE_DIRECTORY}`.");
+
+    Ok(ExitStatus::Success)
+}
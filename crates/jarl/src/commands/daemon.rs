@@ -0,0 +1,165 @@
+use air_workspace::resolve::PathResolver;
+use anyhow::Context;
+use jarl_core::config::{ArgsConfig, Config, build_config};
+use jarl_core::discovery::discover_settings;
+use jarl_core::settings::Settings;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixListener;
+use std::path::{Path, PathBuf};
+
+use crate::output_format::JsonDiagnostic;
+use crate::{args::DaemonCommand, status::ExitStatus};
+
+/// A request sent to the daemon, on a single line.
+#[derive(Deserialize)]
+struct DaemonRequest {
+    /// The R code to check.
+    source: String,
+    /// The logical name of the code. It is used in the diagnostics and to find
+    /// the `jarl.toml` that applies.
+    path: PathBuf,
+    #[serde(default)]
+    config: RequestConfig,
+}
+
+/// The options of `jarl check` that can be passed in a request.
+#[derive(Clone, Default, Deserialize, PartialEq, Eq, Hash)]
+#[serde(default)]
+struct RequestConfig {
+    select: String,
+    extend_select: String,
+    ignore: String,
+    min_r_version: Option<String>,
+}
+
+/// The response to a request, on a single line. `error` is set if the request
+/// couldn't be parsed or the code couldn't be checked.
+#[derive(Serialize)]
+struct DaemonResponse<'a> {
+    diagnostics: Vec<JsonDiagnostic<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Build the configuration of each directory and set of options only once, so
+/// that `jarl.toml` isn't read and the rules aren't resolved for every request.
+#[derive(Default)]
+struct ConfigCache {
+    configs: HashMap<(PathBuf, RequestConfig), Config>,
+}
+
+impl ConfigCache {
+    fn get(&mut self, path: &Path, request_config: &RequestConfig) -> anyhow::Result<&Config> {
+        let directory = path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from("."));
+        let key = (directory, request_config.clone());
+
+        if !self.configs.contains_key(&key) {
+            tracing::debug!("Building the configuration for {}", key.0.display());
+            let config = build_request_config(path, &key.0, request_config)?;
+            self.configs.insert(key.clone(), config);
+        }
+
+        Ok(&self.configs[&key])
+    }
+}
+
+fn build_request_config(
+    path: &Path,
+    directory: &Path,
+    request_config: &RequestConfig,
+) -> anyhow::Result<Config> {
+    let mut resolver = PathResolver::new(Settings::default());
+    for ds in discover_settings(&[directory])? {
+        resolver.add(&ds.directory, ds.settings);
+    }
+
+    let check_config = ArgsConfig {
+        files: vec![path.to_path_buf()],
+        fix: false,
+        unsafe_fixes: false,
+        fix_only: false,
+        select: request_config.select.clone(),
+        extend_select: request_config.extend_select.clone(),
+        ignore: request_config.ignore.clone(),
+        min_r_version: request_config.min_r_version.clone(),
+        allow_dirty: false,
+        allow_no_vcs: true,
+        assignment: None,
+        show_context: false,
+        atomic: false,
+        only: None,
+        roxygen_examples: false,
+        min_confidence: None,
+        fix_iterations: None,
+    };
+
+    build_config(&check_config, &resolver, vec![path.to_path_buf()])
+}
+
+/// Check the code of a request and serialize the response.
+fn handle_request(line: &str, cache: &mut ConfigCache) -> serde_json::Result<String> {
+    let result = serde_json::from_str::<DaemonRequest>(line)
+        .map_err(anyhow::Error::from)
+        .and_then(|request| {
+            let config = cache.get(&request.path, &request.config)?;
+            jarl_core::check::check_contents(&request.source, &request.path, config)
+        });
+
+    let response = match &result {
+        Ok((diagnostics, _)) => DaemonResponse {
+            diagnostics: diagnostics.iter().map(JsonDiagnostic::new).collect(),
+            error: None,
+        },
+        Err(err) => DaemonResponse {
+            diagnostics: vec![],
+            error: Some(format!("{:#}", err)),
+        },
+    };
+    serde_json::to_string(&response)
+}
+
+/// Check the R code sent over a Unix socket. Each line received is a JSON
+/// request such as `{"source": "any(is.na(x))", "path": "R/foo.R"}` and gets
+/// a JSON response on a single line with the diagnostics. Connections are
+/// handled one after the other.
+pub(crate) fn daemon(command: DaemonCommand) -> anyhow::Result<ExitStatus> {
+    let listener = UnixListener::bind(&command.socket)
+        .with_context(|| format!("Failed to listen on socket: {}", command.socket.display()))?;
+    tracing::info!("Listening on {}", command.socket.display());
+
+    let mut cache = ConfigCache::default();
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                tracing::warn!("Failed to accept connection: {err}");
+                continue;
+            }
+        };
+
+        let mut writer = stream.try_clone()?;
+        for line in BufReader::new(stream).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut response = handle_request(&line, &mut cache)?;
+            response.push('\n');
+            if writer.write_all(response.as_bytes()).is_err() {
+                break;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,15 @@
+use anyhow::Context;
+use std::fs;
+
+use crate::{args::DumpAstCommand, status::ExitStatus};
+
+/// Print the syntax tree of an R file. This is useful to write rules and to
+/// report bugs about violations that are wrongly detected (or not detected).
+pub(crate) fn dump_ast(command: DumpAstCommand) -> anyhow::Result<ExitStatus> {
+    let file = command.file;
+    let contents = fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read file: {}", file.display()))?;
+
+    println!("{}", jarl_core::check::dump_ast(&contents));
+    Ok(ExitStatus::Success)
+}
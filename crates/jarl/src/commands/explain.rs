@@ -0,0 +1,136 @@
+use clap::ValueEnum;
+use colored::Colorize;
+use jarl_core::rule_set::{FixStatus, Rule};
+use serde::Serialize;
+
+use crate::{args::ExplainCommand, status::ExitStatus};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ExplainFormat {
+    #[default]
+    /// Print the documentation as plain text
+    Text,
+    /// Print the metadata and the documentation as JSON
+    Json,
+}
+
+#[derive(Serialize)]
+struct Explanation {
+    name: &'static str,
+    categories: Vec<&'static str>,
+    fix: &'static str,
+    enabled_by_default: bool,
+    minimum_r_version: Option<String>,
+    url: String,
+    documentation: Option<&'static str>,
+}
+
+impl Explanation {
+    fn new(rule: Rule) -> Self {
+        Self {
+            name: rule.name(),
+            categories: rule.categories().iter().map(|c| c.as_str()).collect(),
+            fix: match rule.fix_status() {
+                FixStatus::Safe => "safe",
+                FixStatus::Unsafe => "unsafe",
+                FixStatus::None => "none",
+            },
+            enabled_by_default: rule.is_enabled_by_default(),
+            minimum_r_version: rule
+                .minimum_r_version()
+                .map(|(major, minor, patch)| format!("{major}.{minor}.{patch}")),
+            url: format!("https://jarl.etiennebacher.com/rules/{}", rule.name()),
+            documentation: rule.documentation(),
+        }
+    }
+}
+
+/// Print the metadata of a rule (categories, fix, minimum R version, etc.) and
+/// its documentation, which contains examples of code it reports.
+pub(crate) fn explain(command: ExplainCommand) -> anyhow::Result<ExitStatus> {
+    let Some(rule) = Rule::from_name(&command.rule) else {
+        return Err(unknown_rule_error(&command.rule));
+    };
+    let explanation = Explanation::new(rule);
+
+    match command.output_format {
+        ExplainFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&explanation)?);
+        }
+        ExplainFormat::Text => {
+            let categories = rule
+                .categories()
+                .iter()
+                .map(|c| format!("{} ({})", c.long_name(), c.as_str()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let enabled = if explanation.enabled_by_default {
+                "yes"
+            } else {
+                "no"
+            };
+
+            println!("{}\n", explanation.name.bold());
+            println!("Categories: {categories}");
+            println!("Fix: {}", explanation.fix);
+            println!("Enabled by default: {enabled}");
+            println!(
+                "Minimum R version: {}",
+                explanation.minimum_r_version.as_deref().unwrap_or("none")
+            );
+            println!("Documentation: {}", explanation.url);
+            if let Some(documentation) = explanation.documentation {
+                println!("\n{documentation}");
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Error for a rule that doesn't exist, suggesting the rules with a close name.
+/// A rule is suggested if at most a third of the characters must be changed.
+fn unknown_rule_error(name: &str) -> anyhow::Error {
+    let max_distance = (name.chars().count() / 3).max(1);
+    let mut suggestions = Rule::all()
+        .iter()
+        .map(|rule| (levenshtein(name, rule.name()), rule.name()))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect::<Vec<_>>();
+    suggestions.sort();
+
+    let suggestions = suggestions
+        .iter()
+        .take(3)
+        .map(|(_, name)| format!("`{name}`"))
+        .collect::<Vec<_>>();
+
+    match suggestions.as_slice() {
+        [] => anyhow::anyhow!("Unknown rule: `{name}`."),
+        [suggestion] => anyhow::anyhow!("Unknown rule: `{name}`. Did you mean {suggestion}?"),
+        _ => anyhow::anyhow!(
+            "Unknown rule: `{name}`. Did you mean one of {}?",
+            suggestions.join(", ")
+        ),
+    }
+}
+
+/// Number of insertions, deletions, and substitutions of characters needed to
+/// go from `a` to `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            let deletion = previous[j + 1] + 1;
+            let insertion = current[j] + 1;
+            current.push(substitution.min(deletion).min(insertion));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
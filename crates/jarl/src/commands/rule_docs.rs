@@ -0,0 +1,87 @@
+use anyhow::Context;
+use jarl_core::rule_set::{FixStatus, Rule};
+use std::fs;
+
+use crate::{args::RuleDocsCommand, status::ExitStatus};
+
+/// Write one markdown file per rule in the given directory. Those files only
+/// contain the metadata of the rule (category, fix, minimum R version, etc.)
+/// and placeholders for the sections that must be written by hand. Files that
+/// already exist are left untouched.
+pub(crate) fn rule_docs(command: RuleDocsCommand) -> anyhow::Result<ExitStatus> {
+    let dir = command.rule_docs_dir;
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+
+    let mut n_written = 0;
+    for rule in Rule::all() {
+        let path = dir.join(format!("{}.md", rule.name()));
+        if path.exists() {
+            continue;
+        }
+        fs::write(&path, rule_doc_stub(*rule))
+            .with_context(|| format!("Failed to write file: {}", path.display()))?;
+        n_written += 1;
+    }
+
+    println!("Wrote {n_written} rule stub(s) to '{}'.", dir.display());
+    Ok(ExitStatus::Success)
+}
+
+fn rule_doc_stub(rule: Rule) -> String {
+    let categories = rule
+        .categories()
+        .iter()
+        .map(|c| format!("{} (`{}`)", c.long_name(), c.as_str()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let fix = match rule.fix_status() {
+        FixStatus::Safe => "Safe",
+        FixStatus::Unsafe => "Unsafe",
+        FixStatus::None => "None",
+    };
+
+    let enabled = if rule.is_enabled_by_default() {
+        "Yes"
+    } else {
+        "No"
+    };
+
+    let min_r_version = match rule.minimum_r_version() {
+        Some((major, minor, patch)) => format!("{major}.{minor}.{patch}"),
+        None => "None".to_string(),
+    };
+
+    format!(
+        "# {name}
+## Category
+
+{categories}
+
+## Metadata
+
+- Fix: {fix}
+- Enabled by default: {enabled}
+- Minimum R version: {min_r_version}
+
+## What it does
+
+TODO
+
+## Why is this bad?
+
+TODO
+
+## Example
+
+```r
+```
+
+Use instead:
+```r
+```
+",
+        name = rule.name()
+    )
+}
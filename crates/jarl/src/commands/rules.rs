@@ -0,0 +1,145 @@
+use air_workspace::resolve::PathResolver;
+use clap::ValueEnum;
+use jarl_core::config::{ArgsConfig, build_config};
+use jarl_core::discovery::discover_settings;
+use jarl_core::rule_set::{FixStatus, Rule};
+use jarl_core::settings::Settings;
+use serde::Serialize;
+use std::env;
+
+use crate::{args::RulesCommand, status::ExitStatus};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum RulesFormat {
+    #[default]
+    /// Print the rules as a table
+    Text,
+    /// Print the rules as JSON
+    Json,
+}
+
+#[derive(Serialize)]
+struct RulesOutput {
+    rules: Vec<RuleInfo>,
+}
+
+#[derive(Serialize)]
+struct RuleInfo {
+    name: &'static str,
+    categories: Vec<&'static str>,
+    fix: &'static str,
+    minimum_r_version: Option<String>,
+    enabled_by_default: bool,
+    /// Whether the rule is run with the configuration of the current
+    /// directory.
+    enabled: bool,
+}
+
+/// List all rules with their metadata, and whether they are enabled with the
+/// `jarl.toml` that applies to the current directory. This takes into account
+/// the rules selected and ignored in `jarl.toml` and the minimum R version.
+pub(crate) fn rules(command: RulesCommand) -> anyhow::Result<ExitStatus> {
+    let cwd = env::current_dir()?;
+
+    let mut resolver = PathResolver::new(Settings::default());
+    for ds in discover_settings(&[&cwd])? {
+        resolver.add(&ds.directory, ds.settings);
+    }
+
+    let check_config = ArgsConfig {
+        files: vec![cwd.clone()],
+        fix: false,
+        unsafe_fixes: false,
+        fix_only: false,
+        select: String::new(),
+        extend_select: String::new(),
+        ignore: String::new(),
+        min_r_version: command.min_r_version,
+        allow_dirty: false,
+        allow_no_vcs: true,
+        assignment: None,
+        show_context: false,
+        atomic: false,
+        only: None,
+        roxygen_examples: false,
+        min_confidence: None,
+        fix_iterations: None,
+    };
+    let config = build_config(&check_config, &resolver, vec![cwd])?;
+
+    let rules = Rule::all()
+        .iter()
+        .map(|rule| RuleInfo {
+            name: rule.name(),
+            categories: rule.categories().iter().map(|c| c.as_str()).collect(),
+            fix: match rule.fix_status() {
+                FixStatus::Safe => "safe",
+                FixStatus::Unsafe => "unsafe",
+                FixStatus::None => "none",
+            },
+            minimum_r_version: rule
+                .minimum_r_version()
+                .map(|(major, minor, patch)| format!("{major}.{minor}.{patch}")),
+            enabled_by_default: rule.is_enabled_by_default(),
+            enabled: config.rules.contains(rule),
+        })
+        .collect::<Vec<_>>();
+
+    match command.output_format {
+        RulesFormat::Json => {
+            let output = RulesOutput { rules };
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        RulesFormat::Text => {
+            print_rules_table(&rules);
+            if !config.version_gated_rules.is_empty() {
+                println!(
+                    "\nThe minimum R version is unknown, so rules that require a specific R version are disabled. Set it with `--min-r-version` or in the `Depends` field of `DESCRIPTION` to enable them."
+                );
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+fn print_rules_table(rules: &[RuleInfo]) {
+    let rows = rules
+        .iter()
+        .map(|rule| {
+            [
+                rule.name.to_string(),
+                rule.categories.join(","),
+                rule.fix.to_string(),
+                rule.minimum_r_version
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+                if rule.enabled { "yes" } else { "no" }.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let header = ["RULE", "CATEGORIES", "FIX", "MIN R VERSION", "ENABLED"].map(String::from);
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| row[i].len())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for row in std::iter::once(&header).chain(&rows) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+
+    let n_enabled = rules.iter().filter(|rule| rule.enabled).count();
+    println!("\n{n_enabled} of {} rules are enabled.", rules.len());
+}
@@ -0,0 +1,85 @@
+use air_fs::relativize_path;
+use anyhow::Context;
+use jarl_core::config::Config;
+use jarl_core::diagnostic::Diagnostic;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// The rules that were run on each file, to show that some rules were applied
+/// everywhere.
+#[derive(Serialize)]
+struct CoverageReport {
+    files: Vec<FileCoverage>,
+}
+
+#[derive(Serialize)]
+struct FileCoverage {
+    file: String,
+    rules: Vec<&'static str>,
+    /// Set if the file couldn't be checked, in which case no rule was run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Write the rules that were run on each checked file to `path`. Files that
+/// couldn't be checked, e.g. because they couldn't be parsed, have no rules.
+/// Each file is matched with the configuration it was checked with.
+pub fn write_coverage_report(
+    path: &Path,
+    file_results: &[(String, anyhow::Result<Vec<Diagnostic>>)],
+    configs: &[Config],
+) -> anyhow::Result<()> {
+    let config_of_file: HashMap<String, &Config> = configs
+        .iter()
+        .flat_map(|config| {
+            config
+                .paths
+                .iter()
+                .map(move |path| (relativize_path(path), config))
+        })
+        .collect();
+
+    let mut files = file_results
+        .iter()
+        .map(|(file, result)| {
+            let rules = result
+                .as_ref()
+                .map_err(|err| format!("{err:#}"))
+                .and_then(|_| {
+                    std::fs::read_to_string(file)
+                        .map_err(|err| format!("Failed to read file: {file}: {err}"))
+                })
+                .and_then(|contents| {
+                    let config = config_of_file
+                        .get(file)
+                        .ok_or_else(|| format!("No configuration found for {file}"))?;
+                    Ok(jarl_core::check::active_rules(&contents, config))
+                });
+
+            match rules {
+                Ok(rules) => {
+                    let mut rules: Vec<&'static str> = rules.iter().map(|r| r.name()).collect();
+                    rules.sort_unstable();
+                    FileCoverage {
+                        // Use the same separator on all platforms.
+                        file: file.replace('\\', "/"),
+                        rules,
+                        error: None,
+                    }
+                }
+                Err(error) => FileCoverage {
+                    file: file.replace('\\', "/"),
+                    rules: vec![],
+                    error: Some(error),
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| a.file.cmp(&b.file));
+
+    let mut contents = serde_json::to_string_pretty(&CoverageReport { files })?;
+    contents.push('\n');
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write coverage report: {}", path.display()))
+}
@@ -0,0 +1,60 @@
+use colored::Colorize;
+use similar::TextDiff;
+
+use crate::status::ExitStatus;
+
+/// Print the changes that `--fix` would make as a unified diff per file. This
+/// doesn't modify any file.
+///
+/// `files` contains the original and fixed content of each file. Files that
+/// wouldn't change are skipped. Only the diff is written to stdout so that it
+/// can be applied with `git apply`. The exit status is a failure if at least
+/// one file would change.
+pub fn print_diff(
+    files: Vec<(String, anyhow::Result<(String, String)>)>,
+) -> anyhow::Result<ExitStatus> {
+    let mut n_changed = 0;
+    let mut has_errors = false;
+
+    for (path, result) in files {
+        let (original, fixed) = match result {
+            Ok(contents) => contents,
+            Err(err) => {
+                let root_cause = err.chain().last().unwrap();
+                if root_cause.is::<jarl_core::error::ParseError>() {
+                    eprintln!("{}: {}", "Error".red().bold(), root_cause);
+                } else {
+                    eprintln!("{}: {}", "Error".red().bold(), err);
+                }
+                has_errors = true;
+                continue;
+            }
+        };
+        if original == fixed {
+            continue;
+        }
+
+        // Use the same separator on all platforms.
+        let path = path.replace('\\', "/");
+        let diff = TextDiff::from_lines(&original, &fixed);
+        let mut unified_diff = diff.unified_diff();
+        print!(
+            "{}",
+            unified_diff.header(&format!("a/{path}"), &format!("b/{path}"))
+        );
+        n_changed += 1;
+    }
+
+    if has_errors {
+        return Ok(ExitStatus::Error);
+    }
+
+    if n_changed == 0 {
+        eprintln!("No fixes to apply.");
+        Ok(ExitStatus::Success)
+    } else {
+        let files = if n_changed == 1 { "file" } else { "files" };
+        eprintln!("\n{n_changed} {files} would be changed by `--fix`.");
+        Ok(ExitStatus::Failure)
+    }
+}
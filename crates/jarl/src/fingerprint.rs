@@ -0,0 +1,71 @@
+use jarl_core::diagnostic::Diagnostic;
+
+use crate::status::ExitStatus;
+
+/// Print a hash of the violations, so that tools (e.g. CI caches) can detect
+/// whether the results changed between two runs without comparing the full
+/// output.
+///
+/// The hash only depends on the rule, path, range, and message of each
+/// violation, so it is the same on every run and every platform as long as
+/// the violations are the same. `diagnostics` must be sorted.
+pub fn print_fingerprint(diagnostics: &[&Diagnostic]) -> anyhow::Result<ExitStatus> {
+    println!("{:016x}", fingerprint(diagnostics));
+
+    if diagnostics.is_empty() {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// 64-bit FNV-1a hash of the violations.
+fn fingerprint(diagnostics: &[&Diagnostic]) -> u64 {
+    let mut hasher = Fnv1a::new();
+
+    for diagnostic in diagnostics {
+        hasher.write(diagnostic.message.name.as_bytes());
+        // Use the same separator on all platforms.
+        hasher.write(
+            diagnostic
+                .filename
+                .to_string_lossy()
+                .replace('\\', "/")
+                .as_bytes(),
+        );
+        hasher.write(&u32::from(diagnostic.range.start()).to_le_bytes());
+        hasher.write(&u32::from(diagnostic.range.end()).to_le_bytes());
+        hasher.write(diagnostic.message.body.as_bytes());
+    }
+
+    hasher.finish()
+}
+
+/// 64-bit FNV-1a hasher. `std::hash::DefaultHasher` isn't used because its
+/// output may change between Rust versions, and the hashes are stored (e.g.
+/// in CI caches or in baselines).
+pub(crate) struct Fnv1a {
+    hash: u64,
+}
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    pub(crate) fn new() -> Self {
+        Self { hash: Self::OFFSET_BASIS }
+    }
+
+    /// Add a field to the hash.
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        // Separate fields so that e.g. "ab" + "c" and "a" + "bc" differ.
+        for byte in bytes.iter().chain(&[0]) {
+            self.hash ^= u64::from(*byte);
+            self.hash = self.hash.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.hash
+    }
+}
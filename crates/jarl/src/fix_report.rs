@@ -0,0 +1,79 @@
+use clap::ValueEnum;
+use jarl_core::diagnostic::Diagnostic;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use crate::status::ExitStatus;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+pub enum FixReportFormat {
+    /// Print the fix report as JSON
+    Json,
+}
+
+/// Number of violations of a rule, split by the kind of fix they have.
+#[derive(Debug, Default, Serialize)]
+struct FixCounts {
+    safe: usize,
+    #[serde(rename = "unsafe")]
+    unsafe_: usize,
+    manual: usize,
+}
+
+impl FixCounts {
+    fn add(&mut self, diagnostic: &Diagnostic) {
+        if diagnostic.has_safe_fix() {
+            self.safe += 1;
+        } else if diagnostic.has_unsafe_fix() {
+            self.unsafe_ += 1;
+        } else {
+            // Either the rule has no fix, or the fix was disabled (e.g. with
+            // `unfixable` in `jarl.toml`, or because it contains comments).
+            self.manual += 1;
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct FixReport<'a> {
+    total: FixCounts,
+    rules: BTreeMap<&'a str, FixCounts>,
+}
+
+/// Report how many violations could be fixed automatically (with or without
+/// `--unsafe-fixes`) and how many require a manual intervention. This doesn't
+/// modify any file, so it can be run regularly to follow the progress of a
+/// cleanup.
+pub fn print_fix_report(
+    diagnostics: &[&Diagnostic],
+    format: FixReportFormat,
+) -> anyhow::Result<ExitStatus> {
+    let mut report = FixReport {
+        total: FixCounts::default(),
+        rules: BTreeMap::new(),
+    };
+
+    for diagnostic in diagnostics {
+        report.total.add(diagnostic);
+        report
+            .rules
+            .entry(diagnostic.message.name.as_str())
+            .or_default()
+            .add(diagnostic);
+    }
+
+    let mut stdout = std::io::stdout();
+    match format {
+        FixReportFormat::Json => {
+            serde_json::to_writer_pretty(&mut stdout, &report)?;
+            stdout.flush()?;
+        }
+    }
+
+    if diagnostics.is_empty() {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
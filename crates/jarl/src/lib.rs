@@ -3,11 +3,19 @@ use crate::args::Command;
 use crate::status::ExitStatus;
 
 pub mod args;
+pub mod baseline;
+pub mod cache;
 pub mod commands;
+pub mod coverage;
+pub mod diff;
+pub mod fingerprint;
+pub mod fix_report;
 pub mod logging;
 pub mod output_format;
 pub mod statistics;
 pub mod status;
+pub mod unfixed;
+pub mod workspace_edit;
 
 pub use args::CheckCommand;
 pub use output_format::{ConciseEmitter, JsonEmitter, OutputFormat};
@@ -21,5 +29,17 @@ pub fn run(args: Args) -> anyhow::Result<ExitStatus> {
     match args.command {
         Command::Check(command) => commands::check::check(command),
         Command::Server(command) => commands::server::server(command),
+        #[cfg(unix)]
+        Command::Daemon(command) => commands::daemon::daemon(command),
+        #[cfg(not(unix))]
+        Command::Daemon(command) => Err(anyhow::anyhow!(
+            "Can't listen on {}: `jarl daemon` is only available on Unix.",
+            command.socket.display()
+        )),
+        Command::ClearCache(command) => commands::clear_cache::clear_cache(command),
+        Command::Explain(command) => commands::explain::explain(command),
+        Command::Rules(command) => commands::rules::rules(command),
+        Command::RuleDocs(command) => commands::rule_docs::rule_docs(command),
+        Command::DumpAst(command) => commands::dump_ast::dump_ast(command),
     }
 }
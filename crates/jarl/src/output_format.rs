@@ -5,6 +5,7 @@ use colored::Colorize;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::{BufWriter, Write};
+use std::path::Path;
 
 /// Creates a terminal hyperlink using OSC 8 escape sequences
 /// Format: \x1b]8;;<URL>\x1b\\<TEXT>\x1b]8;;\x1b\\
@@ -15,24 +16,155 @@ fn make_hyperlink(text: &str) -> String {
     )
 }
 
-use jarl_core::diagnostic::Diagnostic;
+/// When to wrap rule names in hyperlinks to their documentation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum HyperlinkMode {
+    #[default]
+    /// Use hyperlinks if the output is a terminal and colors are enabled
+    Auto,
+    /// Always use hyperlinks
+    Always,
+    /// Never use hyperlinks
+    Never,
+}
+
+impl HyperlinkMode {
+    /// Whether hyperlinks should be emitted, given whether the output is
+    /// written to a terminal. Terminals that don't support OSC 8 are expected
+    /// to ignore the escape sequences, but pipes and files would get them
+    /// verbatim.
+    pub fn is_enabled(self, is_terminal: bool) -> bool {
+        match self {
+            HyperlinkMode::Auto => is_terminal && std::env::var("NO_COLOR").is_err(),
+            HyperlinkMode::Always => true,
+            HyperlinkMode::Never => false,
+        }
+    }
+}
+
+/// Whether columns are counted from 0 or from 1 in the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ColumnBase {
+    /// The first column is 0
+    #[value(name = "0")]
+    Zero,
+    /// The first column is 1
+    #[value(name = "1")]
+    One,
+}
+
+impl ColumnBase {
+    /// Number to add to the zero-based columns of diagnostics.
+    pub fn offset(self) -> usize {
+        match self {
+            ColumnBase::Zero => 0,
+            ColumnBase::One => 1,
+        }
+    }
+}
+
+/// How to group violations in the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    /// Group violations by the category of their rule
+    Category,
+}
 
-fn show_hint_statistics(total_diagnostics: i32) {
+use jarl_core::diagnostic::{
+    Applicability, Confidence, Diagnostic, Severity, ViolationData, rule_id,
+};
+use jarl_core::location::Location;
+use jarl_core::rule_set::{Category, FixStatus, Rule};
+use jarl_lsp::lint::DiagnosticFix;
+
+use crate::unfixed::UnfixedCount;
+
+fn show_hint_statistics<W: Write>(writer: &mut W, total_diagnostics: i32) -> std::io::Result<()> {
     let n_violations = std::env::var("JARL_N_VIOLATIONS_HINT_STAT")
         .ok()
         .and_then(|value| value.parse::<i32>().ok())
         .unwrap_or(15);
     if total_diagnostics > n_violations {
-        println!(
+        writeln!(
+            writer,
             "\nMore than {n_violations} errors reported, use `--statistics` to get the count by rule."
-        );
+        )?;
     }
+    Ok(())
 }
 
+/// Version of the schema of the JSON output. It must be incremented when
+/// fields are renamed or removed so that consumers can detect the change.
+pub const JSON_OUTPUT_VERSION: u32 = 1;
+
 #[derive(Debug, Serialize)]
 struct JsonOutput<'a> {
-    diagnostics: Vec<&'a Diagnostic>,
+    version: u32,
+    diagnostics: Vec<JsonDiagnostic<'a>>,
     errors: Vec<JsonError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unfixed: Option<&'a [UnfixedCount]>,
+}
+
+/// A violation in the JSON output and in the responses of `jarl daemon`.
+/// Offsets are in bytes, lines start at 1, and columns start at 0 unless
+/// `--column-base 1` is passed.
+#[derive(Debug, Serialize)]
+pub struct JsonDiagnostic<'a> {
+    message: &'a ViolationData,
+    rule: &'a str,
+    /// First category of the rule.
+    category: Option<&'static str>,
+    /// Rules without a severity in `jarl.toml` are reported as warnings.
+    severity: &'static str,
+    filename: &'a Path,
+    range: [usize; 2],
+    location: Option<Location>,
+    start_byte: usize,
+    end_byte: usize,
+    start_line: Option<usize>,
+    start_col: Option<usize>,
+    end_line: Option<usize>,
+    end_col: Option<usize>,
+    fix: Option<DiagnosticFix>,
+    applicability: Applicability,
+    confidence: Confidence,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enclosing_function: Option<&'a str>,
+}
+
+impl<'a> JsonDiagnostic<'a> {
+    pub fn new(diagnostic: &'a Diagnostic) -> Self {
+        let start_byte: usize = diagnostic.range.start().into();
+        let end_byte: usize = diagnostic.range.end().into();
+        let has_fix = diagnostic.has_safe_fix() || diagnostic.has_unsafe_fix();
+        Self {
+            message: &diagnostic.message,
+            rule: &diagnostic.message.name,
+            category: diagnostic
+                .rule()
+                .and_then(|rule| rule.categories().first())
+                .map(|category| category.as_str()),
+            severity: match diagnostic.severity {
+                Some(Severity::Error) => "error",
+                Some(Severity::Info) => "info",
+                Some(Severity::Warning) | None => "warning",
+            },
+            filename: &diagnostic.filename,
+            range: [start_byte, end_byte],
+            location: diagnostic.location,
+            start_byte,
+            end_byte,
+            start_line: diagnostic.location.map(|location| location.row()),
+            start_col: diagnostic.location.map(|location| location.column()),
+            end_line: diagnostic.end_location.map(|location| location.row()),
+            end_col: diagnostic.end_location.map(|location| location.column()),
+            fix: has_fix.then(|| DiagnosticFix::new(diagnostic)),
+            applicability: diagnostic.applicability,
+            confidence: diagnostic.confidence,
+            enclosing_function: diagnostic.enclosing_function.as_deref(),
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -52,6 +184,10 @@ pub enum OutputFormat {
     Github,
     /// Print diagnostics as JSON
     Json,
+    /// Print diagnostics as SARIF 2.1.0
+    Sarif,
+    /// Print diagnostics as JUnit XML
+    Junit,
 }
 
 /// Takes the diagnostics and parsing errors in each file and then displays
@@ -66,7 +202,82 @@ pub trait Emitter {
     ) -> anyhow::Result<()>;
 }
 
-pub struct ConciseEmitter;
+pub struct ConciseEmitter {
+    /// Whether rule names are clickable links to their documentation.
+    pub hyperlinks: bool,
+    /// Whether columns are counted from 0 or from 1.
+    pub column_base: ColumnBase,
+    /// How to group violations, if at all.
+    pub group_by: Option<GroupBy>,
+    /// Whether to print one line per file with violations instead of one line
+    /// per violation.
+    pub compact_files: bool,
+}
+
+/// Print one line per file with the number of violations and of distinct rules
+/// violated, e.g. `R/foo.R: 3 issues (2 rules)`. Files without violations are
+/// not printed. `diagnostics` must be sorted.
+fn write_compact_files<W: Write>(
+    writer: &mut W,
+    diagnostics: &[&Diagnostic],
+) -> anyhow::Result<()> {
+    for file in diagnostics.chunk_by(|a, b| a.filename == b.filename) {
+        let mut rules: Vec<&String> = file.iter().map(|d| &d.message.name).collect();
+        rules.sort();
+        rules.dedup();
+
+        let issues = if file.len() == 1 { "issue" } else { "issues" };
+        let rules_label = if rules.len() == 1 { "rule" } else { "rules" };
+        writeln!(
+            writer,
+            "{}: {} {issues} ({} {rules_label})",
+            relativize_path(file[0].filename.clone()).white(),
+            file.len(),
+            rules.len()
+        )?;
+    }
+    Ok(())
+}
+
+/// Split the diagnostics by the category of their rule, in the order of
+/// `Category::ALL`. Rules that have several categories are grouped under the
+/// first one. The header of each group contains the number of violations.
+fn group_by_category<'a>(
+    diagnostics: &[&'a Diagnostic],
+) -> Vec<(Option<String>, Vec<&'a Diagnostic>)> {
+    let category_of = |diagnostic: &Diagnostic| {
+        Rule::from_name(&diagnostic.message.name)
+            .and_then(|rule| rule.categories().first().copied())
+    };
+
+    let mut categories: Vec<Option<Category>> = Category::ALL.iter().copied().map(Some).collect();
+    // Shouldn't happen since all diagnostics come from known rules.
+    categories.push(None);
+
+    categories
+        .into_iter()
+        .filter_map(|category| {
+            let group: Vec<&Diagnostic> = diagnostics
+                .iter()
+                .copied()
+                .filter(|diagnostic| category_of(diagnostic) == category)
+                .collect();
+            if group.is_empty() {
+                return None;
+            }
+            let label = match category {
+                Some(category) => format!("{} ({})", category.long_name(), category.as_str()),
+                None => "Other".to_string(),
+            };
+            let count = if group.len() == 1 {
+                "1 error".to_string()
+            } else {
+                format!("{} errors", group.len())
+            };
+            Some((Some(format!("{label}: {count}")), group))
+        })
+        .collect()
+}
 
 impl Emitter for ConciseEmitter {
     fn emit<W: Write>(
@@ -76,9 +287,10 @@ impl Emitter for ConciseEmitter {
         errors: &[(String, anyhow::Error)],
     ) -> anyhow::Result<()> {
         let mut writer = BufWriter::new(writer);
-        let mut total_diagnostics = 0;
-        let mut n_diagnostic_with_fixes = 0usize;
-        let mut n_diagnostic_with_unsafe_fixes = 0usize;
+        let total_diagnostics = diagnostics.len();
+        let n_diagnostic_with_fixes = diagnostics.iter().filter(|d| d.has_safe_fix()).count();
+        let n_diagnostic_with_unsafe_fixes =
+            diagnostics.iter().filter(|d| d.has_unsafe_fix()).count();
 
         // First, print all parsing errors
         if !errors.is_empty() {
@@ -93,62 +305,76 @@ impl Emitter for ConciseEmitter {
             }
         }
 
-        // Cache relativized paths to avoid repeated filesystem operations
-        let mut path_cache = std::collections::HashMap::new();
-
         // Then, print the diagnostics.
-        for diagnostic in diagnostics {
-            let (row, col) = match diagnostic.location {
-                Some(loc) => (loc.row(), loc.column() + 1), // Convert to 1-based for display
-                None => {
-                    unreachable!("Row/col locations must have been parsed successfully before.")
-                }
+        if self.compact_files {
+            write_compact_files(&mut writer, diagnostics)?;
+        } else {
+            // Cache relativized paths to avoid repeated filesystem operations
+            let mut path_cache = std::collections::HashMap::new();
+
+            let groups = match self.group_by {
+                Some(GroupBy::Category) => group_by_category(diagnostics),
+                None => vec![(None, diagnostics.to_vec())],
             };
 
-            // Get or compute relativized path
-            let relative_path = path_cache
-                .entry(&diagnostic.filename)
-                .or_insert_with(|| relativize_path(diagnostic.filename.clone()));
+            for (i, (header, group)) in groups.iter().enumerate() {
+                if let Some(header) = header {
+                    if i > 0 {
+                        writeln!(writer)?;
+                    }
+                    writeln!(writer, "{}", header.bold())?;
+                }
+                for diagnostic in group {
+                    let (row, col) = match diagnostic.location {
+                        Some(loc) => (loc.row(), loc.column() + self.column_base.offset()),
+                        None => {
+                            unreachable!(
+                                "Row/col locations must have been parsed successfully before."
+                            )
+                        }
+                    };
 
-            let message = if let Some(suggestion) = &diagnostic.message.suggestion {
-                format!("{} {}", diagnostic.message.body, suggestion)
-            } else {
-                diagnostic.message.body.clone()
-            };
-            let use_colors = std::env::var("NO_COLOR").is_err();
-            let rule_name = if use_colors {
-                &make_hyperlink(&diagnostic.message.name)
-            } else {
-                &diagnostic.message.name
-            };
-            writeln!(
-                writer,
-                "{} [{}:{}] {} {}",
-                relative_path.white(),
-                row,
-                col,
-                rule_name.red(),
-                message
-            )?;
+                    // Get or compute relativized path
+                    let relative_path = path_cache
+                        .entry(&diagnostic.filename)
+                        .or_insert_with(|| relativize_path(diagnostic.filename.clone()));
 
-            if diagnostic.has_safe_fix() {
-                n_diagnostic_with_fixes += 1;
-            }
-            if diagnostic.has_unsafe_fix() {
-                n_diagnostic_with_unsafe_fixes += 1;
+                    let mut message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                        format!("{} {}", diagnostic.message.body, suggestion)
+                    } else {
+                        diagnostic.message.body.clone()
+                    };
+                    if let Some(function) = &diagnostic.enclosing_function {
+                        message.push_str(&format!(" (in `{}`)", function));
+                    }
+                    if diagnostic.confidence == Confidence::Heuristic {
+                        message.push_str(" (heuristic)");
+                    }
+                    let rule_name = if self.hyperlinks {
+                        &make_hyperlink(&diagnostic.message.name)
+                    } else {
+                        &diagnostic.message.name
+                    };
+                    writeln!(
+                        writer,
+                        "{} [{}:{}] {} {}",
+                        relative_path.white(),
+                        row,
+                        col,
+                        rule_name.red(),
+                        message
+                    )?;
+                }
             }
-            total_diagnostics += 1;
         }
 
-        writer.flush()?; // Ensure all diagnostics are written before summary
-
         // Finally, print the info about the number of errors found and how
         // many can be fixed.
         if total_diagnostics > 0 {
             if total_diagnostics > 1 {
-                println!("\nFound {total_diagnostics} errors.");
+                writeln!(writer, "\nFound {total_diagnostics} errors.")?;
             } else {
-                println!("\nFound 1 error.");
+                writeln!(writer, "\nFound 1 error.")?;
             }
 
             if n_diagnostic_with_fixes > 0 {
@@ -164,28 +390,36 @@ impl Emitter for ConciseEmitter {
                         "{n_diagnostic_with_fixes} fixable with the `--fix` option ({unsafe_label} can be enabled with the `--unsafe-fixes` option)."
                     )
                 };
-                println!("{msg}");
+                writeln!(writer, "{msg}")?;
             } else if n_diagnostic_with_unsafe_fixes > 0 {
                 let label = if n_diagnostic_with_unsafe_fixes == 1 {
                     "1 fix is".to_string()
                 } else {
                     format!("{n_diagnostic_with_unsafe_fixes} fixes are")
                 };
-                println!("{label} available with the `--fix --unsafe-fixes` option.");
+                writeln!(
+                    writer,
+                    "{label} available with the `--fix --unsafe-fixes` option."
+                )?;
             }
 
-            show_hint_statistics(total_diagnostics);
+            show_hint_statistics(&mut writer, total_diagnostics)?;
         } else if errors.is_empty() {
-            println!("All checks passed!");
+            writeln!(writer, "All checks passed!")?;
         }
 
+        writer.flush()?;
         Ok(())
     }
 }
 
-pub struct JsonEmitter;
+pub struct JsonEmitter<'a> {
+    /// Number of violations left by rule after applying fixes with
+    /// `--fix-only`.
+    pub unfixed: Option<&'a [UnfixedCount]>,
+}
 
-impl Emitter for JsonEmitter {
+impl Emitter for JsonEmitter<'_> {
     fn emit<W: Write>(
         &self,
         writer: &mut W,
@@ -201,8 +435,239 @@ impl Emitter for JsonEmitter {
             .collect();
 
         let output = JsonOutput {
-            diagnostics: diagnostics.to_vec(),
+            version: JSON_OUTPUT_VERSION,
+            diagnostics: diagnostics.iter().map(|d| JsonDiagnostic::new(d)).collect(),
             errors: json_errors,
+            unfixed: self.unfixed,
+        };
+
+        serde_json::to_writer_pretty(&mut writer, &output)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+pub struct SarifEmitter {
+    /// Whether rule ids are prefixed with the `jarl` namespace. In this case,
+    /// the diagnostics have already been renamed.
+    pub namespaced_rule_ids: bool,
+}
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+#[derive(Debug, Serialize)]
+struct SarifOutput {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+    invocations: Vec<SarifInvocation>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    help_uri: String,
+    properties: SarifRuleProperties,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRuleProperties {
+    categories: Vec<&'static str>,
+    fix: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    byte_offset: usize,
+    byte_length: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifInvocation {
+    execution_successful: bool,
+    tool_execution_notifications: Vec<SarifNotification>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifNotification {
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+}
+
+/// SARIF expects URIs, so we always use forward slashes.
+fn sarif_uri(path: &std::path::Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+impl Emitter for SarifEmitter {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        let rules: Vec<SarifRule> = Rule::all()
+            .iter()
+            .map(|rule| SarifRule {
+                id: rule_id(rule.name(), self.namespaced_rule_ids),
+                help_uri: format!("https://jarl.etiennebacher.com/rules/{}", rule.name()),
+                properties: SarifRuleProperties {
+                    categories: rule.categories().iter().map(|c| c.as_str()).collect(),
+                    fix: match rule.fix_status() {
+                        FixStatus::Safe => "safe",
+                        FixStatus::Unsafe => "unsafe",
+                        FixStatus::None => "none",
+                    },
+                },
+            })
+            .collect();
+
+        let results: Vec<SarifResult> = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let (row, col) = match diagnostic.location {
+                    Some(loc) => (loc.row(), loc.column() + 1), // SARIF columns are 1-based
+                    None => {
+                        unreachable!("Row/col locations must have been parsed successfully before.")
+                    }
+                };
+
+                let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                    format!("{} {}", diagnostic.message.body, suggestion)
+                } else {
+                    diagnostic.message.body.clone()
+                };
+
+                let start: usize = diagnostic.range.start().into();
+                let end: usize = diagnostic.range.end().into();
+
+                SarifResult {
+                    rule_id: diagnostic.message.name.clone(),
+                    rule_index: Rule::all().iter().position(|r| {
+                        rule_id(r.name(), self.namespaced_rule_ids) == diagnostic.message.name
+                    }),
+                    level: match diagnostic.severity {
+                        Some(Severity::Error) => "error",
+                        Some(Severity::Info) => "note",
+                        Some(Severity::Warning) | None => "warning",
+                    },
+                    message: SarifMessage { text: message },
+                    locations: vec![SarifLocation {
+                        physical_location: SarifPhysicalLocation {
+                            artifact_location: SarifArtifactLocation {
+                                uri: sarif_uri(&diagnostic.filename),
+                            },
+                            region: Some(SarifRegion {
+                                start_line: row,
+                                start_column: col,
+                                byte_offset: start,
+                                byte_length: end - start,
+                            }),
+                        },
+                    }],
+                }
+            })
+            .collect();
+
+        let notifications: Vec<SarifNotification> = errors
+            .iter()
+            .map(|(path, err)| SarifNotification {
+                level: "error",
+                message: SarifMessage { text: format!("{:#}", err) },
+                locations: vec![SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location: SarifArtifactLocation {
+                            uri: sarif_uri(std::path::Path::new(path)),
+                        },
+                        region: None,
+                    },
+                }],
+            })
+            .collect();
+
+        let output = SarifOutput {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "jarl",
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: "https://jarl.etiennebacher.com",
+                        rules,
+                    },
+                },
+                results,
+                invocations: vec![SarifInvocation {
+                    execution_successful: errors.is_empty(),
+                    tool_execution_notifications: notifications,
+                }],
+            }],
         };
 
         serde_json::to_writer_pretty(&mut writer, &output)?;
@@ -218,7 +683,7 @@ impl Emitter for GithubEmitter {
         &self,
         writer: &mut W,
         diagnostics: &[&Diagnostic],
-        _errors: &[(String, anyhow::Error)],
+        errors: &[(String, anyhow::Error)],
     ) -> anyhow::Result<()> {
         let mut writer = BufWriter::new(writer);
         for diagnostic in diagnostics {
@@ -238,9 +703,14 @@ impl Emitter for GithubEmitter {
             // - one after the "::" marker: this is so that the workflow shows
             //   the location of diagnostics when we inspect the workflow itself,
             //   without the Github annotations.
+            let level = match diagnostic.severity {
+                Some(Severity::Error) => "error",
+                Some(Severity::Info) => "notice",
+                Some(Severity::Warning) | None => "warning",
+            };
             write!(
                 writer,
-                "::warning title=Jarl ({}),file={file},line={row},col={col}::{file}:{row}:{col} ",
+                "::{level} title=Jarl ({}),file={file},line={row},col={col}::{file}:{row}:{col} ",
                 diagnostic.message.name,
                 file = diagnostic.filename.to_string_lossy()
             )?;
@@ -253,12 +723,127 @@ impl Emitter for GithubEmitter {
             writeln!(writer, "[{}] {}", diagnostic.message.name, message)?;
         }
 
+        // Files that couldn't be checked, e.g. because of syntax errors, have
+        // no location so they are reported as errors on the first line.
+        for (path, err) in errors {
+            writeln!(
+                writer,
+                "::error title=Jarl,file={path},line=1,col=1::{path}:1:1 {:#}",
+                err
+            )?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+pub struct JunitEmitter;
+
+/// Escape the characters that have a special meaning in XML text and
+/// attributes.
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl Emitter for JunitEmitter {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // Each violation is a failing test case, and each file that couldn't
+        // be checked is a test case with an error.
+        let n_failures = diagnostics.len();
+        let n_errors = errors.len();
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<testsuites name="jarl" tests="{}" failures="{n_failures}" errors="{n_errors}">"#,
+            n_failures + n_errors
+        )?;
+
+        // `diagnostics` are sorted, so the violations of a file are next to
+        // each other.
+        for file_diagnostics in diagnostics.chunk_by(|a, b| a.filename == b.filename) {
+            let file = xml_escape(&file_diagnostics[0].filename.to_string_lossy());
+            let n_tests = file_diagnostics.len();
+            writeln!(
+                writer,
+                r#"  <testsuite name="{file}" tests="{n_tests}" failures="{n_tests}" errors="0">"#
+            )?;
+
+            for diagnostic in file_diagnostics {
+                let (row, col) = match diagnostic.location {
+                    Some(loc) => (loc.row(), loc.column() + 1),
+                    None => {
+                        unreachable!("Row/col locations must have been parsed successfully before.")
+                    }
+                };
+
+                let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                    format!("{} {}", diagnostic.message.body, suggestion)
+                } else {
+                    diagnostic.message.body.clone()
+                };
+                let message = xml_escape(&message);
+
+                writeln!(
+                    writer,
+                    r#"    <testcase name="{file}:{row}:{col}" classname="{}">"#,
+                    xml_escape(&diagnostic.message.name)
+                )?;
+                writeln!(
+                    writer,
+                    r#"      <failure message="{message}">{file}:{row}:{col} {message}</failure>"#
+                )?;
+                writeln!(writer, "    </testcase>")?;
+            }
+
+            writeln!(writer, "  </testsuite>")?;
+        }
+
+        for (path, err) in errors {
+            let file = xml_escape(path);
+            let message = xml_escape(&format!("{:#}", err));
+            writeln!(
+                writer,
+                r#"  <testsuite name="{file}" tests="1" failures="0" errors="1">"#
+            )?;
+            writeln!(writer, r#"    <testcase name="{file}" classname="jarl">"#)?;
+            writeln!(
+                writer,
+                r#"      <error message="{message}">{message}</error>"#
+            )?;
+            writeln!(writer, "    </testcase>")?;
+            writeln!(writer, "  </testsuite>")?;
+        }
+
+        writeln!(writer, "</testsuites>")?;
+
         writer.flush()?;
         Ok(())
     }
 }
 
-pub struct FullEmitter;
+pub struct FullEmitter {
+    /// Whether rule names are clickable links to their documentation.
+    pub hyperlinks: bool,
+}
 
 impl Emitter for FullEmitter {
     fn emit<W: Write>(
@@ -353,24 +938,30 @@ impl Emitter for FullEmitter {
                 .entry(&diagnostic.filename)
                 .or_insert_with(|| relativize_path(diagnostic.filename.clone()));
 
+            let level = match diagnostic.severity {
+                Some(Severity::Error) => Level::Error,
+                Some(Severity::Info) => Level::Info,
+                Some(Severity::Warning) | None => Level::Warning,
+            };
+
             // Build the message with snippet
             let snippet = Snippet::source(source)
                 .origin(file_path)
                 .fold(true)
                 .annotation(
-                    Level::Warning
+                    level
                         .span(start_offset..end_offset)
                         .label(&diagnostic.message.body),
                 );
 
             // Create the main message with clickable rule name
-            let title = if use_colors {
+            let title = if self.hyperlinks {
                 make_hyperlink(&diagnostic.message.name)
             } else {
                 diagnostic.message.name.clone()
             };
 
-            let mut message = Level::Warning.title(&title).snippet(snippet);
+            let mut message = level.title(&title).snippet(snippet);
 
             // Add suggestion as a footer message if present
             if let Some(suggestion_text) = &diagnostic.message.suggestion {
@@ -389,15 +980,13 @@ impl Emitter for FullEmitter {
             total_diagnostics += 1;
         }
 
-        writer.flush()?; // Ensure all diagnostics are written before summary
-
         // Finally, print the info about the number of errors found and how
         // many can be fixed.
         if total_diagnostics > 0 {
             if total_diagnostics > 1 {
-                println!("Found {total_diagnostics} errors.");
+                writeln!(writer, "Found {total_diagnostics} errors.")?;
             } else {
-                println!("Found 1 error.");
+                writeln!(writer, "Found 1 error.")?;
             }
 
             if n_diagnostic_with_fixes > 0 {
@@ -413,21 +1002,25 @@ impl Emitter for FullEmitter {
                         "{n_diagnostic_with_fixes} fixable with the `--fix` option ({unsafe_label} can be enabled with the `--unsafe-fixes` option)."
                     )
                 };
-                println!("{msg}");
+                writeln!(writer, "{msg}")?;
             } else if n_diagnostic_with_unsafe_fixes > 0 {
                 let label = if n_diagnostic_with_unsafe_fixes == 1 {
                     "1 fix is".to_string()
                 } else {
                     format!("{n_diagnostic_with_unsafe_fixes} fixes are")
                 };
-                println!("{label} available with the `--fix --unsafe-fixes` option.");
+                writeln!(
+                    writer,
+                    "{label} available with the `--fix --unsafe-fixes` option."
+                )?;
             }
 
-            show_hint_statistics(total_diagnostics);
+            show_hint_statistics(&mut writer, total_diagnostics)?;
         } else if errors.is_empty() {
-            println!("All checks passed!");
+            writeln!(writer, "All checks passed!")?;
         }
 
+        writer.flush()?;
         Ok(())
     }
 }
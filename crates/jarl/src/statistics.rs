@@ -1,50 +1,132 @@
 use colored::Colorize;
-use jarl_core::diagnostic::Diagnostic;
+use jarl_core::diagnostic::{Diagnostic, RULE_NAMESPACE};
+use jarl_core::rule_set::Rule;
+use serde::Serialize;
 use std::{collections::HashMap, path::PathBuf};
 
 use crate::status::ExitStatus;
 
+#[derive(Serialize)]
+struct StatisticsOutput<'a> {
+    statistics: Statistics<'a>,
+}
+
+#[derive(Serialize)]
+struct Statistics<'a> {
+    rules: Vec<RuleStatistics<'a>>,
+    total: TotalStatistics,
+}
+
+#[derive(Serialize)]
+struct RuleStatistics<'a> {
+    rule: &'a str,
+    count: usize,
+    /// Number of violations that can be fixed with `--fix`.
+    fixable: usize,
+    categories: Vec<&'static str>,
+}
+
+#[derive(Serialize)]
+struct TotalStatistics {
+    count: usize,
+    fixable: usize,
+}
+
+/// Count the violations and the fixable violations of each rule, sorted by
+/// decreasing number of violations.
+fn compute_statistics<'a>(diagnostics: &[&'a Diagnostic]) -> Statistics<'a> {
+    let mut counts: HashMap<&str, (usize, usize)> = HashMap::new();
+    for diagnostic in diagnostics {
+        let entry = counts.entry(&diagnostic.message.name).or_default();
+        entry.0 += 1;
+        if diagnostic.has_safe_fix() {
+            entry.1 += 1;
+        }
+    }
+
+    let mut rules: Vec<RuleStatistics> = counts
+        .into_iter()
+        .map(|(rule, (count, fixable))| {
+            // Rule names may be prefixed with `--namespaced-rule-ids`.
+            let name = rule
+                .strip_prefix(RULE_NAMESPACE)
+                .and_then(|name| name.strip_prefix(':'))
+                .unwrap_or(rule);
+            let categories = Rule::from_name(name)
+                .map(|rule| rule.categories().iter().map(|c| c.as_str()).collect())
+                .unwrap_or_default();
+            RuleStatistics { rule, count, fixable, categories }
+        })
+        .collect();
+    rules.sort_by(|a, b| b.count.cmp(&a.count).then(a.rule.cmp(b.rule)));
+
+    let total = TotalStatistics {
+        count: rules.iter().map(|rule| rule.count).sum(),
+        fixable: rules.iter().map(|rule| rule.fixable).sum(),
+    };
+
+    Statistics { rules, total }
+}
+
 pub fn print_statistics(
     diagnostics: &[&Diagnostic],
     parent_config_path: Option<PathBuf>,
+    json: bool,
 ) -> anyhow::Result<ExitStatus> {
+    let statistics = compute_statistics(diagnostics);
+    let status = if diagnostics.is_empty() {
+        ExitStatus::Success
+    } else {
+        ExitStatus::Failure
+    };
+
+    if json {
+        let output = StatisticsOutput { statistics };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(status);
+    }
+
     if diagnostics.is_empty() {
         println!("All checks passed!");
-        return Ok(ExitStatus::Success);
+        return Ok(status);
     }
 
-    // Hashmap with rule name as key, and (number of occurrences, has_fix) as
-    // value.
-    let mut hm: HashMap<&String, (usize, bool)> = HashMap::new();
-
-    for diagnostic in diagnostics {
-        let rule_name = &diagnostic.message.name;
-        hm.entry(rule_name).or_default().0 += 1;
-        if diagnostic.has_safe_fix() && !hm.entry(rule_name).or_default().1 {
-            hm.entry(rule_name).or_default().1 = true;
-        }
-    }
+    let width = statistics
+        .rules
+        .iter()
+        .map(|rule| rule.rule.len())
+        .chain(["Rule".len(), "Total".len()])
+        .max()
+        .unwrap_or_default();
 
-    let mut sorted: Vec<_> = hm.iter().collect();
-    sorted.sort_by_key(|a| a.1.0);
-    sorted.reverse();
-
-    for (key, value) in sorted {
-        let star = if value.1 { "*" } else { " " };
-        println!(
-            "{:>5} [{}] {}",
-            value.0.to_string().bold(),
-            star,
-            key.bold().red()
+    println!(
+        "{:>5}  {:>7}  {:<width$}  Category",
+        "Count", "Fixable", "Rule"
+    );
+    for rule in &statistics.rules {
+        // Pad before coloring, since the color codes count in the width.
+        let line = format!(
+            "{}  {:>7}  {}  {}",
+            format!("{:>5}", rule.count).bold(),
+            rule.fixable,
+            format!("{:<width$}", rule.rule).bold().red(),
+            rule.categories.join(", ")
         );
+        println!("{}", line.trim_end());
     }
+    println!(
+        "{}  {:>7}  {}",
+        format!("{:>5}", statistics.total.count).bold(),
+        statistics.total.fixable,
+        "Total".bold()
+    );
 
-    println!("\nRules with `[*]` have an automatic fix.");
+    println!("\nFixable violations can be fixed with the `--fix` option.");
 
     // Inform the user if the config file used comes from a parent directory.
     if let Some(config_path) = parent_config_path {
         println!("\nUsed '{}'", config_path.display());
     }
 
-    Ok(ExitStatus::Failure)
+    Ok(status)
 }
@@ -6,6 +6,8 @@ pub enum ExitStatus {
     Success,
     /// Successful but there were errors.
     Failure,
+    /// Stopped before checking all files because the time budget was exceeded.
+    TimeBudgetExceeded,
     /// Failed.
     Error,
 }
@@ -15,6 +17,7 @@ impl From<ExitStatus> for ExitCode {
         match status {
             ExitStatus::Success => ExitCode::from(0),
             ExitStatus::Failure => ExitCode::from(1),
+            ExitStatus::TimeBudgetExceeded => ExitCode::from(2),
             ExitStatus::Error => ExitCode::from(u8::MAX),
         }
     }
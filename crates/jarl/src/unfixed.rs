@@ -0,0 +1,55 @@
+use colored::Colorize;
+use jarl_core::diagnostic::Diagnostic;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Number of violations of a rule that remain after applying fixes.
+#[derive(Debug, Serialize)]
+pub struct UnfixedCount {
+    pub rule: String,
+    pub count: usize,
+}
+
+/// Count the violations left in the fixed files, sorted by decreasing number
+/// of violations.
+pub fn count_unfixed(results: &[(String, anyhow::Result<Vec<Diagnostic>>)]) -> Vec<UnfixedCount> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for diagnostic in results
+        .iter()
+        .filter_map(|(_path, result)| result.as_ref().ok())
+        .flatten()
+    {
+        *counts.entry(&diagnostic.message.name).or_default() += 1;
+    }
+
+    let mut unfixed: Vec<UnfixedCount> = counts
+        .into_iter()
+        .map(|(rule, count)| UnfixedCount { rule: rule.to_string(), count })
+        .collect();
+    unfixed.sort_by(|a, b| b.count.cmp(&a.count).then(a.rule.cmp(&b.rule)));
+    unfixed
+}
+
+/// Print the number of violations that couldn't be fixed, either because
+/// their rule has no fix or because the fix was skipped (e.g. it would remove
+/// comments). This is written to stderr so that it doesn't corrupt structured
+/// output.
+pub fn print_unfixed(unfixed: &[UnfixedCount]) {
+    if unfixed.is_empty() {
+        return;
+    }
+
+    let total: usize = unfixed.iter().map(|u| u.count).sum();
+    let violations = if total == 1 {
+        "violation"
+    } else {
+        "violations"
+    };
+    eprintln!(
+        "\n{}: {total} {violations} could not be fixed automatically:",
+        "Note".bold()
+    );
+    for UnfixedCount { rule, count } in unfixed {
+        eprintln!("  - {rule}: {count}");
+    }
+}
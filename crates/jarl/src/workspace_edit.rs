@@ -0,0 +1,21 @@
+use jarl_core::diagnostic::Diagnostic;
+use std::io::Write;
+
+use crate::status::ExitStatus;
+
+/// Print the safe fixes of all violations as a single LSP `WorkspaceEdit` in
+/// JSON, so that they can be applied by tools that already know how to apply
+/// edits from a language server. This doesn't modify any file.
+pub fn print_workspace_edit(diagnostics: &[&Diagnostic]) -> anyhow::Result<ExitStatus> {
+    let workspace_edit = jarl_lsp::lint::diagnostics_to_workspace_edit(diagnostics)?;
+
+    let mut stdout = std::io::stdout();
+    serde_json::to_writer_pretty(&mut stdout, &workspace_edit)?;
+    stdout.flush()?;
+
+    if diagnostics.is_empty() {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
@@ -0,0 +1,41 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_atomic_fix() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("a.R"), "any(is.na(x))\n")?;
+    std::fs::write(directory.join("b.R"), "any(is.na(y))\nx == NA\n")?;
+    std::fs::write(directory.join("c.R"), "x <- 1\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--atomic")
+            .arg("--allow-no-vcs")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    assert_eq!(
+        std::fs::read_to_string(directory.join("a.R"))?,
+        "anyNA(x)\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(directory.join("b.R"))?,
+        "anyNA(y)\nis.na(x)\n"
+    );
+    assert_eq!(std::fs::read_to_string(directory.join("c.R"))?, "x <- 1\n");
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_baseline() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    std::fs::write(directory.join(test_path), "any(is.na(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--baseline")
+            .arg("baseline.json")
+            .arg("--write-baseline")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    let baseline: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(directory.join("baseline.json"))?)?;
+    let entries = baseline["diagnostics"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["file"], "test.R");
+    assert_eq!(entries[0]["rule"], "any_is_na");
+    assert_eq!(entries[0]["line"], 1);
+
+    // Existing violations are not reported anymore.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--baseline")
+            .arg("baseline.json")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // The existing violation moved by two lines and is still matched, but the
+    // new one is reported even though it has the same rule and message.
+    std::fs::write(
+        directory.join(test_path),
+        "x <- 1\ny <- 2\nany(is.na(x))\nany(is.na(y))\n",
+    )?;
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--baseline")
+            .arg("baseline.json")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_baseline_missing_file() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "any(is.na(x))\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--baseline")
+        .arg("baseline.json")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(
+        output
+            .stderr
+            .contains("Failed to read baseline: baseline.json")
+    );
+
+    Ok(())
+}
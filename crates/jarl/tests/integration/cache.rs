@@ -0,0 +1,166 @@
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+fn check(directory: &Path, args: &[&str]) -> String {
+    Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg("concise")
+        .args(args)
+        .run()
+        .stdout
+}
+
+/// Change the messages stored in the cache, to know whether they are used.
+fn edit_cache(directory: &Path) -> anyhow::Result<()> {
+    for entry in std::fs::read_dir(directory.join(".jarl_cache"))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            let contents = std::fs::read_to_string(&path)?;
+            std::fs::write(&path, contents.replace("is inefficient", "is cached"))?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_cache_reuses_diagnostics() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("a.R"), "any(is.na(x))\n")?;
+    std::fs::write(directory.join("b.R"), "any(duplicated(x))\n")?;
+
+    let cold = check(directory, &[]);
+    assert!(directory.join(".jarl_cache").is_dir());
+    assert_eq!(
+        std::fs::read_to_string(directory.join(".jarl_cache/.gitignore"))?,
+        "*\n"
+    );
+
+    // The output is the same when the diagnostics come from the cache.
+    let warm = check(directory, &[]);
+    assert_eq!(cold, warm);
+
+    edit_cache(directory)?;
+    let warm = check(directory, &[]);
+    assert!(warm.contains("`any(is.na(...))` is cached."));
+    assert!(warm.contains("`any(duplicated(...))` is cached."));
+
+    Ok(())
+}
+
+#[test]
+fn test_cache_invalidated_by_content() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("a.R"), "any(is.na(x))\n")?;
+    std::fs::write(directory.join("b.R"), "any(duplicated(x))\n")?;
+
+    check(directory, &[]);
+    edit_cache(directory)?;
+
+    // Only the file that changed is checked again.
+    std::fs::write(directory.join("a.R"), "\nany(is.na(x))\n")?;
+    let output = check(directory, &[]);
+    assert!(output.contains("a.R [2:1] any_is_na `any(is.na(...))` is inefficient."));
+    assert!(output.contains("`any(duplicated(...))` is cached."));
+
+    Ok(())
+}
+
+#[test]
+fn test_cache_invalidated_by_config() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("a.R"), "any(is.na(x))\nx = 1\n")?;
+
+    check(directory, &[]);
+    edit_cache(directory)?;
+
+    let output = check(directory, &["--select", "any_is_na"]);
+    assert!(output.contains("`any(is.na(...))` is inefficient."));
+
+    // The preferred assignment operator is set in `jarl.toml`.
+    let output = check(directory, &["--extend-select", "assignment"]);
+    assert!(output.contains("a.R [2:1] assignment"));
+    edit_cache(directory)?;
+    std::fs::write(directory.join("jarl.toml"), "[lint]\nassignment = \"=\"\n")?;
+    let output = check(directory, &["--extend-select", "assignment"]);
+    assert!(output.contains("`any(is.na(...))` is inefficient."));
+    assert!(!output.contains("assignment"));
+
+    Ok(())
+}
+
+#[test]
+fn test_no_cache() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("a.R"), "any(is.na(x))\n")?;
+
+    check(directory, &["--no-cache"]);
+    assert!(!directory.join(".jarl_cache").exists());
+
+    check(directory, &[]);
+    edit_cache(directory)?;
+    let output = check(directory, &["--no-cache"]);
+    assert!(output.contains("`any(is.na(...))` is inefficient."));
+
+    Ok(())
+}
+
+#[test]
+fn test_cache_not_used_with_fix() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("a.R"), "any(is.na(x))\n")?;
+
+    check(directory, &["--fix", "--allow-no-vcs"]);
+    assert!(!directory.join(".jarl_cache").exists());
+    assert_eq!(
+        std::fs::read_to_string(directory.join("a.R"))?,
+        "anyNA(x)\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_clear_cache() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("a.R"), "any(is.na(x))\n")?;
+    check(directory, &[]);
+    assert!(directory.join(".jarl_cache").is_dir());
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("clear-cache")
+            .run()
+            .normalize_os_executable_name()
+    );
+    assert!(!directory.join(".jarl_cache").exists());
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("clear-cache")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+fn check_with_column_base(
+    directory: &std::path::Path,
+    output_format: &str,
+    column_base: Option<&str>,
+) -> String {
+    let mut command = Command::new(binary_path());
+    command
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg(output_format);
+    if let Some(column_base) = column_base {
+        command.arg("--column-base").arg(column_base);
+    }
+    command.run().stdout
+}
+
+fn json_column(output: &str) -> anyhow::Result<u64> {
+    let output: serde_json::Value = serde_json::from_str(output)?;
+    Ok(output["diagnostics"][0]["location"]["column"]
+        .as_u64()
+        .unwrap())
+}
+
+#[test]
+fn test_column_base_concise() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // `any(is.na(x))` starts at the 6th character of the second line.
+    std::fs::write(directory.join("test.R"), "x <- 1\ny <- any(is.na(x))\n")?;
+
+    let default = check_with_column_base(directory, "concise", None);
+    let one_based = check_with_column_base(directory, "concise", Some("1"));
+    let zero_based = check_with_column_base(directory, "concise", Some("0"));
+
+    assert!(default.contains("test.R [2:6] any_is_na"));
+    assert!(one_based.contains("test.R [2:6] any_is_na"));
+    assert!(zero_based.contains("test.R [2:5] any_is_na"));
+
+    Ok(())
+}
+
+#[test]
+fn test_column_base_json() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "x <- 1\ny <- any(is.na(x))\n")?;
+
+    let default = check_with_column_base(directory, "json", None);
+    let one_based = check_with_column_base(directory, "json", Some("1"));
+    let zero_based = check_with_column_base(directory, "json", Some("0"));
+
+    assert_eq!(json_column(&default)?, 5);
+    assert_eq!(json_column(&one_based)?, 6);
+    assert_eq!(json_column(&zero_based)?, 5);
+
+    Ok(())
+}
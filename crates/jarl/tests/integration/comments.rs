@@ -321,3 +321,31 @@ any(duplicated(x))
 
     Ok(())
 }
+
+#[test]
+fn test_nolint_unknown_rule() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    std::fs::write(
+        directory.join(test_path),
+        "
+# nolint: assignmnet
+any(is.na(x))
+",
+    )?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_compact_files() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("a.R"),
+        "any(is.na(x))\nany(is.na(y))\nx == NA\n",
+    )?;
+    std::fs::write(directory.join("b.R"), "any(is.na(x))\n")?;
+    // Files without violations are not printed.
+    std::fs::write(directory.join("c.R"), "x <- 1\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .arg("--compact-files")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_compact_files_requires_concise() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("a.R"), "any(is.na(x))\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--compact-files")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(
+        output
+            .stderr
+            .contains("`--compact-files` can only be used with `--output-format concise`.")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+fn check_with_min_confidence(
+    directory: &std::path::Path,
+    output_format: &str,
+    min_confidence: Option<&str>,
+) -> String {
+    let mut command = Command::new(binary_path());
+    command
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg(output_format)
+        .arg("--extend-select")
+        .arg("absolute_path");
+    if let Some(min_confidence) = min_confidence {
+        command.arg("--min-confidence").arg(min_confidence);
+    }
+    command.run().stdout
+}
+
+#[test]
+fn test_min_confidence_drops_heuristic_rules() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // `absolute_path` is a heuristic rule, `any_is_na` isn't. `absolute_path`
+    // is disabled by default, so it is selected in `check_with_min_confidence()`.
+    std::fs::write(
+        directory.join("test.R"),
+        "x <- read.csv(\"/home/user/data.csv\")\nany(is.na(x))\n",
+    )?;
+
+    let default = check_with_min_confidence(directory, "concise", None);
+    assert!(default.contains("absolute_path"));
+    assert!(default.contains("(heuristic)"));
+    assert!(default.contains("any_is_na"));
+
+    let heuristic = check_with_min_confidence(directory, "concise", Some("heuristic"));
+    assert!(heuristic.contains("absolute_path"));
+    assert!(heuristic.contains("any_is_na"));
+
+    let high = check_with_min_confidence(directory, "concise", Some("high"));
+    assert!(!high.contains("absolute_path"));
+    assert!(!high.contains("(heuristic)"));
+    assert!(high.contains("any_is_na"));
+
+    Ok(())
+}
+
+#[test]
+fn test_confidence_in_json() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("test.R"),
+        "x <- read.csv(\"/home/user/data.csv\")\nany(is.na(x))\n",
+    )?;
+
+    let output = check_with_min_confidence(directory, "json", None);
+    let output: serde_json::Value = serde_json::from_str(&output)?;
+    let confidences: Vec<(&str, &str)> = output["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|diagnostic| {
+            (
+                diagnostic["message"]["name"].as_str().unwrap(),
+                diagnostic["confidence"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        confidences,
+        vec![("absolute_path", "heuristic"), ("any_is_na", "high")]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_min_confidence_invalid_value() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "any(is.na(x))\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--min-confidence")
+        .arg("low")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(
+        output
+            .stderr
+            .contains("Invalid value for `--min-confidence`: low")
+    );
+
+    Ok(())
+}
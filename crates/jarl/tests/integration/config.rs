@@ -0,0 +1,85 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_config() -> anyhow::Result<()> {
+    let root_dir = TempDir::new()?;
+    let root_path = root_dir.path();
+
+    let project = root_path.join("project");
+    let configs = root_path.join("configs");
+    std::fs::create_dir_all(&project)?;
+    std::fs::create_dir_all(&configs)?;
+
+    std::fs::write(
+        project.join("test.R"),
+        "any(is.na(x))\nany(duplicated(x))\n",
+    )?;
+    std::fs::write(
+        project.join("jarl.toml"),
+        r#"
+[lint]
+select = ["any_duplicated"]
+"#,
+    )?;
+    std::fs::write(
+        configs.join("strict.toml"),
+        r#"
+[lint]
+select = ["any_is_na"]
+"#,
+    )?;
+
+    // By default, the `jarl.toml` of the project is used.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(&project)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // `--config` replaces it, even if it is outside of the project.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(&project)
+            .arg("check")
+            .arg(".")
+            .arg("--config")
+            .arg("../configs/strict.toml")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_not_found() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "any(is.na(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--config")
+            .arg("missing.toml")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_coverage_report() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("a.R"), "any(is.na(x))\n")?;
+    std::fs::write(directory.join("b.R"), "x <- 1\n")?;
+    // Generated files are skipped entirely.
+    std::fs::write(
+        directory.join("generated.R"),
+        "# Generated by roxygen2: do not edit by hand\nany(is.na(x))\n",
+    )?;
+
+    // `grepv` requires R >= 4.5.0 and the minimum R version is unknown, so it
+    // is not run.
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--select")
+        .arg("any_is_na,any_duplicated,grepv")
+        .arg("--ignore")
+        .arg("any_duplicated")
+        .arg("--coverage-report")
+        .arg("coverage.json")
+        .run();
+    assert_eq!(output.status.code(), Some(1));
+
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(directory.join("coverage.json"))?)?;
+    assert_eq!(
+        report,
+        serde_json::json!({
+            "files": [
+                { "file": "a.R", "rules": ["any_is_na"] },
+                { "file": "b.R", "rules": ["any_is_na"] },
+                { "file": "generated.R", "rules": [] }
+            ]
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_coverage_report_parsing_error() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("a.R"), "any(is.na(x))\n")?;
+    std::fs::write(directory.join("b.R"), "f <- function(\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--select")
+        .arg("any_is_na")
+        .arg("--coverage-report")
+        .arg("coverage.json")
+        .run();
+    assert_eq!(output.status.code(), Some(255));
+
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(directory.join("coverage.json"))?)?;
+    let files = report["files"].as_array().unwrap();
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0]["rules"], serde_json::json!(["any_is_na"]));
+    assert_eq!(files[1]["file"], "b.R");
+    assert_eq!(files[1]["rules"], serde_json::json!([]));
+    assert!(files[1]["error"].as_str().unwrap().contains("b.R"));
+
+    Ok(())
+}
+
+#[test]
+fn test_coverage_report_stdin() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg("-")
+            .arg("--coverage-report")
+            .arg("coverage.json")
+            .run_with_stdin("any(is.na(x))")
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
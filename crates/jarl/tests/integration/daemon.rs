@@ -0,0 +1,82 @@
+#![cfg(unix)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+use crate::helpers::binary_path;
+
+#[test]
+fn test_daemon_sequential_requests() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+    let socket = directory.join("jarl.sock");
+
+    let mut child = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("daemon")
+        .arg("--socket")
+        .arg(&socket)
+        .arg("--log-level")
+        .arg("debug")
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Wait for the daemon to listen on the socket.
+    let start = Instant::now();
+    let stream = loop {
+        match UnixStream::connect(&socket) {
+            Ok(stream) => break stream,
+            Err(_) if start.elapsed() < Duration::from_secs(10) => {
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            Err(err) => {
+                child.kill()?;
+                return Err(err.into());
+            }
+        }
+    };
+
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+    let mut request = |request: &str| -> anyhow::Result<serde_json::Value> {
+        writeln!(writer, "{request}")?;
+        let mut response = String::new();
+        reader.read_line(&mut response)?;
+        Ok(serde_json::from_str(&response)?)
+    };
+
+    let first = request(r#"{"source": "any(is.na(x))", "path": "test.R"}"#)?;
+    let second = request(r#"{"source": "any(duplicated(x))\nx <- 1", "path": "test2.R"}"#)?;
+    let third = request(r#"{"source": "any(", "path": "test3.R"}"#)?;
+
+    child.kill()?;
+    let output = child.wait_with_output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+
+    let diagnostics = first["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["message"]["name"], "any_is_na");
+    assert_eq!(diagnostics[0]["filename"], "test.R");
+
+    let diagnostics = second["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["message"]["name"], "any_duplicated");
+    assert_eq!(diagnostics[0]["filename"], "test2.R");
+
+    assert!(third["diagnostics"].as_array().unwrap().is_empty());
+    assert!(
+        third["error"]
+            .as_str()
+            .unwrap()
+            .contains("Failed to parse test3.R due to syntax errors.")
+    );
+
+    // The configuration is built once and reused for the next requests.
+    assert_eq!(stderr.matches("Building the configuration").count(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_diff() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "any(is.na(x))\nx <- 1\nif (all.equal(a, b)) 1\n";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--diff")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--diff")
+            .arg("--unsafe-fixes")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // Only violations of selected rules are fixed.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--diff")
+            .arg("--unsafe-fixes")
+            .arg("--select")
+            .arg("all_equal")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // The file is left untouched.
+    let contents = std::fs::read_to_string(directory.join(test_path))?;
+    assert_eq!(contents, test_contents);
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_no_fixes() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "x <- 1\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--diff")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_conflicts_with_fix() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "any(is.na(x))\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--diff")
+        .arg("--fix")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(output.stderr.contains("cannot be used with"));
+
+    // The file is left untouched.
+    let contents = std::fs::read_to_string(directory.join("test.R"))?;
+    assert_eq!(contents, "any(is.na(x))\n");
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_dump_ast() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "x <- 1";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("dump-ast")
+        .arg(test_path)
+        .run();
+    assert!(output.status.success());
+
+    for kind in [
+        "R_ROOT",
+        "R_BINARY_EXPRESSION",
+        "R_IDENTIFIER",
+        "ASSIGN",
+        "R_DOUBLE_VALUE",
+    ] {
+        assert!(
+            output.stdout.contains(kind),
+            "`{kind}` not found in:\n{}",
+            output.stdout
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_dump_ast_with_syntax_error() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "x <- ";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    // The tree is printed even if the code can't be parsed entirely.
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("dump-ast")
+        .arg(test_path)
+        .run();
+    assert!(output.status.success());
+    assert!(output.stdout.contains("R_ROOT"));
+
+    Ok(())
+}
+
+#[test]
+fn test_dump_ast_is_hidden() -> anyhow::Result<()> {
+    let output = Command::new(binary_path()).arg("--help").run();
+    assert!(!output.stdout.contains("dump-ast"));
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use std::process::Command;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_explain() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .arg("explain")
+            .arg("any_is_na")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_explain_json() -> anyhow::Result<()> {
+    let output = Command::new(binary_path())
+        .arg("explain")
+        .arg("grepv")
+        .arg("--output-format")
+        .arg("json")
+        .run();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    assert_eq!(json["name"], "grepv");
+    assert_eq!(json["categories"], serde_json::json!(["READ"]));
+    assert_eq!(json["fix"], "safe");
+    assert_eq!(json["enabled_by_default"], true);
+    assert_eq!(json["minimum_r_version"], "4.5.0");
+    assert!(
+        json["documentation"]
+            .as_str()
+            .unwrap()
+            .starts_with("## What it does")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_explain_unknown_rule() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .arg("explain")
+            .arg("any_isna")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .arg("explain")
+            .arg("foo")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_fail_on() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "x == NA\nany(is.na(x))\n")?;
+
+    // `equals_na` is in the `CORR` category, so the check fails.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .arg("--fail-on")
+            .arg("CORR")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // Violations of rules in other categories are reported but don't make the
+    // check fail.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .arg("--fail-on")
+            .arg("SUSP,READ")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_fail_on_invalid_category() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "x == NA\nany(is.na(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .arg("--fail-on")
+            .arg("foo")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_fail_on_with_severity() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint.severity]
+equals_na = "warning"
+"#,
+    )?;
+    std::fs::write(directory.join("test.R"), "x == NA\n")?;
+
+    // `equals_na` is in the `CORR` category but its severity is "warning", so
+    // the check doesn't fail, as without `--fail-on`.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .arg("--fail-on")
+            .arg("CORR")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .arg("--fail-on")
+            .arg("CORR")
+            .arg("--warnings-as-errors")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
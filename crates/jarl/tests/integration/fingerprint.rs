@@ -0,0 +1,64 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+fn fingerprint(directory: &std::path::Path) -> String {
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--print-fingerprint")
+        .run();
+    output.stdout.trim().to_string()
+}
+
+#[test]
+fn test_fingerprint() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    std::fs::write(directory.join(test_path), "any(is.na(x))\nx = 1\n")?;
+
+    let first = fingerprint(directory);
+    assert_eq!(first.len(), 16);
+    assert!(first.chars().all(|c| c.is_ascii_hexdigit()));
+
+    // Same violations, same fingerprint.
+    let second = fingerprint(directory);
+    assert_eq!(first, second);
+
+    // A violation was fixed.
+    std::fs::write(directory.join(test_path), "any(is.na(x))\nx <- 1\n")?;
+    let third = fingerprint(directory);
+    assert_ne!(first, third);
+
+    // Same rule but the violation moved.
+    std::fs::write(directory.join(test_path), "\nany(is.na(x))\nx <- 1\n")?;
+    let fourth = fingerprint(directory);
+    assert_ne!(third, fourth);
+
+    Ok(())
+}
+
+#[test]
+fn test_fingerprint_no_violations() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "x <- 1\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--print-fingerprint")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
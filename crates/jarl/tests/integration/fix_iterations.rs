@@ -0,0 +1,52 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_fix_iterations() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // Fixing `equals_na` gives `any(is.na(x))`, which is then fixed by
+    // `any_is_na` in the second pass.
+    let test_path = "test.R";
+    let test_contents = "any(x == NA)";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name()
+    );
+    let contents = std::fs::read_to_string(directory.join(test_path))?;
+    assert_eq!(contents, "anyNA(x)");
+
+    // With a single pass, the violation of `any_is_na` is left.
+    std::fs::write(directory.join(test_path), test_contents)?;
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--fix-iterations")
+            .arg("1")
+            .arg("--allow-no-vcs")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+    let contents = std::fs::read_to_string(directory.join(test_path))?;
+    assert_eq!(contents, "any(is.na(x))");
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_fix_only_reports_unfixed_json() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // File with 4 lints:
+    // - any_is_na (has fix)
+    // - duplicated_arguments, twice (has no fix)
+    // - for_loop_index (has no fix)
+    let test_path = "test.R";
+    let test_contents =
+        "any(is.na(x))\nlist(x = 1, x = 2)\nlist(y = 1, y = 2)\nfor (x in x) print(x)";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--fix-only")
+            .arg("--allow-no-vcs")
+            .arg("--output-format")
+            .arg("json")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_fix_only_all_fixed() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    std::fs::write(directory.join(test_path), "any(is.na(x))")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--fix-only")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    let contents = std::fs::read_to_string(directory.join(test_path))?;
+    assert_eq!(contents, "anyNA(x)");
+
+    Ok(())
+}
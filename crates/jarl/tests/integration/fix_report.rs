@@ -0,0 +1,60 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_fix_report_json() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // File with 3 lints:
+    // - any_is_na (has fix)
+    // - class_equals (has unsafe fix)
+    // - duplicated_arguments (has no fix)
+    let test_path = "test.R";
+    let test_contents = "any(is.na(x))\nclass(x) == 'foo'\nlist(x = 1, x = 2)";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--fix-report")
+            .arg("json")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // The file is left untouched.
+    let contents = std::fs::read_to_string(directory.join(test_path))?;
+    assert_eq!(contents, test_contents);
+
+    Ok(())
+}
+
+#[test]
+fn test_fix_report_no_violations() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "anyNA(x)";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--fix-report")
+            .arg("json")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
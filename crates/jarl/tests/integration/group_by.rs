@@ -0,0 +1,79 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_group_by_category() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // `any_is_na` is a PERF rule, `equals_na` is a CORR rule, and
+    // `true_false_symbol` is a READ rule.
+    std::fs::write(
+        directory.join("test.R"),
+        "any(is.na(x))\nx == NA\ny <- T\nz == NA\n",
+    )?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg("concise")
+        .arg("--group-by")
+        .arg("category")
+        .run();
+    let stdout = output.stdout;
+
+    let corr = stdout.find("Correctness (CORR): 2 errors").unwrap();
+    let perf = stdout.find("Performance (PERF): 1 error").unwrap();
+    let read = stdout.find("Readability (READ): 1 error").unwrap();
+
+    // Categories are shown in order of importance.
+    assert!(corr < perf);
+    assert!(perf < read);
+
+    // Categories without violations don't have a header.
+    assert!(!stdout.contains("Suspicious (SUSP)"));
+
+    // Violations are shown under the header of their category.
+    let equals_na: Vec<usize> = stdout.match_indices("equals_na").map(|(i, _)| i).collect();
+    assert_eq!(equals_na.len(), 2);
+    assert!(equals_na.iter().all(|i| corr < *i && *i < perf));
+    let any_is_na = stdout.find("any_is_na").unwrap();
+    assert!(perf < any_is_na && any_is_na < read);
+    let true_false_symbol = stdout.find("true_false_symbol").unwrap();
+    assert!(read < true_false_symbol);
+
+    assert!(stdout.contains("Found 4 errors."));
+
+    Ok(())
+}
+
+#[test]
+fn test_group_by_requires_concise() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "x == NA\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--group-by")
+        .arg("category")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(
+        output
+            .stderr
+            .contains("`--group-by` can only be used with `--output-format concise`.")
+    );
+
+    Ok(())
+}
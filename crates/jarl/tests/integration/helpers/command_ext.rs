@@ -1,6 +1,8 @@
 use std::fmt::Display;
+use std::io::Write;
 use std::process::Command;
 use std::process::ExitStatus;
+use std::process::Stdio;
 
 pub trait CommandExt {
     /// Executes the command as a child process, waiting for it to finish and collecting all of its output.
@@ -11,6 +13,10 @@ pub trait CommandExt {
     ///
     /// Sets the `NO_COLOR` environment variable to disable colored output in tests
     fn run(&mut self) -> Output;
+
+    /// Like [CommandExt::run], but writes `stdin` to the standard input of the
+    /// child process
+    fn run_with_stdin(&mut self, stdin: &str) -> Output;
 }
 
 /// Like [std::process::Output], but augmented with `arguments` and a few extra methods
@@ -65,21 +71,48 @@ impl CommandExt for Command {
         // Augment `std::process::Output` with the arguments
         let output = self.output().unwrap();
 
-        // Go ahead and turn these into `String`
-        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
-        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        to_output(self, output)
+    }
+
+    fn run_with_stdin(&mut self, stdin: &str) -> Output {
+        // Set NO_COLOR environment variable to disable colored output in tests
+        self.env("NO_COLOR", "1");
+
+        let mut child = self
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
 
-        let arguments: Vec<String> = self
-            .get_args()
-            .map(|x| x.to_string_lossy().into_owned())
-            .collect();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(stdin.as_bytes())
+            .unwrap();
 
-        let arguments = arguments.join(" ");
+        let output = child.wait_with_output().unwrap();
 
-        Output { status: output.status, stdout, stderr, arguments }
+        to_output(self, output)
     }
 }
 
+fn to_output(command: &Command, output: std::process::Output) -> Output {
+    // Go ahead and turn these into `String`
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+
+    let arguments: Vec<String> = command
+        .get_args()
+        .map(|x| x.to_string_lossy().into_owned())
+        .collect();
+
+    let arguments = arguments.join(" ");
+
+    Output { status: output.status, stdout, stderr, arguments }
+}
+
 impl Display for Output {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // Normalize path separators for readable snapshots
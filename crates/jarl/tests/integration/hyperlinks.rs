@@ -0,0 +1,79 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+// Start of the OSC 8 escape sequence wrapping the rule name.
+const HYPERLINK: &str = "\x1b]8;;https://jarl.etiennebacher.com/rules/any_is_na\x1b\\";
+
+#[test]
+fn test_hyperlinks_always() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+    std::fs::write(directory.join("test.R"), "any(is.na(x))\n")?;
+
+    for output_format in ["concise", "full"] {
+        let output = Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg(output_format)
+            .arg("--hyperlinks")
+            .arg("always")
+            .run();
+        assert!(output.stdout.contains(HYPERLINK), "{}", output.stdout);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_hyperlinks_never() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+    std::fs::write(directory.join("test.R"), "any(is.na(x))\n")?;
+
+    for output_format in ["concise", "full"] {
+        let output = Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg(output_format)
+            .arg("--hyperlinks")
+            .arg("never")
+            .env_remove("NO_COLOR")
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(!stdout.contains("\x1b]8;;"), "{stdout}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_hyperlinks_auto_without_terminal() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+    std::fs::write(directory.join("test.R"), "any(is.na(x))\n")?;
+
+    // Colors are enabled but stdout is piped, so it is not a terminal.
+    for output_format in ["concise", "full"] {
+        let output = Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg(output_format)
+            .env_remove("NO_COLOR")
+            .output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("any_is_na"), "{stdout}");
+        assert!(!stdout.contains("\x1b]8;;"), "{stdout}");
+    }
+
+    Ok(())
+}
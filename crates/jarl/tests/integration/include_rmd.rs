@@ -0,0 +1,114 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+const RMD_CONTENTS: &str = "---
+title: Report
+---
+
+Text with any(is.na(y)).
+
+```{r}
+x <- 1
+any(is.na(x))
+```
+";
+
+const QMD_CONTENTS: &str = "```{python}
+any(x)
+```
+
+```{r}
+#| echo: false
+  any(is.na(x))
+```
+";
+
+#[test]
+fn test_include_rmd() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "any(is.na(x))\n")?;
+    std::fs::write(directory.join("report.Rmd"), RMD_CONTENTS)?;
+    std::fs::write(directory.join("doc.qmd"), QMD_CONTENTS)?;
+
+    // R Markdown and Quarto files are not checked by default.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // Only the code in R chunks is checked, and the locations are the ones in
+    // the original file.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--include-rmd")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // Files passed explicitly are always checked.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg("report.Rmd")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_include_rmd_fix() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("report.Rmd"), RMD_CONTENTS)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--include-rmd")
+            .arg("--fix")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    assert_eq!(
+        std::fs::read_to_string(directory.join("report.Rmd"))?,
+        "---
+title: Report
+---
+
+Text with any(is.na(y)).
+
+```{r}
+x <- 1
+anyNA(x)
+```
+"
+    );
+
+    Ok(())
+}
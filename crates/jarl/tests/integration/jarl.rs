@@ -366,6 +366,48 @@ fn test_safe_and_unsafe_lints() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_fix_applicability() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // - any_is_na has a fix that is always correct
+    // - class_equals has a fix that may be incorrect
+    let test_path = "test.R";
+    let test_contents = "any(is.na(x))\nclass(x) == 'foo'";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    // `--fix` only applies fixes that are always correct.
+    let _ = &mut Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--fix")
+        .arg("--allow-no-vcs")
+        .run()
+        .normalize_os_executable_name();
+
+    let fixed_contents = std::fs::read_to_string(directory.join(test_path))?;
+    insta::assert_snapshot!(fixed_contents);
+
+    // `--unsafe-fixes` also applies fixes that may be incorrect.
+    std::fs::write(directory.join(test_path), test_contents)?;
+    let _ = &mut Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--fix")
+        .arg("--unsafe-fixes")
+        .arg("--allow-no-vcs")
+        .run()
+        .normalize_os_executable_name();
+
+    let fixed_contents = std::fs::read_to_string(directory.join(test_path))?;
+    insta::assert_snapshot!(fixed_contents);
+
+    Ok(())
+}
+
 #[test]
 fn test_newline_character_in_string() -> anyhow::Result<()> {
     let directory = TempDir::new()?;
@@ -387,3 +429,74 @@ fn test_newline_character_in_string() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_several_paths_overlapping() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::create_dir(directory.join("a"))?;
+    std::fs::create_dir(directory.join("b"))?;
+    std::fs::write(directory.join("a/test.R"), "any(is.na(x))")?;
+    std::fs::write(directory.join("a/test2.R"), "any(duplicated(x))")?;
+    std::fs::write(directory.join("b/test.R"), "any(is.na(x))")?;
+
+    // "a/test.R" is also covered by "a", it must only be checked once.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg("b")
+            .arg("a/test.R")
+            .arg("a")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_symlinks_and_hardlinks_checked_once() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "any(is.na(x))")?;
+    std::fs::hard_link(directory.join("test.R"), directory.join("test_hardlink.R"))?;
+    std::os::unix::fs::symlink(directory.join("test.R"), directory.join("test_symlink.R"))?;
+
+    // The three paths point to the same file, it must only be checked once.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("test_symlink.R")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // The fix must only be applied once.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("test_symlink.R")
+            .arg("--fix")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name()
+    );
+    assert_eq!(
+        std::fs::read_to_string(directory.join("test.R"))?,
+        "anyNA(x)"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_jarlignore() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::create_dir(directory.join("sub"))?;
+    std::fs::write(directory.join("test.R"), "any(is.na(x))")?;
+    std::fs::write(directory.join("excluded.R"), "any(is.na(x))")?;
+    std::fs::write(directory.join("sub/kept.R"), "any(is.na(x))")?;
+    std::fs::write(directory.join("sub/nested.R"), "any(is.na(x))")?;
+
+    // Nested `.jarlignore` files are combined with the ones in the parent
+    // directories.
+    std::fs::write(directory.join(".jarlignore"), "excluded.R\n")?;
+    std::fs::write(directory.join("sub/.jarlignore"), "nested.R\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    std::fs::remove_file(directory.join(".jarlignore"))?;
+    std::fs::remove_file(directory.join("sub/.jarlignore"))?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_jarlignore_directory() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::create_dir(directory.join("scratch"))?;
+    std::fs::write(directory.join("test.R"), "any(is.na(x))")?;
+    std::fs::write(directory.join("scratch/test.R"), "any(is.na(x))")?;
+    std::fs::write(directory.join(".jarlignore"), "scratch/\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
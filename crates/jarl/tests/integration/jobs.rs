@@ -0,0 +1,66 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_jobs_same_output() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    for i in 0..50 {
+        std::fs::write(
+            directory.join(format!("test{i}.R")),
+            "any(is.na(x))\nany(duplicated(x))\n",
+        )?;
+    }
+
+    let run = |jobs: Option<&str>| {
+        let mut command = Command::new(binary_path());
+        command
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise");
+        if let Some(jobs) = jobs {
+            command.arg("--jobs").arg(jobs);
+        }
+        command.run()
+    };
+
+    // The violations are sorted, so the output doesn't depend on the order in
+    // which files are checked.
+    let default = run(None);
+    let one = run(Some("1"));
+    let four = run(Some("4"));
+
+    assert!(default.stdout.contains("Found 100 errors."));
+    assert_eq!(default.stdout, one.stdout);
+    assert_eq!(default.stdout, four.stdout);
+    assert_eq!(one.status.code(), Some(1));
+
+    Ok(())
+}
+
+#[test]
+fn test_jobs_zero() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "any(is.na(x))")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--jobs")
+        .arg("0")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(output.stderr.contains("invalid value '0' for '--jobs <N>'"));
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use std::process::Command;
+
+use jarl_core::rule_set::Rule;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+fn rule_enabled(json: &serde_json::Value, name: &str) -> bool {
+    json["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|rule| rule["name"] == name)
+        .unwrap()["enabled"]
+        .as_bool()
+        .unwrap()
+}
+
+#[test]
+fn test_list_rules_json() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("rules")
+        .arg("--output-format")
+        .arg("json")
+        .run();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    assert_eq!(json["rules"].as_array().unwrap().len(), Rule::all().len());
+
+    let any_is_na = json["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|rule| rule["name"] == "any_is_na")
+        .unwrap();
+    assert_eq!(any_is_na["categories"], serde_json::json!(["PERF"]));
+    assert_eq!(any_is_na["fix"], "safe");
+    assert_eq!(any_is_na["minimum_r_version"], serde_json::Value::Null);
+    assert_eq!(any_is_na["enabled_by_default"], true);
+    assert_eq!(any_is_na["enabled"], true);
+
+    Ok(())
+}
+
+#[test]
+fn test_list_rules_with_toml() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["any_is_na", "grepv"]
+"#,
+    )?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("rules")
+        .arg("--output-format")
+        .arg("json")
+        .run();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    assert!(rule_enabled(&json, "any_is_na"));
+    assert!(!rule_enabled(&json, "length_levels"));
+    // `grepv` requires R >= 4.5.0, which is unknown here.
+    assert!(!rule_enabled(&json, "grepv"));
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("rules")
+        .arg("--min-r-version")
+        .arg("4.5")
+        .arg("--output-format")
+        .arg("json")
+        .run();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    assert!(rule_enabled(&json, "any_is_na"));
+    assert!(rule_enabled(&json, "grepv"));
+
+    Ok(())
+}
+
+#[test]
+fn test_list_rules_text() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["any_is_na"]
+"#,
+    )?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("rules")
+        .run();
+    assert!(output.status.success());
+
+    assert!(output.stdout.starts_with("RULE"));
+    assert!(
+        output
+            .stdout
+            .lines()
+            .any(|line| line.starts_with("any_is_na") && line.ends_with("yes"))
+    );
+    assert!(
+        output
+            .stdout
+            .contains(&format!("1 of {} rules are enabled.", Rule::all().len()))
+    );
+
+    Ok(())
+}
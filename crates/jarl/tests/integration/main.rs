@@ -9,14 +9,46 @@
 mod allow_dirty;
 mod allow_no_vcs;
 mod assignment;
+mod atomic;
+mod baseline;
+mod cache;
+mod column_base;
 mod comments;
+mod compact_files;
+mod confidence;
+mod config;
+mod coverage_report;
+mod daemon;
+mod diff;
+mod dump_ast;
+mod explain;
+mod fail_on;
+mod fingerprint;
+mod fix_iterations;
+mod fix_only;
+mod fix_report;
+mod group_by;
 mod help;
 mod helpers;
+mod hyperlinks;
+mod include_rmd;
 mod jarl;
+mod jarlignore;
+mod jobs;
+mod list_rules;
 mod min_r_version;
 mod no_default_exclude;
+mod only;
 mod output_format;
+mod roxygen_examples;
+mod rule_docs;
 mod rules;
+mod select_category;
+mod severity;
+mod show_context;
 mod statistics;
+mod stdin;
+mod time_budget;
 mod toml;
 mod toml_hierarchical;
+mod workspace_edit;
@@ -99,3 +99,39 @@ Depends: R (>= 4.6.0), utils, stats"#,
 
     Ok(())
 }
+
+#[test]
+fn test_min_r_version_unknown_verbose() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "grep('a.*', x, value = TRUE)")?;
+    std::fs::write(directory.join("test2.R"), "x <- 1")?;
+
+    // grepv() rule only exists for R >= 4.5. The note lists the files in
+    // which it would report a violation.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--verbose")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // No note if the minimum R version is known, even if it is too low.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--verbose")
+            .arg("--min-r-version")
+            .arg("4.4.0")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_only_bypasses_min_r_version() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "grep('a.*', x, value = TRUE)\nany(is.na(x))";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    // grepv() rule only exists for R >= 4.5 so it is disabled when the min R
+    // version is unknown, but `--only` forces it to run. Other rules are not
+    // run.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--only")
+            .arg("grepv")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_only_bypasses_rule_selection() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+ignore = ["any_is_na"]
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "any(is.na(x))\nany(duplicated(x))";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--select")
+            .arg("any_duplicated")
+            .arg("--only")
+            .arg("any_is_na")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_only_unknown_rule() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "any(is.na(x))";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    // Groups of rules are not accepted.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--only")
+            .arg("PERF")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
@@ -124,6 +124,188 @@ fn test_output_json() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_output_json_applicability() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // class_equals has a fix that may be incorrect.
+    let test_path = "test.R";
+    let test_contents = "class(x) == 'foo'";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("json")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_json_positions() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "x <- 1\nany(\n  is.na(x)\n)\n";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    let output = |column_base: &str| -> anyhow::Result<serde_json::Value> {
+        let output = Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("json")
+            .arg("--column-base")
+            .arg(column_base)
+            .run();
+        Ok(serde_json::from_str(&output.stdout)?)
+    };
+
+    let json = output("0")?;
+    assert_eq!(json["version"], 1);
+
+    let diagnostic = &json["diagnostics"][0];
+    assert_eq!(diagnostic["rule"], "any_is_na");
+    assert_eq!(diagnostic["category"], "PERF");
+    assert_eq!(diagnostic["severity"], "warning");
+    assert_eq!(diagnostic["start_byte"], 7);
+    assert_eq!(diagnostic["end_byte"], 24);
+    assert_eq!(diagnostic["start_line"], 2);
+    assert_eq!(diagnostic["start_col"], 0);
+    assert_eq!(diagnostic["end_line"], 4);
+    assert_eq!(diagnostic["end_col"], 1);
+    assert_eq!(diagnostic["fix"]["start"], 7);
+    assert_eq!(diagnostic["fix"]["end"], 24);
+    assert_eq!(diagnostic["fix"]["content"], "anyNA(x)");
+    assert_eq!(diagnostic["fix"]["is_safe"], true);
+
+    // Both the start and end columns follow `--column-base`.
+    let json = output("1")?;
+    let diagnostic = &json["diagnostics"][0];
+    assert_eq!(diagnostic["start_col"], 1);
+    assert_eq!(diagnostic["end_col"], 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_output_sarif() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "x <- 1\nany(is.na(x))";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg("sarif")
+        .run();
+
+    // Don't use a snapshot since the output contains the version of Jarl.
+    let sarif: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    assert_eq!(sarif["version"], "2.1.0");
+    assert!(sarif["$schema"].as_str().unwrap().contains("sarif-2.1.0"));
+
+    let run = &sarif["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "jarl");
+
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), jarl_core::rule_set::Rule::all().len());
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+
+    let result = &results[0];
+    assert_eq!(result["ruleId"], "any_is_na");
+    assert_eq!(result["level"], "warning");
+    let rule_index = result["ruleIndex"].as_u64().unwrap() as usize;
+    assert_eq!(rules[rule_index]["id"], "any_is_na");
+    assert_eq!(rules[rule_index]["properties"]["fix"], "safe");
+
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "test.R");
+    assert_eq!(location["region"]["startLine"], 2);
+    assert_eq!(location["region"]["startColumn"], 1);
+    assert_eq!(location["region"]["byteOffset"], 7);
+    assert_eq!(location["region"]["byteLength"], 13);
+
+    assert_eq!(run["invocations"][0]["executionSuccessful"], true);
+
+    Ok(())
+}
+
+#[test]
+fn test_output_namespaced_rule_ids() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "any(is.na(x))";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    let rule_name = |namespaced: bool| -> anyhow::Result<serde_json::Value> {
+        let mut command = Command::new(binary_path());
+        command
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("json");
+        if namespaced {
+            command.arg("--namespaced-rule-ids");
+        }
+        let json: serde_json::Value = serde_json::from_str(&command.run().stdout)?;
+        Ok(json["diagnostics"][0]["message"]["name"].clone())
+    };
+
+    // No namespace by default to avoid breaking existing consumers.
+    assert_eq!(rule_name(false)?, "any_is_na");
+    assert_eq!(rule_name(true)?, "jarl:any_is_na");
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg("sarif")
+        .arg("--namespaced-rule-ids")
+        .run();
+
+    let sarif: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    let run = &sarif["runs"][0];
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "jarl:any_is_na");
+    let rule_index = result["ruleIndex"].as_u64().unwrap() as usize;
+    assert_eq!(rules[rule_index]["id"], "jarl:any_is_na");
+
+    // Human-readable formats keep the plain rule names.
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg("concise")
+        .arg("--namespaced-rule-ids")
+        .run();
+    assert!(!output.stdout.contains("jarl:any_is_na"));
+
+    Ok(())
+}
+
 #[test]
 fn test_output_github() -> anyhow::Result<()> {
     let directory = TempDir::new()?;
@@ -164,6 +346,56 @@ fn test_output_github() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_output_junit() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "any(is.na(x))";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    let test_path_2 = "test2.R";
+    let test_contents_2 = "any(duplicated(x))";
+    std::fs::write(directory.join(test_path_2), test_contents_2)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("junit")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_junit_no_violations() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "anyNA(x)";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("junit")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_with_parsing_error() -> anyhow::Result<()> {
     let directory = TempDir::new()?;
@@ -221,5 +453,16 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
             .normalize_os_executable_name()
     );
 
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("junit")
+            .run()
+            .normalize_os_executable_name()
+    );
+
     Ok(())
 }
@@ -0,0 +1,119 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_roxygen_examples() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "#' Add one
+#'
+#' @param x A number.
+#' @examples
+#' x = 1
+#' add_one(x)
+add_one <- function(x) {
+  x + 1
+}
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    // Examples are not checked by default.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--select")
+            .arg("assignment")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--select")
+            .arg("assignment")
+            .arg("--roxygen-examples")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_roxygen_examples_dontrun() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "#' @examples
+#' \\dontrun{
+#' x = download()
+#' }
+#' y = 1
+foo <- function() NULL
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--select")
+            .arg("assignment")
+            .arg("--roxygen-examples")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_roxygen_examples_fix() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "#' @examplesIf interactive()
+#' x = 1
+#' @export
+foo <- function() NULL
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--select")
+            .arg("assignment")
+            .arg("--roxygen-examples")
+            .arg("--fix")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    assert_eq!(
+        std::fs::read_to_string(directory.join(test_path))?,
+        "#' @examplesIf interactive()
+#' x <- 1
+#' @export
+foo <- function() NULL
+"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::process::Command;
+
+use jarl_core::rule_set::Rule;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_rule_docs_one_file_per_rule() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("rule-docs")
+        .arg("--rule-docs-dir")
+        .arg("stubs")
+        .run();
+    assert!(output.status.success());
+
+    for rule in Rule::all() {
+        let path = directory.join("stubs").join(format!("{}.md", rule.name()));
+        let contents = std::fs::read_to_string(&path)?;
+
+        assert!(contents.starts_with(&format!("# {}\n", rule.name())));
+
+        let category = rule.categories()[0];
+        let expected = format!(
+            "## Category\n\n{} (`{}`)",
+            category.long_name(),
+            category.as_str()
+        );
+        assert!(
+            contents.contains(&expected),
+            "Wrong category for rule {}",
+            rule.name()
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_rule_docs_does_not_overwrite() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::create_dir(directory.join("stubs"))?;
+    std::fs::write(directory.join("stubs/any_is_na.md"), "Hand-written docs")?;
+
+    let _ = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("rule-docs")
+        .arg("--rule-docs-dir")
+        .arg("stubs")
+        .run();
+
+    let contents = std::fs::read_to_string(directory.join("stubs/any_is_na.md"))?;
+    assert_eq!(contents, "Hand-written docs");
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+fn write_severity_test_files(directory: &std::path::Path, severity: &str) -> anyhow::Result<()> {
+    std::fs::write(directory.join("jarl.toml"), severity)?;
+    std::fs::write(
+        directory.join("test.R"),
+        "any(is.na(x))\nany(duplicated(x))",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_severity_error_and_info() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    write_severity_test_files(
+        directory,
+        r#"
+[lint.severity]
+any_is_na = "error"
+any_duplicated = "info"
+"#,
+    )?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_severity_warning_does_not_fail() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    write_severity_test_files(
+        directory,
+        r#"
+[lint.severity]
+any_is_na = "warning"
+any_duplicated = "info"
+"#,
+    )?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    // Warnings make the check fail with `--warnings-as-errors`.
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .arg("--warnings-as-errors")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_severity_with_group() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // The severity given by rule name takes precedence over the group.
+    write_severity_test_files(
+        directory,
+        r#"
+[lint.severity]
+PERF = "info"
+any_is_na = "error"
+"#,
+    )?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_severity_unknown_rule() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    write_severity_test_files(
+        directory,
+        r#"
+[lint.severity]
+invalid_rule_name = "error"
+"#,
+    )?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_severity_invalid_value() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    write_severity_test_files(
+        directory,
+        r#"
+[lint.severity]
+any_is_na = "fatal"
+"#,
+    )?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_no_severity_fails() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // Without `[lint.severity]`, violations are displayed as warnings but
+    // still make the check fail.
+    std::fs::write(
+        directory.join("test.R"),
+        "any(is.na(x))\nany(duplicated(x))",
+    )?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
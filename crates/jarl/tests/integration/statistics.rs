@@ -44,6 +44,34 @@ any(is.na(x))
     Ok(())
 }
 
+#[test]
+fn test_stats_json() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "any(is.na(x))\nany(is.na(x))";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    let test_path_2 = "test2.R";
+    let test_contents_2 = "mean(x <- 1)";
+    std::fs::write(directory.join(test_path_2), test_contents_2)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--statistics")
+            .arg("--output-format")
+            .arg("json")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_stats_no_violation() -> anyhow::Result<()> {
     let directory = TempDir::new()?;
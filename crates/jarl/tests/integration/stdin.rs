@@ -0,0 +1,133 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_stdin_uses_stdin_filename() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg("-")
+            .arg("--stdin-filename")
+            .arg("R/foo.R")
+            .arg("--output-format")
+            .arg("concise")
+            .run_with_stdin("any(is.na(x))\n")
+            .normalize_os_executable_name()
+    );
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg("-")
+            .arg("--stdin-filename")
+            .arg("R/foo.R")
+            .arg("--output-format")
+            .arg("json")
+            .run_with_stdin("any(is.na(x))\n")
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_without_stdin_filename() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg("-")
+            .arg("--output-format")
+            .arg("concise")
+            .run_with_stdin("any(is.na(x))\n")
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_uses_jarl_toml_of_stdin_filename() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::create_dir(directory.join("sub"))?;
+    std::fs::write(
+        directory.join("sub/jarl.toml"),
+        r#"
+[lint]
+select = ["any_duplicated"]
+"#,
+    )?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg("-")
+            .arg("--stdin-filename")
+            .arg("sub/test.R")
+            .arg("--output-format")
+            .arg("concise")
+            .run_with_stdin("any(is.na(x))\nany(duplicated(x))\n")
+            .normalize_os_executable_name()
+            .normalize_temp_paths()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_fix_writes_to_stdout() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg("-")
+            .arg("--stdin-filename")
+            .arg("foo.R")
+            .arg("--fix")
+            .arg("--output-format")
+            .arg("concise")
+            .run_with_stdin("any(is.na(x))\n")
+            .normalize_os_executable_name()
+    );
+
+    // No file is created
+    assert!(!directory.join("foo.R").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_cannot_be_combined_with_paths() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg("-")
+            .arg(".")
+            .run_with_stdin("any(is.na(x))\n")
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_time_budget_exceeded() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    for i in 0..100 {
+        std::fs::write(directory.join(format!("test_{i}.R")), "any(is.na(x))\n")?;
+    }
+
+    // With a budget of 0 seconds, only the first batch of 32 files is checked.
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg("concise")
+        .arg("--time-budget")
+        .arg("0")
+        .run();
+
+    assert_eq!(output.status.code(), Some(2));
+    let n_violations = output
+        .stdout
+        .lines()
+        .filter(|line| line.contains(" any_is_na "))
+        .count();
+    assert_eq!(n_violations, 32);
+    assert!(
+        output
+            .stderr
+            .contains("Warning: Time budget exceeded, 68 files were not checked.")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_time_budget_not_exceeded() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "any(is.na(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--time-budget")
+            .arg("60")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_time_budget_invalid() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(directory.join("test.R"), "any(is.na(x))\n")?;
+
+    for value in ["-1", "inf", "NaN", "abc"] {
+        let output = Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg(format!("--time-budget={value}"))
+            .run();
+
+        assert_eq!(output.status.code(), Some(2));
+        assert!(output.stderr.contains("invalid value"));
+    }
+
+    Ok(())
+}
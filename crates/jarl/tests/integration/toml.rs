@@ -1511,3 +1511,502 @@ extend-select = ["FOO"]
 
     Ok(())
 }
+
+#[test]
+fn test_toml_undesirable_function() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // The table replaces the default list of undesirable functions, so
+    // `sapply()` is not reported.
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["undesirable_function"]
+
+[lint.undesirable_function]
+setwd = "Use `here::here()` instead."
+library = ""
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "
+base::setwd('data')
+library(dplyr)
+sapply(x, f)
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_nested_ifelse() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["nested_ifelse"]
+
+[lint.nested_ifelse]
+max_depth = 3
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "
+ifelse(a, 1, ifelse(b, 2, ifelse(c, 3, 4)))
+ifelse(a, 1, ifelse(b, 2, ifelse(c, 3, ifelse(d, 4, 5))))
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_nested_ifelse_invalid_max_depth() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint.nested_ifelse]
+max_depth = 0
+"#,
+    )?;
+    std::fs::write(directory.join("test.R"), "x <- 1\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(output.stderr.contains("it must be at least 1"));
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_duplicated_arguments_partial_matching() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["duplicated_arguments"]
+
+[lint.duplicated_arguments]
+partial_matching = true
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "
+fun(ver = 1, verbose = 2)
+fun(verbose = 1, verbose = 2)
+fun(x = 1, y = 2)
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_duplicated_arguments_no_partial_matching_by_default() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["duplicated_arguments"]
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "
+fun(ver = 1, verbose = 2)
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_quotes_single() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["quotes"]
+
+[lint.quotes]
+style = "single"
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "
+x <- 'a'
+y <- \"b\"
+z <- \"it's\"
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_quotes_invalid_style() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint.quotes]
+style = "backtick"
+"#,
+    )?;
+    std::fs::write(directory.join("test.R"), "x <- 1\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(
+        output
+            .stderr
+            .contains("it must be \"double\" or \"single\"")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_line_length_max() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["line_length"]
+
+[lint.line_length]
+max = 20
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "
+x <- c(1, 2, 3)
+y <- c(1, 2, 3, 4, 5, 6)
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_line_length_invalid_max() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint.line_length]
+max = 0
+"#,
+    )?;
+    std::fs::write(directory.join("test.R"), "x <- 1\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(output.stderr.contains("it must be at least 1"));
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_infix_spaces_exponent() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["infix_spaces"]
+
+[lint.infix_spaces]
+exponent = true
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "
+x <- 1:10
+y <- x^2
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_object_name_styles() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["object_name"]
+
+[lint.object_name]
+style = ["camelCase", "UPPER_SNAKE"]
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "
+myValue <- 1
+MAX_SIZE <- 10
+my_value <- 2
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_object_name_invalid_style() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint.object_name]
+style = "kebab-case"
+"#,
+    )?;
+    std::fs::write(directory.join("test.R"), "x <- 1\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(
+        output
+            .stderr
+            .contains("\"kebab-case\" is not a naming style")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_pipe_consistency_native() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["pipe_consistency"]
+
+[lint.pipe_consistency]
+style = "native"
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "
+x %>% f()
+y %>% g(z, .)
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_pipe_consistency_invalid_style() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint.pipe_consistency]
+style = "base"
+"#,
+    )?;
+    std::fs::write(directory.join("test.R"), "x <- 1\n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(
+        output
+            .stderr
+            .contains("it must be \"auto\", \"native\", or \"magrittr\"")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_toml_undesirable_operator() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    // The table replaces the default list of undesirable operators, so `:::`
+    // is not reported.
+    std::fs::write(
+        directory.join("jarl.toml"),
+        r#"
+[lint]
+select = ["undesirable_operator"]
+
+[lint.undesirable_operator]
+"<<-" = "Return the value instead."
+"$" = ""
+"#,
+    )?;
+
+    let test_path = "test.R";
+    let test_contents = "
+f <- function() x <<- 1
+y <- df$col
+pkg:::g(a = 1)
+";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
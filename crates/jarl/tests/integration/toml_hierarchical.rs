@@ -170,3 +170,47 @@ ignore = ["any_duplicated"]
 
     Ok(())
 }
+
+#[test]
+fn test_nested_tomls_apply_to_their_directory() -> anyhow::Result<()> {
+    let root_dir = TempDir::new()?;
+    let root_path = root_dir.path();
+
+    let subdir = root_path.join("sub");
+    std::fs::create_dir_all(&subdir)?;
+
+    // Both files have the same violations.
+    let test_contents = "any(is.na(x))\nany(duplicated(x))";
+    std::fs::write(root_path.join("test.R"), test_contents)?;
+    std::fs::write(subdir.join("test.R"), test_contents)?;
+
+    // Each TOML selects a different rule.
+    std::fs::write(
+        root_path.join("jarl.toml"),
+        r#"
+[lint]
+select = ["any_is_na"]
+"#,
+    )?;
+    std::fs::write(
+        subdir.join("jarl.toml"),
+        r#"
+[lint]
+select = ["any_duplicated"]
+"#,
+    )?;
+
+    // Each file should only report the rule selected by the closest TOML.
+    insta::assert_snapshot!(
+        "nested_tomls_apply_to_their_directory",
+        &mut Command::new(binary_path())
+            .current_dir(root_path)
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths()
+    );
+
+    Ok(())
+}
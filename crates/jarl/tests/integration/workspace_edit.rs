@@ -0,0 +1,102 @@
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::CommandExt;
+use crate::helpers::binary_path;
+
+#[test]
+fn test_workspace_edit() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    let test_contents = "y <- 2\nx = 1\n";
+    std::fs::write(directory.join(test_path), test_contents)?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--select")
+        .arg("assignment")
+        .arg("--workspace-edit")
+        .run();
+
+    // Don't use a snapshot since the output contains the path to the file.
+    let workspace_edit: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    let changes = workspace_edit["changes"].as_object().unwrap();
+    assert_eq!(changes.len(), 1);
+
+    let (uri, edits) = changes.iter().next().unwrap();
+    assert!(uri.starts_with("file://"));
+    assert!(uri.ends_with("/test.R"));
+
+    let edits = edits.as_array().unwrap();
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0]["newText"], "x <- 1");
+    assert_eq!(edits[0]["range"]["start"]["line"], 1);
+    assert_eq!(edits[0]["range"]["start"]["character"], 0);
+    assert_eq!(edits[0]["range"]["end"]["line"], 1);
+    assert_eq!(edits[0]["range"]["end"]["character"], 5);
+
+    // The file is left untouched.
+    let contents = std::fs::read_to_string(directory.join(test_path))?;
+    assert_eq!(contents, test_contents);
+
+    Ok(())
+}
+
+#[test]
+fn test_workspace_edit_deletion() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    std::fs::write(directory.join(test_path), "x <- 1   \n")?;
+
+    let output = Command::new(binary_path())
+        .current_dir(directory)
+        .arg("check")
+        .arg(".")
+        .arg("--select")
+        .arg("trailing_whitespace")
+        .arg("--workspace-edit")
+        .run();
+
+    // Fixes that only remove code have an empty replacement.
+    let workspace_edit: serde_json::Value = serde_json::from_str(&output.stdout)?;
+    let changes = workspace_edit["changes"].as_object().unwrap();
+    assert_eq!(changes.len(), 1);
+
+    let (_uri, edits) = changes.iter().next().unwrap();
+    let edits = edits.as_array().unwrap();
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0]["newText"], "");
+    assert_eq!(edits[0]["range"]["start"]["line"], 0);
+    assert_eq!(edits[0]["range"]["start"]["character"], 6);
+    assert_eq!(edits[0]["range"]["end"]["line"], 0);
+    assert_eq!(edits[0]["range"]["end"]["character"], 9);
+
+    Ok(())
+}
+
+#[test]
+fn test_workspace_edit_no_violations() -> anyhow::Result<()> {
+    let directory = TempDir::new()?;
+    let directory = directory.path();
+
+    let test_path = "test.R";
+    std::fs::write(directory.join(test_path), "x <- 1\n")?;
+
+    insta::assert_snapshot!(
+        &mut Command::new(binary_path())
+            .current_dir(directory)
+            .arg("check")
+            .arg(".")
+            .arg("--workspace-edit")
+            .run()
+            .normalize_os_executable_name()
+    );
+
+    Ok(())
+}
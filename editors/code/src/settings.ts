@@ -10,6 +10,7 @@ type LogLevel = "error" | "warn" | "info" | "debug" | "trace";
 export type InitializationOptions = {
 	logLevel?: LogLevel;
 	dependencyLogLevels?: string;
+	lintDebounceMs?: number;
 };
 
 export type WorkspaceSettings = {
@@ -28,6 +29,7 @@ export function getInitializationOptions(
 			config,
 			"dependencyLogLevels",
 		),
+		lintDebounceMs: getOptionalUserValue<number>(config, "lintDebounceMs"),
 	};
 }
 